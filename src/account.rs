@@ -1,8 +1,21 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyBytes, PyDict, PyList};
 use pyo3::exceptions::PyValueError;
 use reqwest::blocking::Client;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Datelike, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use crate::auth::Credentials;
+use crate::client;
+use crate::error;
+use crate::metadata;
+use crate::playlist;
+use crate::utils::{self, redact};
+use crate::metrics::{self, EngagementWeights};
+use crate::text::TextOptions;
 
 // YouTube API Response Structures
 #[derive(Debug, Deserialize, Serialize)]
@@ -19,11 +32,46 @@ struct YouTubeChannelResponse {
 struct YouTubeChannel {
     id: String,
     snippet: ChannelSnippet,
-    statistics: ChannelStatistics,
+    // Absent for some auto-generated (topic) channels
+    statistics: Option<ChannelStatistics>,
     #[serde(rename = "contentDetails")]
     content_details: Option<ContentDetails>,
     #[serde(rename = "brandingSettings")]
     branding_settings: Option<BrandingSettings>,
+    #[serde(rename = "topicDetails")]
+    topic_details: Option<TopicDetails>,
+    status: Option<ChannelStatus>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct ChannelStatus {
+    #[serde(rename = "privacyStatus")]
+    privacy_status: Option<String>,
+    #[serde(rename = "isLinked")]
+    is_linked: Option<bool>,
+    #[serde(rename = "madeForKids")]
+    made_for_kids: Option<bool>,
+    #[serde(rename = "selfDeclaredMadeForKids")]
+    self_declared_made_for_kids: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct TopicDetails {
+    // Wikipedia URLs such as https://en.wikipedia.org/wiki/Hip_hop_music
+    #[serde(rename = "topicCategories", default)]
+    topic_categories: Vec<String>,
+}
+
+impl TopicDetails {
+    /// Set `topic_categories` as readable names ("Music", "Hip hop music") and
+    /// `topic_category_urls` as the raw URLs; returns the names
+    pub(crate) fn set_items(topics: Option<&Self>, dict: &PyDict) -> PyResult<Vec<String>> {
+        let topic_urls = topics.map(|t| t.topic_categories.clone()).unwrap_or_default();
+        let topic_names: Vec<String> = topic_urls.iter().map(|url| utils::topic_name(url)).collect();
+        dict.set_item("topic_categories", &topic_names)?;
+        dict.set_item("topic_category_urls", topic_urls)?;
+        Ok(topic_names)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -36,6 +84,26 @@ struct ChannelSnippet {
     published_at: String,
     thumbnails: Thumbnails,
     country: Option<String>,
+    // Title/description in the `hl` language when the channel provides one
+    localized: Option<LocalizedText>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct LocalizedText {
+    title: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelLocalizationsResponse {
+    #[serde(default)]
+    items: Vec<ChannelLocalizations>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelLocalizations {
+    #[serde(default)]
+    localizations: BTreeMap<String, LocalizedText>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -44,7 +112,7 @@ struct ChannelStatistics {
     view_count: Option<String>,
     #[serde(rename = "subscriberCount")]
     subscriber_count: Option<String>,
-    #[serde(rename = "hiddenSubscriberCount")]
+    #[serde(rename = "hiddenSubscriberCount", default)]
     hidden_subscriber_count: bool,
     #[serde(rename = "videoCount")]
     video_count: Option<String>,
@@ -65,6 +133,7 @@ struct RelatedPlaylists {
 #[derive(Debug, Deserialize, Serialize)]
 struct BrandingSettings {
     channel: Option<ChannelBranding>,
+    image: Option<BrandingImage>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -72,13 +141,64 @@ struct ChannelBranding {
     title: Option<String>,
     description: Option<String>,
     keywords: Option<String>,
+    #[serde(rename = "unsubscribedTrailer")]
+    unsubscribed_trailer: Option<String>,
+    country: Option<String>,
+    #[serde(rename = "defaultLanguage")]
+    default_language: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct BrandingImage {
+    #[serde(rename = "bannerExternalUrl")]
+    banner_external_url: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct Thumbnails {
+pub(crate) struct Thumbnails {
     default: Option<Thumbnail>,
     medium: Option<Thumbnail>,
     high: Option<Thumbnail>,
+    standard: Option<Thumbnail>,
+    maxres: Option<Thumbnail>,
+}
+
+impl Thumbnails {
+    // Variants from smallest to largest
+    fn variants(&self) -> [(&'static str, &Option<Thumbnail>); 5] {
+        [
+            ("default", &self.default),
+            ("medium", &self.medium),
+            ("high", &self.high),
+            ("standard", &self.standard),
+            ("maxres", &self.maxres),
+        ]
+    }
+
+    pub(crate) fn to_py<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let thumbnails = PyDict::new(py);
+        for (name, thumbnail) in self.variants() {
+            if let Some(thumbnail) = thumbnail {
+                thumbnails.set_item(name, &thumbnail.url)?;
+            }
+        }
+        Ok(thumbnails)
+    }
+
+    /// Every variant as `{"url", "width", "height"}`; sizes are None when the API omits them
+    pub(crate) fn details_to_py<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let details = PyDict::new(py);
+        for (name, thumbnail) in self.variants() {
+            if let Some(thumbnail) = thumbnail {
+                let entry = PyDict::new(py);
+                entry.set_item("url", &thumbnail.url)?;
+                entry.set_item("width", thumbnail.width)?;
+                entry.set_item("height", thumbnail.height)?;
+                details.set_item(name, entry)?;
+            }
+        }
+        Ok(details)
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -99,6 +219,7 @@ struct PageInfo {
 // Video list response structures
 #[derive(Debug, Deserialize, Serialize)]
 struct YouTubeVideoListResponse {
+    #[serde(default)]
     items: Vec<YouTubeVideo>,
     #[serde(rename = "nextPageToken")]
     next_page_token: Option<String>,
@@ -106,15 +227,150 @@ struct YouTubeVideoListResponse {
 
 #[derive(Debug, Deserialize, Serialize)]
 struct YouTubeVideo {
-    id: VideoId,
+    id: String,
     snippet: VideoSnippet,
     statistics: Option<VideoStatistics>,
+    status: Option<VideoStatus>,
+    #[serde(rename = "contentDetails")]
+    content_details: Option<VideoContentDetails>,
+    #[serde(rename = "paidProductPlacementDetails")]
+    paid_product_placement_details: Option<PaidProductPlacementDetails>,
 }
 
+// Set when the creator declares a sponsorship or paid promotion
 #[derive(Debug, Deserialize, Serialize)]
-struct VideoId {
-    #[serde(rename = "videoId")]
-    video_id: String,
+pub(crate) struct PaidProductPlacementDetails {
+    #[serde(rename = "hasPaidProductPlacement")]
+    pub has_paid_product_placement: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct VideoContentDetails {
+    // ISO 8601, e.g. PT4M13S
+    pub duration: Option<String>,
+    // "hd" or "sd"
+    definition: Option<String>,
+    // "2d" or "3d"
+    dimension: Option<String>,
+    #[serde(rename = "licensedContent")]
+    licensed_content: Option<bool>,
+    // "true" or "false" as a string
+    caption: Option<String>,
+    #[serde(rename = "regionRestriction")]
+    region_restriction: Option<RegionRestriction>,
+}
+
+// Either list may be present; a video is viewable only in `allowed`, or everywhere but `blocked`
+#[derive(Debug, Deserialize, Serialize)]
+struct RegionRestriction {
+    allowed: Option<Vec<String>>,
+    blocked: Option<Vec<String>>,
+}
+
+impl VideoContentDetails {
+    pub(crate) fn duration_seconds(&self) -> Option<u64> {
+        self.duration.as_deref().and_then(utils::parse_duration)
+    }
+
+    /// Set `duration`, `duration_seconds`, `definition`, `dimension`,
+    /// `licensed_content`, `caption` and `region_restriction` on a video dict;
+    /// None when absent
+    pub(crate) fn set_items(details: Option<&Self>, video: &PyDict) -> PyResult<()> {
+        video.set_item("duration", details.and_then(|d| d.duration.as_deref()))?;
+        video.set_item("duration_seconds", details.and_then(Self::duration_seconds))?;
+        video.set_item("definition", details.and_then(|d| d.definition.as_deref()))?;
+        video.set_item("dimension", details.and_then(|d| d.dimension.as_deref()))?;
+        video.set_item("licensed_content", details.and_then(|d| d.licensed_content))?;
+        video.set_item("caption", details.and_then(|d| d.caption.as_deref()).map(|caption| caption == "true"))?;
+        match details.and_then(|d| d.region_restriction.as_ref()) {
+            Some(restriction) => {
+                let region_restriction = PyDict::new(video.py());
+                region_restriction.set_item("allowed", &restriction.allowed)?;
+                region_restriction.set_item("blocked", &restriction.blocked)?;
+                video.set_item("region_restriction", region_restriction)?;
+            }
+            None => video.set_item("region_restriction", video.py().None())?,
+        }
+        Ok(())
+    }
+}
+
+// Longest a Short can be; anything longer is a regular video without asking youtube.com
+const MAX_SHORT_SECONDS: u64 = 180;
+// Before October 2024 Shorts were capped at one minute
+const LEGACY_MAX_SHORT_SECONDS: u64 = 60;
+
+/// Classify a video as a Short, returning `(is_short, method)`
+///
+/// Videos up to three minutes are checked against `youtube.com/shorts/<id>`,
+/// which serves Shorts directly and redirects regular videos to the watch
+/// page. When that probe is inconclusive, only videos up to one minute count
+/// as Shorts. `is_short` is None when neither signal is available.
+pub(crate) fn classify_short(web_client: &Client, video_id: &str, duration_seconds: Option<u64>) -> (Option<bool>, &'static str) {
+    if duration_seconds.is_some_and(|seconds| seconds > MAX_SHORT_SECONDS) {
+        return (Some(false), "duration");
+    }
+    match client::head_status(web_client, &format!("{}/shorts/{}", client::WEB_URL, video_id)) {
+        Some(status) if status.is_success() => (Some(true), "shorts_url"),
+        Some(status) if status.is_redirection() => (Some(false), "shorts_url"),
+        _ => (duration_seconds.map(|seconds| seconds <= LEGACY_MAX_SHORT_SECONDS), "duration"),
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct VideoStatus {
+    #[serde(rename = "privacyStatus")]
+    pub privacy_status: Option<String>,
+    #[serde(rename = "uploadStatus")]
+    pub upload_status: Option<String>,
+    // "youtube" or "creativeCommon"
+    license: Option<String>,
+    embeddable: Option<bool>,
+    #[serde(rename = "publicStatsViewable")]
+    public_stats_viewable: Option<bool>,
+    #[serde(rename = "madeForKids")]
+    made_for_kids: Option<bool>,
+}
+
+impl VideoStatus {
+    /// Set `privacy_status`, `upload_status`, `license`, `embeddable`,
+    /// `public_stats_viewable` and `made_for_kids` on a video dict; None when absent
+    pub(crate) fn set_items(status: Option<&Self>, video: &PyDict) -> PyResult<()> {
+        video.set_item("privacy_status", status.and_then(|s| s.privacy_status.as_deref()))?;
+        video.set_item("upload_status", status.and_then(|s| s.upload_status.as_deref()))?;
+        video.set_item("license", status.and_then(|s| s.license.as_deref()))?;
+        video.set_item("embeddable", status.and_then(|s| s.embeddable))?;
+        video.set_item("public_stats_viewable", status.and_then(|s| s.public_stats_viewable))?;
+        video.set_item("made_for_kids", status.and_then(|s| s.made_for_kids))?;
+        Ok(())
+    }
+}
+
+/// `public`, `unlisted`, `private` or `deleted` for a video the videos endpoint returned
+pub(crate) fn availability(status: Option<&VideoStatus>) -> &'static str {
+    let status = match status {
+        Some(status) => status,
+        None => return "public",
+    };
+    if matches!(status.upload_status.as_deref(), Some("deleted" | "rejected")) {
+        return "deleted";
+    }
+    match status.privacy_status.as_deref() {
+        Some("unlisted") => "unlisted",
+        Some("private") => "private",
+        _ => "public",
+    }
+}
+
+/// Availability of a playlist entry the videos endpoint did not return
+///
+/// The API answers private and deleted IDs alike with no item; only the
+/// placeholder title of the playlist entry tells them apart.
+pub(crate) fn missing_video_availability(item: &playlist::PlaylistItem) -> &'static str {
+    match item.snippet.as_ref().and_then(|snippet| snippet.title.as_deref()) {
+        Some("Private video") => "private",
+        _ => "deleted",
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -127,7 +383,7 @@ struct VideoSnippet {
 }
 
 #[derive(Debug, Deserialize, Serialize)]
-struct VideoStatistics {
+pub(crate) struct VideoStatistics {
     #[serde(rename = "viewCount")]
     view_count: Option<String>,
     #[serde(rename = "likeCount")]
@@ -136,24 +392,45 @@ struct VideoStatistics {
     comment_count: Option<String>,
 }
 
-// Error response structure
-#[derive(Debug, Deserialize, Serialize)]
-struct YouTubeErrorResponse {
-    error: YouTubeError,
+impl VideoStatistics {
+    // (views, likes, comments), missing or unparsable values counted as 0
+    pub(crate) fn counts(&self) -> (u64, u64, u64) {
+        let parse = |value: &Option<String>| value.as_ref().and_then(|v| v.parse::<u64>().ok()).unwrap_or(0);
+        (parse(&self.view_count), parse(&self.like_count), parse(&self.comment_count))
+    }
+
+    // Like `counts`, but hidden likes and disabled comments stay None
+    pub(crate) fn reported_counts(&self) -> metrics::VideoCounts {
+        let parse = |value: &Option<String>| value.as_ref().and_then(|v| v.parse::<u64>().ok());
+        (parse(&self.view_count).unwrap_or(0), parse(&self.like_count), parse(&self.comment_count))
+    }
+
+    /// Set `likes_hidden` and `comments_disabled`, which the API signals by
+    /// leaving the count out; both None without statistics
+    pub(crate) fn set_hidden_items(stats: Option<&Self>, video: &PyDict) -> PyResult<()> {
+        video.set_item("likes_hidden", stats.map(|s| s.like_count.is_none()))?;
+        video.set_item("comments_disabled", stats.map(|s| s.comment_count.is_none()))?;
+        Ok(())
+    }
+
+    // None when views are 0 or a count is hidden
+    pub(crate) fn engagement_rate(&self, weights: EngagementWeights) -> Option<f64> {
+        let parse = |value: &Option<String>| value.as_ref().and_then(|v| v.parse::<u64>().ok());
+        metrics::engagement_rate(parse(&self.view_count), parse(&self.like_count), parse(&self.comment_count), weights)
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct YouTubeError {
-    code: u32,
-    message: String,
-    errors: Vec<ErrorDetail>,
+// videos?part=statistics response structures
+#[derive(Debug, Deserialize)]
+struct VideoStatisticsListResponse {
+    #[serde(default)]
+    items: Vec<VideoStatisticsItem>,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
-struct ErrorDetail {
-    message: String,
-    domain: String,
-    reason: String,
+#[derive(Debug, Deserialize)]
+pub(crate) struct VideoStatisticsItem {
+    pub id: String,
+    pub statistics: Option<VideoStatistics>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -162,6 +439,8 @@ struct YouTubeSearchResultId {
     kind: String,
     #[serde(rename = "channelId")]
     channel_id: Option<String>,
+    #[serde(rename = "videoId")]
+    video_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -181,6 +460,8 @@ struct YouTubeSearchResult {
 #[derive(Debug, Deserialize)]
 struct YouTubeSearchResponse {
     items: Vec<YouTubeSearchResult>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
 }
 
 
@@ -226,15 +507,23 @@ struct YouTubeSearchResponse {
 //}
 
 
-fn fetch_channel_by_url(
-    client: &Client,
-    api_key: &str,
-    channel_identifier: &str,
-) -> PyResult<YouTubeChannel> {
+// Parts requested for every channel lookup
+const CHANNEL_PARTS: &str = "snippet,statistics,contentDetails,brandingSettings,topicDetails,status";
+
+/// The channel's `@handle`, which the API reports in `snippet.customUrl`
+///
+/// Channels without a handle may still carry a legacy custom URL there
+/// (no `@`); that stays available as `custom_url` only.
+fn channel_handle(custom_url: Option<&str>) -> Option<&str> {
+    custom_url.filter(|custom_url| custom_url.starts_with('@'))
+}
+
+/// channels.list URL for an ID, @handle or legacy username
+fn channel_lookup_url(channel_identifier: &str, parts: &str, hl: Option<&str>) -> PyResult<String> {
     let base_url = "https://www.googleapis.com/youtube/v3";
     let mut url = format!(
-        "{}/channels?part=snippet,statistics,contentDetails,brandingSettings&key={}",
-        base_url, api_key
+        "{}/channels?part={}",
+        base_url, parts
     );
 
     // Determine type
@@ -242,151 +531,226 @@ fn fetch_channel_by_url(
         // channel ID
         url.push_str(&format!("&id={}", channel_identifier));
     } else if channel_identifier.starts_with("@") {
-        // handle: search for channel
-        let handle = &channel_identifier[1..];
-        let search_url = format!(
-            "{}/search?part=snippet&type=channel&q={}&key={}",
-            base_url, handle, api_key
-        );
-        let search_resp = client.get(&search_url)
-            .header("Accept", "application/json")
-            .send()
-            .map_err(|e| PyValueError::new_err(format!("Search request failed: {}", e)))?;
-        if !search_resp.status().is_success() {
-            return Err(PyValueError::new_err(format!("Search failed: {}", search_resp.status())));
-        }
-        let search_text = search_resp.text()
-            .map_err(|e| PyValueError::new_err(format!("Failed to read response text: {}", e)))?;
-        println!("--- Full YouTube search response ---\n{}", search_text);
-        let search_data: YouTubeSearchResponse = serde_json::from_str(&search_text)
-            .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
-        let first_channel = search_data.items.into_iter().next()
-            .ok_or_else(|| PyValueError::new_err("Channel not found via handle"))?;
-        // use the channel ID for full fetch
-        if let Some(channel_id) = &first_channel.id.channel_id {
-            return fetch_channel_by_url(client, api_key, channel_id);
-        } else {
-            return Err(PyValueError::new_err("Channel ID not found in search result"));
-        }
-
+        // handle: exact lookup, 1 quota unit instead of a 100-unit search
+        url = Url::parse_with_params(&url, &[("forHandle", channel_identifier)])
+            .map_err(|e| PyValueError::new_err(format!("Invalid handle {}: {}", channel_identifier, e)))?
+            .to_string();
     } else {
         // old username
         url.push_str(&format!("&forUsername={}", channel_identifier));
     }
 
-    let resp = client.get(&url)
-        .header("Accept", "application/json")
-        .send()
-        .map_err(|e| PyValueError::new_err(format!("Request failed: {}", e)))?;
-
-    if !resp.status().is_success() {
-        return Err(PyValueError::new_err(format!("Failed to fetch channel: {}", resp.status())));
+    // Language for snippet.localized
+    if let Some(hl) = hl {
+        url = Url::parse_with_params(&url, &[("hl", hl)])
+            .map_err(|e| PyValueError::new_err(format!("Invalid language {}: {}", hl, e)))?
+            .to_string();
     }
+    Ok(url)
+}
+
+fn fetch_channel_by_url(
+    client: &Client,
+    credentials: &Credentials,
+    channel_identifier: &str,
+    hl: Option<&str>,
+) -> PyResult<YouTubeChannel> {
+    let url = channel_lookup_url(channel_identifier, CHANNEL_PARTS, hl)?;
+
+    let resp = client::send_get(client, credentials, &url)?;
 
-    let data_text = resp.text()
-        .map_err(|e| PyValueError::new_err(format!("Failed to read response text: {}", e)))?;
+    if !resp.is_success() {
+        return Err(PyValueError::new_err(format!("Failed to fetch channel: {}", resp.status)));
+    }
 
-    println!("--- Full YouTube channel response ---\n{}", data_text);
+    let data_text = resp.body;
 
     let data: YouTubeChannelResponse = serde_json::from_str(&data_text) 
         .map_err(|e| PyValueError::new_err(format!("Failed to parse channel data: {}", e)))?;
 
     data.items.into_iter().next()
-        .ok_or_else(|| PyValueError::new_err("Channel not found"))
+        .ok_or_else(|| channel_missing(client, channel_identifier))
 }
 
+// Phrases of youtube.com's channel page for taken-down channels, with the reason reported
+const UNAVAILABLE_MARKERS: [(&str, &str); 4] = [
+    ("account has been terminated", "terminated"),
+    ("channel was removed", "terminated"),
+    ("account has been suspended", "suspended"),
+    ("this channel is not available", "unavailable"),
+];
 
-/// Get YouTube channel statistics and recent videos
-/// 
-/// # Arguments
-/// * `channel_identifier` - Can be channel ID, username, or custom URL
-/// * `api_key` - YouTube Data API v3 key
-/// * `video_count` - Number of recent videos to fetch (default: 10)
-/// 
-/// # Returns
-/// * PyResult<PyObject> - Dictionary containing channel stats and recent videos
-#[pyfunction]
-pub fn get_youtube_channel_stats(
-    channel_identifier: String,
-    api_key: String,
-    video_count: Option<u32>,
-) -> PyResult<PyObject> {
-    let client = Client::new();
-    let base_url = "https://www.googleapis.com/youtube/v3";
-    let videos_to_fetch = video_count.unwrap_or(10);
-    
-    // First, try to get channel info
-    let channel = fetch_channel_by_url(&client, &api_key, &channel_identifier)
-        .map_err(|e| PyValueError::new_err(format!("Failed to fetch channel: {}", e)))?;
+/// Why youtube.com no longer shows a channel the API does not return
+///
+/// The API answers with an empty list for terminated, suspended and
+/// mistyped channels alike, but the channel page still explains a takedown.
+/// None means the channel page gave no reason, i.e. most likely a typo.
+pub(crate) fn channel_unavailable_reason(client: &Client, channel_id: &str) -> Option<&'static str> {
+    let (status, body) = client::get_page(client, &format!("{}/channel/{}", client::WEB_URL, channel_id))?;
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return None;
+    }
+    let body = body.to_lowercase();
+    UNAVAILABLE_MARKERS.iter()
+        .find(|(marker, _)| body.contains(marker))
+        .map(|(_, reason)| *reason)
+}
 
-    // Get recent videos if we have an uploads playlist
-    let mut recent_videos = Vec::new();
-    
-    if let Some(content_details) = &channel.content_details {
-        if let Some(uploads_playlist) = &content_details.related_playlists.uploads {
-            println!("Found uploads playlist");
-            println!("uploads_playlist: {:?}", uploads_playlist);
-            let videos_url = format!(
-                "{}/search?part=id,snippet&channelId={}&maxResults={}&order=date&type=video&key={}",
-                base_url, channel.id, videos_to_fetch, api_key
-            );
-            
-            if let Ok(videos_response) = client.get(&videos_url)
-                .header("Accept", "application/json")
-                .send() 
-            {
-                if videos_response.status().is_success() {
-                    if let Ok(videos_data) = videos_response.json::<YouTubeVideoListResponse>() {
-                        // Get video IDs
-                        let video_ids: Vec<String> = videos_data.items.iter()
-                            .map(|v| v.id.video_id.clone())
-                            .collect();
-                        
-                        if !video_ids.is_empty() {
-                            // Fetch detailed statistics for these videos
-                            let video_stats_url = format!(
-                                "{}/videos?part=statistics,snippet&id={}&key={}",
-                                base_url, video_ids.join(","), api_key
-                            );
-                            
-                            if let Ok(stats_response) = client.get(&video_stats_url)
-                                .header("Accept", "application/json")
-                                .send()
-                            {
-                                if stats_response.status().is_success() {
-                                    if let Ok(stats_data) = stats_response.json::<YouTubeVideoListResponse>() {
-                                        recent_videos = stats_data.items;
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+/// ChannelUnavailableError for a taken-down channel ID, else ChannelNotFoundError
+fn channel_missing(client: &Client, identifier: &str) -> PyErr {
+    let reason = if identifier.starts_with("UC") { channel_unavailable_reason(client, identifier) } else { None };
+    match reason {
+        Some(reason) => error::channel_unavailable(identifier, reason),
+        None => error::channel_not_found(identifier),
+    }
+}
+
+
+// Largest page size / ID batch the Data API accepts
+pub(crate) const MAX_RESULTS_PER_PAGE: usize = 50;
+
+/// Statistics for any number of videos, 50 IDs per request
+pub(crate) fn fetch_video_statistics(
+    client: &Client,
+    credentials: &Credentials,
+    video_ids: &[String],
+) -> PyResult<Vec<VideoStatisticsItem>> {
+    let mut videos = Vec::with_capacity(video_ids.len());
+    for batch in video_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let url = format!("{}/videos?part=statistics&id={}", client::BASE_URL, batch.join(","));
+        let response = client::send_get(client, credentials, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch video statistics: {}", response.status)));
         }
+        let page: VideoStatisticsListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse video statistics: {}", e)))?;
+        videos.extend(page.items);
     }
-    
-    // Convert to Python dictionary
-    Python::with_gil(|py| {
-        let py_dict = PyDict::new(py);
-        
-        // Channel basic info
-        py_dict.set_item("channel_id", &channel.id)?;
-        py_dict.set_item("channel_title", &channel.snippet.title)?;
-        py_dict.set_item("channel_description", &channel.snippet.description)?;
-        py_dict.set_item("published_at", &channel.snippet.published_at)?;
-        
-        if let Some(custom_url) = &channel.snippet.custom_url {
-            py_dict.set_item("custom_url", custom_url)?;
+    Ok(videos)
+}
+
+fn uploads_playlist_id(channel: &YouTubeChannel) -> PyResult<&str> {
+    channel.content_details.as_ref()
+        .and_then(|details| details.related_playlists.uploads.as_deref())
+        .ok_or_else(|| PyValueError::new_err(format!("Channel {} has no uploads playlist", channel.id)))
+}
+
+// Lifetime views per subscriber observed across public channels
+const VIEWS_PER_SUBSCRIBER: (f64, f64) = (100.0, 400.0);
+// Share of subscribers a typical recent upload reaches
+const RECENT_VIEWS_PER_SUBSCRIBER: (f64, f64) = (0.05, 0.30);
+// Likes plus comments a typical recent upload gets per subscriber
+const RECENT_INTERACTIONS_PER_SUBSCRIBER: (f64, f64) = (0.002, 0.02);
+
+struct SubscriberEstimate {
+    low: u64,
+    high: u64,
+    point: u64,
+    confidence: &'static str,
+    signals: Vec<&'static str>,
+}
+
+/// Estimate a subscriber range from public view and engagement signals
+///
+/// Each signal is divided by the per-subscriber ratios peer channels show
+/// (the constants above): lifetime views, average views of the recent uploads
+/// and their average likes plus comments. `recent` holds `(views, likes,
+/// comments)` per upload.
+fn estimate_subscribers(total_views: Option<u64>, recent: &[(u64, u64, u64)]) -> Option<SubscriberEstimate> {
+    let mut ranges: Vec<(f64, f64)> = Vec::new();
+    let mut signals = Vec::new();
+
+    if let Some(views) = total_views.filter(|v| *v > 0) {
+        let views = views as f64;
+        ranges.push((views / VIEWS_PER_SUBSCRIBER.1, views / VIEWS_PER_SUBSCRIBER.0));
+        signals.push("total_views");
+    }
+
+    if !recent.is_empty() {
+        let uploads = recent.len() as f64;
+        let average_views = recent.iter().map(|v| v.0).sum::<u64>() as f64 / uploads;
+        if average_views > 0.0 {
+            ranges.push((average_views / RECENT_VIEWS_PER_SUBSCRIBER.1, average_views / RECENT_VIEWS_PER_SUBSCRIBER.0));
+            signals.push("recent_views");
         }
-        
-        if let Some(country) = &channel.snippet.country {
-            py_dict.set_item("country", country)?;
+        let average_interactions = recent.iter().map(|v| v.1 + v.2).sum::<u64>() as f64 / uploads;
+        if average_interactions > 0.0 {
+            ranges.push((
+                average_interactions / RECENT_INTERACTIONS_PER_SUBSCRIBER.1,
+                average_interactions / RECENT_INTERACTIONS_PER_SUBSCRIBER.0,
+            ));
+            signals.push("recent_engagement");
         }
-        
-        // Channel statistics
-        let stats = &channel.statistics;
-        
+    }
+
+    if ranges.is_empty() {
+        return None;
+    }
+
+    // Narrow to the overlap of all signals; fall back to their union when they disagree
+    let overlap_low = ranges.iter().map(|r| r.0).fold(f64::MIN, f64::max);
+    let overlap_high = ranges.iter().map(|r| r.1).fold(f64::MAX, f64::min);
+    let (low, high, confidence) = if overlap_low <= overlap_high {
+        let confidence = match ranges.len() {
+            1 => "low",
+            2 => "medium",
+            _ => "high",
+        };
+        (overlap_low, overlap_high, confidence)
+    } else {
+        (
+            ranges.iter().map(|r| r.0).fold(f64::MAX, f64::min),
+            ranges.iter().map(|r| r.1).fold(f64::MIN, f64::max),
+            "low",
+        )
+    };
+
+    Some(SubscriberEstimate {
+        low: low.round() as u64,
+        high: high.round() as u64,
+        point: (low * high).sqrt().round() as u64,
+        confidence,
+        signals,
+    })
+}
+
+
+/// Channel-level fields shared by single and batch stats results
+///
+/// `estimate_from` holds `(views, likes, comments)` of the recent videos; when
+/// given, channels hiding their subscriber count get a `subscriber_count_estimate`.
+fn channel_to_py<'py>(
+    py: Python<'py>,
+    channel: &YouTubeChannel,
+    text: TextOptions,
+    estimate_from: Option<&[(u64, u64, u64)]>,
+) -> PyResult<&'py PyDict> {
+    let py_dict = PyDict::new(py);
+
+    // Channel basic info
+    py_dict.set_item("channel_id", &channel.id)?;
+    let localized = channel.snippet.localized.as_ref();
+    let title = localized.and_then(|l| l.title.as_deref()).unwrap_or(&channel.snippet.title);
+    let description = localized.and_then(|l| l.description.as_deref()).unwrap_or(&channel.snippet.description);
+    py_dict.set_item("channel_title", text.apply(title))?;
+    py_dict.set_item("channel_description", text.apply(description))?;
+    py_dict.set_item("published_at", &channel.snippet.published_at)?;
+
+    if let Some(custom_url) = &channel.snippet.custom_url {
+        py_dict.set_item("custom_url", custom_url)?;
+    }
+    py_dict.set_item("handle", channel_handle(channel.snippet.custom_url.as_deref()))?;
+    metrics::set_derived(py_dict, "handle", channel_handle(channel.snippet.custom_url.as_deref()), metrics::SOURCE_COMPUTED)?;
+
+    if let Some(country) = &channel.snippet.country {
+        py_dict.set_item("country", country)?;
+    }
+
+    // Channel statistics; some auto-generated channels have none at all
+    if let Some(stats) = &channel.statistics {
+        py_dict.set_item("statistics_unavailable", false)?;
+        py_dict.set_item("subscriber_count_hidden", stats.hidden_subscriber_count)?;
+
         // Parse subscriber count
         if !stats.hidden_subscriber_count {
             if let Some(sub_count) = &stats.subscriber_count {
@@ -395,188 +759,2831 @@ pub fn get_youtube_channel_stats(
             }
         } else {
             py_dict.set_item("subscriber_count", py.None())?;
-            py_dict.set_item("subscriber_count_hidden", true)?;
+
+            if let Some(recent) = estimate_from {
+                let total_views = stats.view_count.as_ref().and_then(|v| v.parse::<u64>().ok());
+                if let Some(estimate) = estimate_subscribers(total_views, recent) {
+                    let estimate_dict = PyDict::new(py);
+                    estimate_dict.set_item("low", estimate.low)?;
+                    estimate_dict.set_item("high", estimate.high)?;
+                    estimate_dict.set_item("point", estimate.point)?;
+                    estimate_dict.set_item("confidence", estimate.confidence)?;
+                    estimate_dict.set_item("signals", estimate.signals)?;
+                    estimate_dict.set_item("source", "estimated")?;
+                    py_dict.set_item("subscriber_count_estimate", estimate_dict)?;
+                    metrics::set_derived(py_dict, "subscriber_count_estimate", estimate_dict, metrics::SOURCE_COMPUTED)?;
+                }
+            }
         }
-        
+
         // Parse other statistics
         if let Some(view_count) = &stats.view_count {
             let views = view_count.parse::<u64>().unwrap_or(0);
             py_dict.set_item("total_view_count", views)?;
         }
-        
+
         if let Some(video_count) = &stats.video_count {
             let videos = video_count.parse::<u32>().unwrap_or(0);
             py_dict.set_item("video_count", videos)?;
         }
-        
-        // Thumbnails
-        let thumbnails = PyDict::new(py);
-        if let Some(default) = &channel.snippet.thumbnails.default {
-            thumbnails.set_item("default", &default.url)?;
-        }
-        if let Some(medium) = &channel.snippet.thumbnails.medium {
-            thumbnails.set_item("medium", &medium.url)?;
-        }
-        if let Some(high) = &channel.snippet.thumbnails.high {
-            thumbnails.set_item("high", &high.url)?;
+    } else {
+        py_dict.set_item("subscriber_count", py.None())?;
+        py_dict.set_item("total_view_count", py.None())?;
+        py_dict.set_item("video_count", py.None())?;
+        py_dict.set_item("statistics_unavailable", true)?;
+    }
+
+    // Unparsed API values: tells "0" apart from missing, hidden or unparsable counts
+    let stats = channel.statistics.as_ref();
+    let raw_statistics = PyDict::new(py);
+    raw_statistics.set_item("view_count", stats.and_then(|s| s.view_count.as_deref()))?;
+    raw_statistics.set_item("subscriber_count", stats.and_then(|s| s.subscriber_count.as_deref()))?;
+    raw_statistics.set_item("video_count", stats.and_then(|s| s.video_count.as_deref()))?;
+    raw_statistics.set_item("hidden_subscriber_count", stats.map(|s| s.hidden_subscriber_count))?;
+    py_dict.set_item("raw_statistics", raw_statistics)?;
+
+    // Thumbnails
+    py_dict.set_item("thumbnails", channel.snippet.thumbnails.to_py(py)?)?;
+    py_dict.set_item("thumbnail_details", channel.snippet.thumbnails.details_to_py(py)?)?;
+
+    // Branding settings
+    if let Some(branding) = &channel.branding_settings {
+        if let Some(channel_branding) = &branding.channel {
+            if let Some(keywords) = &channel_branding.keywords {
+                py_dict.set_item("channel_keywords", utils::parse_keywords(keywords))?;
+                py_dict.set_item("channel_keywords_raw", keywords)?;
+            }
         }
-        py_dict.set_item("thumbnails", thumbnails)?;
-        
-        // Branding settings
-        if let Some(branding) = &channel.branding_settings {
-            if let Some(channel_branding) = &branding.channel {
-                if let Some(keywords) = &channel_branding.keywords {
-                    py_dict.set_item("channel_keywords", keywords)?;
+
+        let channel_branding = branding.channel.as_ref();
+        let branding_dict = PyDict::new(py);
+        branding_dict.set_item("banner_url", branding.image.as_ref().and_then(|image| image.banner_external_url.as_deref()))?;
+        branding_dict.set_item("unsubscribed_trailer", channel_branding.and_then(|b| b.unsubscribed_trailer.as_deref()))?;
+        branding_dict.set_item("country", channel_branding.and_then(|b| b.country.as_deref()))?;
+        branding_dict.set_item("default_language", channel_branding.and_then(|b| b.default_language.as_deref()))?;
+        branding_dict.set_item("keywords", channel_branding.and_then(|b| b.keywords.as_deref()))?;
+        py_dict.set_item("branding", branding_dict)?;
+    }
+
+    // Topic categories as readable names plus the raw URLs
+    let topic_names = TopicDetails::set_items(channel.topic_details.as_ref(), py_dict)?;
+    metrics::set_derived(py_dict, "topic_categories", topic_names, metrics::SOURCE_COMPUTED)?;
+
+    // Status; the kids designation is needed by compliance tooling
+    let status = channel.status.as_ref();
+    py_dict.set_item("privacy_status", status.and_then(|s| s.privacy_status.as_deref()))?;
+    py_dict.set_item("is_linked", status.and_then(|s| s.is_linked))?;
+    py_dict.set_item("made_for_kids", status.and_then(|s| s.made_for_kids))?;
+    py_dict.set_item("self_declared_made_for_kids", status.and_then(|s| s.self_declared_made_for_kids))?;
+
+    // Channel URL
+    py_dict.set_item("channel_url", format!("https://www.youtube.com/channel/{}", channel.id))?;
+
+    // User metadata attached via metadata.set_channel_metadata
+    if let Some(channel_metadata) = metadata::channel_metadata(py, &channel.id)? {
+        py_dict.set_item("metadata", channel_metadata)?;
+    }
+
+    Ok(py_dict)
+}
+
+/// Get YouTube channel statistics and recent videos
+/// 
+/// # Arguments
+/// * `channel_identifier` - Can be channel ID, username, or custom URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `video_count` - Number of recent videos to fetch (default: 10)
+/// * `engagement_weights` - `(like_weight, comment_weight)` for the
+///   `engagement_score` metrics (default: `(1.0, 2.0)`)
+/// * `estimate_hidden_subscribers` - For channels hiding their subscriber count,
+///   add a `subscriber_count_estimate` range (`low`, `high`, `point`,
+///   `confidence` and the `signals` used: `total_views`, `recent_views`,
+///   `recent_engagement`) derived from view and like/comment counts against
+///   peer-channel ratios (default: False)
+/// * `normalize_unicode` - NFC-normalize titles and descriptions (default: False)
+/// * `strip_emoji` - Remove emoji from titles and descriptions (default: False)
+/// * `hl` - Language code (e.g. `"es"`); the channel title and description are
+///   returned in that language when the channel provides a localization
+/// * `classify_shorts` - Label each recent video as a Short or a regular video
+///   (`is_short`, `short_detection`) and add a `video_classes` summary with
+///   counts and view totals (default: False)
+/// * `engagement_rate_weights` - `(like_weight, comment_weight)` for the
+///   `engagement_rate` of each video and of the summary (default: `(1.0, 1.0)`)
+/// 
+/// # Returns
+/// * PyResult<PyObject> - Dictionary containing channel stats, recent videos
+///   (with `duration`, `duration_seconds`, `definition`, `dimension`,
+///   `licensed_content`, `caption` and `region_restriction` from
+///   contentDetails, and `privacy_status`, `upload_status`, `license`,
+///   `embeddable`, `public_stats_viewable` and `made_for_kids` from status,
+///   `has_paid_product_placement`, True for declared sponsorships, and
+///   `engagement_rate`, see `engagement_rate_weights`, and
+///   `likes_hidden` / `comments_disabled`, True when the uploader hid the
+///   like count or turned comments off and the count key is left out) and an
+///   `engagement_summary` over them (`average_views`, `median_views`,
+///   `like_view_ratio`, `comment_view_ratio`, `engagement_rate`, each over the
+///   videos where its counts are present, and `likes_hidden_count`,
+///   `comments_disabled_count`, `engagement_excluded_count`)
+#[pyfunction]
+#[pyo3(signature = (channel_identifier, api_key=None, video_count=None, estimate_hidden_subscribers=false, engagement_weights=None, normalize_unicode=false, strip_emoji=false, hl=None, classify_shorts=false, engagement_rate_weights=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn get_youtube_channel_stats(
+    channel_identifier: String,
+    api_key: Option<Credentials>,
+    video_count: Option<u32>,
+    estimate_hidden_subscribers: bool,
+    engagement_weights: Option<(f64, f64)>,
+    normalize_unicode: bool,
+    strip_emoji: bool,
+    hl: Option<String>,
+    classify_shorts: bool,
+    engagement_rate_weights: Option<(f64, f64)>,
+) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let weights = EngagementWeights::from_tuple(engagement_weights, EngagementWeights::default())?;
+        let rate_weights = EngagementWeights::from_tuple(engagement_rate_weights, metrics::ENGAGEMENT_RATE_WEIGHTS)?;
+        let text = TextOptions::new(normalize_unicode, strip_emoji);
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let base_url = "https://www.googleapis.com/youtube/v3";
+        let videos_to_fetch = video_count.unwrap_or(10);
+    
+        // First, try to get channel info
+        // Missing channels keep their ChannelNotFoundError / ChannelUnavailableError type,
+        // and a disabled API its ApiNotEnabledError with the enable URL
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_identifier, hl.as_deref())
+            .map_err(|e| if Python::with_gil(|py| {
+                e.is_instance_of::<error::ChannelNotFoundError>(py) || e.is_instance_of::<error::ApiNotEnabledError>(py)
+            }) {
+                e
+            } else {
+                PyValueError::new_err(format!("Failed to fetch channel: {}", e))
+            })?;
+
+        // Get recent videos if we have an uploads playlist
+        let mut recent_videos = Vec::new();
+        // Playlist entries the videos endpoint returned nothing for, with their availability
+        let mut unavailable_videos: Vec<(String, &'static str)> = Vec::new();
+    
+        if let Some(content_details) = &channel.content_details {
+            if let Some(uploads_playlist) = &content_details.related_playlists.uploads {
+                // playlistItems costs 1 unit versus 100 for search?channelId=
+                if let Ok(uploads) = playlist::fetch_playlist_items(&client, &api_key, uploads_playlist, Some(videos_to_fetch as usize)) {
+                    let video_ids: Vec<String> = uploads.iter()
+                        .map(|item| item.content_details.video_id.clone())
+                        .collect();
+                
+                    // Fetch detailed statistics for these videos, 50 IDs per request
+                    for batch in video_ids.chunks(MAX_RESULTS_PER_PAGE) {
+                        let video_stats_url = format!(
+                            "{}/videos?part=statistics,snippet,status,contentDetails,paidProductPlacementDetails&id={}",
+                            base_url, batch.join(",")
+                        );
+                    
+                        if let Ok(stats_response) = client::send_get(&client, &api_key, &video_stats_url) {
+                            if stats_response.is_success() {
+                                if let Ok(stats_data) = stats_response.json::<YouTubeVideoListResponse>() {
+                                    recent_videos.extend(stats_data.items);
+                                }
+                            }
+                        }
+                    }
+                    // Newest first, as search?order=date returned them
+                    recent_videos.sort_by(|a, b| b.snippet.published_at.cmp(&a.snippet.published_at));
+                    unavailable_videos = uploads.iter()
+                        .filter(|item| !recent_videos.iter().any(|video| video.id == item.content_details.video_id))
+                        .map(|item| (item.content_details.video_id.clone(), missing_video_availability(item)))
+                        .collect();
                 }
             }
         }
+    
+        // Short or regular, with how it was decided, per video ID
+        let mut short_classes: HashMap<&str, (Option<bool>, &'static str)> = HashMap::new();
+        if classify_shorts {
+            let web_client = client::build_web_client(&api_key)?;
+            for video in &recent_videos {
+                let duration = video.content_details.as_ref().and_then(VideoContentDetails::duration_seconds);
+                let (is_short, method) = classify_short(&web_client, &video.id, duration);
+                short_classes.insert(video.id.as_str(), (is_short, method));
+            }
+        }
+
+        // Convert to Python dictionary
+        Python::with_gil(|py| {
+            let recent_counts: Vec<(u64, u64, u64)> = recent_videos.iter()
+                .filter_map(|v| v.statistics.as_ref())
+                .filter(|s| s.view_count.is_some())
+                .map(VideoStatistics::counts)
+                .collect();
+            let estimate_from = if estimate_hidden_subscribers { Some(recent_counts.as_slice()) } else { None };
+            let py_dict = channel_to_py(py, &channel, text, estimate_from)?;
         
-        // Recent videos
-        let py_videos = PyList::new(py, recent_videos.iter().map(|video| {
-            let video_dict = PyDict::new(py);
-            
-            // Try to use actual ID if available, otherwise use the nested structure
-            let video_id = if video.id.video_id.is_empty() {
-                // Sometimes the ID might be directly in a different field
-                video.id.video_id.clone()
-            } else {
-                video.id.video_id.clone()
-            };
+            // Recent videos
+            let py_videos = PyList::new(py, recent_videos.iter().map(|video| {
+                let video_dict = PyDict::new(py);
+                let video_id = &video.id;
             
-            video_dict.set_item("video_id", &video_id).unwrap();
-            video_dict.set_item("title", &video.snippet.title).unwrap();
-            video_dict.set_item("published_at", &video.snippet.published_at).unwrap();
+                video_dict.set_item("video_id", video_id).unwrap();
+                video_dict.set_item("title", text.apply(&video.snippet.title)).unwrap();
+                video_dict.set_item("published_at", &video.snippet.published_at).unwrap();
+                video_dict.set_item("availability", availability(video.status.as_ref())).unwrap();
+                let availability_source = if video.status.is_some() { metrics::SOURCE_API } else { metrics::SOURCE_COMPUTED };
+                metrics::set_derived(video_dict, "availability", availability(video.status.as_ref()), availability_source).unwrap();
             
-            if let Some(desc) = &video.snippet.description {
-                video_dict.set_item("description", desc).unwrap();
-            }
-            
-            // Video statistics
-            if let Some(stats) = &video.statistics {
-                if let Some(views) = &stats.view_count {
-                    let view_count = views.parse::<u64>().unwrap_or(0);
-                    video_dict.set_item("view_count", view_count).unwrap();
+                if let Some(desc) = &video.snippet.description {
+                    video_dict.set_item("description", text.apply(desc)).unwrap();
                 }
+            
+                let raw_statistics = PyDict::new(py);
+                let stats = video.statistics.as_ref();
+                raw_statistics.set_item("view_count", stats.and_then(|s| s.view_count.as_deref())).unwrap();
+                raw_statistics.set_item("like_count", stats.and_then(|s| s.like_count.as_deref())).unwrap();
+                raw_statistics.set_item("comment_count", stats.and_then(|s| s.comment_count.as_deref())).unwrap();
+                video_dict.set_item("raw_statistics", raw_statistics).unwrap();
+
+                // Video statistics
+                if let Some(stats) = &video.statistics {
+                    if let Some(views) = &stats.view_count {
+                        let view_count = views.parse::<u64>().unwrap_or(0);
+                        video_dict.set_item("view_count", view_count).unwrap();
+                    }
                 
-                if let Some(likes) = &stats.like_count {
-                    let like_count = likes.parse::<u64>().unwrap_or(0);
-                    video_dict.set_item("like_count", like_count).unwrap();
-                }
+                    if let Some(likes) = &stats.like_count {
+                        let like_count = likes.parse::<u64>().unwrap_or(0);
+                        video_dict.set_item("like_count", like_count).unwrap();
+                    }
                 
-                if let Some(comments) = &stats.comment_count {
-                    let comment_count = comments.parse::<u64>().unwrap_or(0);
-                    video_dict.set_item("comment_count", comment_count).unwrap();
+                    if let Some(comments) = &stats.comment_count {
+                        let comment_count = comments.parse::<u64>().unwrap_or(0);
+                        video_dict.set_item("comment_count", comment_count).unwrap();
+                    }
+
+                    let (views, likes, comments) = stats.counts();
+                    let engagement_score = metrics::engagement(views, likes, comments, weights);
+                    video_dict.set_item("engagement_score", engagement_score).unwrap();
+                    metrics::set_derived(video_dict, "engagement_score", engagement_score, metrics::SOURCE_COMPUTED).unwrap();
                 }
-            }
+                VideoStatistics::set_hidden_items(video.statistics.as_ref(), video_dict).unwrap();
+                let engagement_rate = video.statistics.as_ref().and_then(|stats| stats.engagement_rate(rate_weights));
+                video_dict.set_item("engagement_rate", engagement_rate).unwrap();
+                metrics::set_derived(video_dict, "engagement_rate", engagement_rate, metrics::SOURCE_COMPUTED).unwrap();
             
-            // Video URL
-            video_dict.set_item("video_url", format!("https://www.youtube.com/watch?v={}", video_id)).unwrap();
+                video_dict.set_item("thumbnails", video.snippet.thumbnails.to_py(py).unwrap()).unwrap();
+                video_dict.set_item("thumbnail_details", video.snippet.thumbnails.details_to_py(py).unwrap()).unwrap();
+                VideoContentDetails::set_items(video.content_details.as_ref(), video_dict).unwrap();
+                VideoStatus::set_items(video.status.as_ref(), video_dict).unwrap();
+                video_dict.set_item("has_paid_product_placement", video.paid_product_placement_details.as_ref().and_then(|p| p.has_paid_product_placement)).unwrap();
+
+                if let Some((is_short, method)) = short_classes.get(video_id.as_str()) {
+                    video_dict.set_item("is_short", is_short).unwrap();
+                    video_dict.set_item("short_detection", method).unwrap();
+                    metrics::set_derived(video_dict, "is_short", is_short, metrics::SOURCE_COMPUTED).unwrap();
+                }
+
+                // Video URL
+                video_dict.set_item("video_url", format!("https://www.youtube.com/watch?v={}", video_id)).unwrap();
             
-            video_dict
-        }));
-        
-        py_dict.set_item("recent_videos", py_videos)?;
+                video_dict
+            }));
+            // Private and deleted uploads close the list instead of silently vanishing
+            for (video_id, availability) in &unavailable_videos {
+                let video_dict = PyDict::new(py);
+                video_dict.set_item("video_id", video_id)?;
+                video_dict.set_item("title", py.None())?;
+                video_dict.set_item("published_at", py.None())?;
+                video_dict.set_item("availability", availability)?;
+                // Inferred from the playlist entry, not reported by the videos endpoint
+                metrics::set_derived(video_dict, "availability", availability, metrics::SOURCE_COMPUTED)?;
+                video_dict.set_item("video_url", format!("https://www.youtube.com/watch?v={}", video_id))?;
+                py_videos.append(video_dict)?;
+            }
         
-        // Calculate totals from recent videos
-        let total_recent_views: u64 = recent_videos.iter()
-            .filter_map(|v| v.statistics.as_ref())
-            .filter_map(|s| s.view_count.as_ref())
-            .filter_map(|v| v.parse::<u64>().ok())
-            .sum();
+            py_dict.set_item("recent_videos", py_videos)?;
         
-        let total_recent_likes: u64 = recent_videos.iter()
-            .filter_map(|v| v.statistics.as_ref())
-            .filter_map(|s| s.like_count.as_ref())
-            .filter_map(|l| l.parse::<u64>().ok())
-            .sum();
+            // Calculate totals from recent videos
+            let total_recent_views: u64 = recent_videos.iter()
+                .filter_map(|v| v.statistics.as_ref())
+                .filter_map(|s| s.view_count.as_ref())
+                .filter_map(|v| v.parse::<u64>().ok())
+                .sum();
         
-        let total_recent_comments: u64 = recent_videos.iter()
-            .filter_map(|v| v.statistics.as_ref())
-            .filter_map(|s| s.comment_count.as_ref())
-            .filter_map(|c| c.parse::<u64>().ok())
-            .sum();
+            let total_recent_likes: u64 = recent_videos.iter()
+                .filter_map(|v| v.statistics.as_ref())
+                .filter_map(|s| s.like_count.as_ref())
+                .filter_map(|l| l.parse::<u64>().ok())
+                .sum();
         
-        py_dict.set_item("total_recent_views", total_recent_views)?;
-        py_dict.set_item("total_recent_likes", total_recent_likes)?;
-        py_dict.set_item("total_recent_comments", total_recent_comments)?;
+            let total_recent_comments: u64 = recent_videos.iter()
+                .filter_map(|v| v.statistics.as_ref())
+                .filter_map(|s| s.comment_count.as_ref())
+                .filter_map(|c| c.parse::<u64>().ok())
+                .sum();
         
-        // Channel URL
-        py_dict.set_item("channel_url", format!("https://www.youtube.com/channel/{}", channel.id))?;
+            py_dict.set_item("total_recent_views", total_recent_views)?;
+            py_dict.set_item("total_recent_likes", total_recent_likes)?;
+            py_dict.set_item("total_recent_comments", total_recent_comments)?;
+            metrics::set_derived(py_dict, "total_recent_views", total_recent_views, metrics::SOURCE_COMPUTED)?;
+            metrics::set_derived(py_dict, "total_recent_likes", total_recent_likes, metrics::SOURCE_COMPUTED)?;
+            metrics::set_derived(py_dict, "total_recent_comments", total_recent_comments, metrics::SOURCE_COMPUTED)?;
+
+            let video_counts: Vec<metrics::VideoCounts> = recent_videos.iter()
+                .filter_map(|v| v.statistics.as_ref())
+                .map(|s| s.reported_counts())
+                .collect();
+            let engagement_score = metrics::aggregate_engagement(&video_counts, weights);
+            py_dict.set_item("engagement_score", engagement_score)?;
+            metrics::set_derived(py_dict, "engagement_score", engagement_score, metrics::SOURCE_COMPUTED)?;
+            let engagement_summary = metrics::engagement_summary(py, &video_counts, rate_weights)?;
+            py_dict.set_item("engagement_summary", engagement_summary)?;
+            metrics::set_derived(py_dict, "engagement_summary", engagement_summary, metrics::SOURCE_COMPUTED)?;
+
+            if classify_shorts {
+                let video_classes = PyDict::new(py);
+                for (class, wanted) in [("shorts", Some(true)), ("regular", Some(false)), ("unclassified", None)] {
+                    let videos: Vec<&YouTubeVideo> = recent_videos.iter()
+                        .filter(|video| short_classes.get(video.id.as_str()).map(|(is_short, _)| *is_short) == Some(wanted))
+                        .collect();
+                    let summary = PyDict::new(py);
+                    summary.set_item("count", videos.len())?;
+                    summary.set_item("total_views", videos.iter()
+                        .filter_map(|video| video.statistics.as_ref())
+                        .map(|stats| stats.counts().0)
+                        .sum::<u64>())?;
+                    video_classes.set_item(class, summary)?;
+                }
+                py_dict.set_item("video_classes", video_classes)?;
+                metrics::set_derived(py_dict, "video_classes", video_classes, metrics::SOURCE_COMPUTED)?;
+            }
         
-        Ok(py_dict.into())
+            Ok(py_dict.into())
+        })
     })
 }
 
-/// Search for YouTube channels by query
+/// One channels.list request for up to 50 IDs; Err carries the API's reason
+fn fetch_channel_batch(client: &Client, credentials: &Credentials, batch: &[String]) -> Result<Vec<YouTubeChannel>, String> {
+    let url = format!(
+        "{}/channels?part={}&id={}&maxResults={}",
+        client::BASE_URL, CHANNEL_PARTS, batch.join(","), MAX_RESULTS_PER_PAGE
+    );
+    let response = client::send_get(client, credentials, &url).map_err(|e| e.to_string())?;
+    if !response.is_success() {
+        let reason = response.error().map(|error| error.message).unwrap_or_default();
+        return Err(format!("{} {}", response.status, reason).trim_end().to_string());
+    }
+    let data: YouTubeChannelResponse = response.json()
+        .map_err(|e| format!("Failed to parse channel data: {}", e))?;
+    Ok(data.items)
+}
+
+/// Fetch channels by ID, 50 per request; unknown IDs are simply absent
+fn fetch_channels_by_id(
+    client: &Client,
+    credentials: &Credentials,
+    channel_ids: &[String],
+) -> PyResult<HashMap<String, YouTubeChannel>> {
+    let mut channels = HashMap::new();
+    for batch in channel_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let items = fetch_channel_batch(client, credentials, batch)
+            .map_err(|e| PyValueError::new_err(format!("Failed to fetch channels: {}", e)))?;
+        channels.extend(items.into_iter().map(|channel| (channel.id.clone(), channel)));
+    }
+    Ok(channels)
+}
+
+/// Like `fetch_channels_by_id`, but a failing batch is retried ID by ID
+///
+/// The API rejects a whole batch when one ID in it is malformed; retrying
+/// singly confines the failure to that ID. Returns the channels found and
+/// the error of every ID that could not be fetched.
+fn fetch_channels_isolating_errors(
+    client: &Client,
+    credentials: &Credentials,
+    channel_ids: &[String],
+) -> (HashMap<String, YouTubeChannel>, HashMap<String, String>) {
+    let mut channels = HashMap::new();
+    let mut errors = HashMap::new();
+    for batch in channel_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let items = match fetch_channel_batch(client, credentials, batch) {
+            Ok(items) => items,
+            Err(batch_error) if batch.len() == 1 => {
+                errors.insert(batch[0].clone(), batch_error);
+                continue;
+            }
+            Err(_) => {
+                let mut items = Vec::new();
+                for channel_id in batch {
+                    match fetch_channel_batch(client, credentials, std::slice::from_ref(channel_id)) {
+                        Ok(found) => items.extend(found),
+                        Err(error) => {
+                            errors.insert(channel_id.clone(), error);
+                        }
+                    }
+                }
+                items
+            }
+        };
+        channels.extend(items.into_iter().map(|channel| (channel.id.clone(), channel)));
+    }
+    (channels, errors)
+}
+
+/// Get statistics for many channels, 50 channel IDs per request
+///
+/// Costs 1 quota unit per 50 channels. Recent videos are not fetched; use
+/// `get_youtube_channel_stats` for those. Returned IDs are cross-checked
+/// against the requested ones, and a batch the API rejects is retried ID by
+/// ID so a single malformed ID cannot hide the other results.
+///
+/// # Arguments
+/// * `channel_ids` - Channel IDs (`UC...`) or @handles (1 extra unit each);
+///   duplicates are fetched once
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `normalize_unicode` - NFC-normalize titles and descriptions (default: False)
+/// * `strip_emoji` - Remove emoji from titles and descriptions (default: False)
+/// * `with_status` - Return per-ID statuses instead of raising when some IDs
+///   fail (default: False)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary mapping each requested ID or handle to its
+///   channel stats, or None when the channel does not exist. With `with_status`,
+///   `{"results": ..., "status": {id: {"status": "ok" | "not_found" | "unavailable"
+///   | "error", "error": message or None}}}`; `unavailable` channels were taken
+///   down and carry a `reason` (`terminated`, `suspended` or `unavailable`),
+///   found by checking the channel page of each missing ID on youtube.com
+#[pyfunction]
+#[pyo3(signature = (channel_ids, api_key=None, normalize_unicode=false, strip_emoji=false, with_status=false))]
+pub fn get_youtube_channels_stats(
+    channel_ids: Vec<String>,
+    api_key: Option<Credentials>,
+    normalize_unicode: bool,
+    strip_emoji: bool,
+    with_status: bool,
+) -> PyResult<PyObject> {
+    let text = TextOptions::new(normalize_unicode, strip_emoji);
+    // Every channel shares the provenance of the batch requests
+    let (fetched, requests) = client::track_requests(|| -> PyResult<_> {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+
+        // Requested identifiers in order, each with the channel ID it stands for
+        let mut requested: Vec<(String, Option<String>)> = Vec::with_capacity(channel_ids.len());
+        let mut errors: HashMap<String, String> = HashMap::new();
+        for identifier in channel_ids {
+            let identifier = identifier.trim().to_string();
+            if requested.iter().any(|(seen, _)| *seen == identifier) {
+                continue;
+            }
+            let channel_id = if identifier.starts_with("UC") {
+                Some(identifier.clone())
+            } else if identifier.starts_with('@') {
+                match lookup_channel_id(&client, &api_key, "forHandle", &identifier) {
+                    Ok(channel_id) => channel_id,
+                    Err(e) => {
+                        errors.insert(identifier.clone(), e.to_string());
+                        None
+                    }
+                }
+            } else {
+                return Err(PyValueError::new_err(format!("Not a channel ID or @handle: {}", identifier)));
+            };
+            requested.push((identifier, channel_id));
+        }
+
+        let mut unique_ids: Vec<String> = Vec::with_capacity(requested.len());
+        for channel_id in requested.iter().filter_map(|(_, channel_id)| channel_id.as_ref()) {
+            if !unique_ids.contains(channel_id) {
+                unique_ids.push(channel_id.clone());
+            }
+        }
+        let (channels, id_errors) = fetch_channels_isolating_errors(&client, &api_key, &unique_ids);
+        for (identifier, channel_id) in &requested {
+            if let Some(error) = channel_id.as_ref().and_then(|channel_id| id_errors.get(channel_id)) {
+                errors.insert(identifier.clone(), error.clone());
+            }
+        }
+        // Tell taken-down channels apart from mistyped IDs
+        let mut unavailable: HashMap<String, &'static str> = HashMap::new();
+        if with_status {
+            for (identifier, channel_id) in &requested {
+                if let Some(channel_id) = channel_id.as_ref().filter(|id| !channels.contains_key(*id) && !errors.contains_key(identifier)) {
+                    if let Some(reason) = channel_unavailable_reason(&client, channel_id) {
+                        unavailable.insert(identifier.clone(), reason);
+                    }
+                }
+            }
+        }
+        Ok((requested, channels, errors, unavailable))
+    });
+    let (requested, channels, errors, unavailable) = fetched?;
+
+    if !with_status && !errors.is_empty() {
+        let failures: Vec<String> = requested.iter()
+            .filter_map(|(identifier, _)| errors.get(identifier).map(|error| format!("{} ({})", identifier, error)))
+            .collect();
+        return Err(PyValueError::new_err(redact(&format!("Failed to fetch channels: {}", failures.join(", ")))));
+    }
+
+    Python::with_gil(|py| {
+        let results = PyDict::new(py);
+        let statuses = PyDict::new(py);
+        for (identifier, channel_id) in &requested {
+            let status = PyDict::new(py);
+            match channel_id.as_ref().and_then(|channel_id| channels.get(channel_id)) {
+                Some(channel) => {
+                    let channel_dict = channel_to_py(py, channel, text, None)?;
+                    channel_dict.set_item("_meta", client::meta_to_py(py, &requests)?)?;
+                    results.set_item(identifier, channel_dict)?;
+                    status.set_item("status", "ok")?;
+                }
+                None => {
+                    results.set_item(identifier, py.None())?;
+                    match unavailable.get(identifier) {
+                        Some(reason) => {
+                            status.set_item("status", "unavailable")?;
+                            status.set_item("reason", reason)?;
+                        }
+                        None => status.set_item("status", if errors.contains_key(identifier) { "error" } else { "not_found" })?,
+                    }
+                }
+            }
+            status.set_item("error", errors.get(identifier).map(|error| redact(error)))?;
+            statuses.set_item(identifier, status)?;
+        }
+
+        if !with_status {
+            return Ok(results.into());
+        }
+        let report = PyDict::new(py);
+        report.set_item("results", results)?;
+        report.set_item("status", statuses)?;
+        Ok(report.into())
+    })
+}
+
+// Quota units charged per search.list page
+const SEARCH_QUOTA_COST: u32 = 100;
+
+// Music topics accepted by search `topicId` (Freebase IDs), exported as account.TOPICS
+pub const SEARCH_TOPICS: [(&str, &str); 15] = [
+    ("music", "/m/04rlf"),
+    ("christian", "/m/02mscn"),
+    ("classical", "/m/0ggq0m"),
+    ("country", "/m/01lyv"),
+    ("electronic", "/m/02lkt"),
+    ("hip_hop", "/m/0glt670"),
+    ("independent", "/m/05rwpb"),
+    ("jazz", "/m/03_d0"),
+    ("asian", "/m/028sqc"),
+    ("latin", "/m/0g293"),
+    ("pop", "/m/064t9"),
+    ("reggae", "/m/06cqb"),
+    ("rnb", "/m/06j6l"),
+    ("rock", "/m/06by7"),
+    ("soul", "/m/0gywn"),
+];
+
+/// Topic ID for a `SEARCH_TOPICS` name (`"hip hop"`, `"Hip-Hop"`) or a raw `/m/...` ID
+fn search_topic_id(topic: &str) -> PyResult<String> {
+    if topic.starts_with("/m/") || topic.starts_with("/g/") {
+        return Ok(topic.to_string());
+    }
+    let key = topic.trim().to_lowercase().replace([' ', '-'], "_");
+    SEARCH_TOPICS.iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, id)| id.to_string())
+        .ok_or_else(|| {
+            let names: Vec<&str> = SEARCH_TOPICS.iter().map(|(name, _)| *name).collect();
+            PyValueError::new_err(format!("Unknown topic '{}'; use a topic ID or one of: {}", topic, names.join(", ")))
+        })
+}
+
+/// Search for YouTube channels by query
 /// 
 /// # Arguments
 /// * `query` - Search query string
-/// * `api_key` - YouTube Data API v3 key  
-/// * `max_results` - Maximum number of results to return (default: 5, max: 50)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `max_results` - Maximum number of results to return (default: 5); more
+///   than 50 results are fetched over several pages
+/// * `max_pages` - Stop after this many result pages
+/// * `max_quota` - Stop before a page would push quota use past this many
+///   units (each page costs 100)
+/// * `stop_when` - Callable receiving each result dict; once it returns True,
+///   no further pages are fetched
+/// * `normalize_unicode` - NFC-normalize titles and descriptions (default: False)
+/// * `strip_emoji` - Remove emoji from titles and descriptions (default: False)
+/// * `topic` - Only channels in this topic: a name from `account.TOPICS`
+///   (`"music"`, `"hip_hop"`, `"pop"`, ...) or a Freebase topic ID such as
+///   `"/m/04rlf"`
 ///
 /// # Returns
 /// * PyResult<PyObject> - List of channels matching the search
 #[pyfunction]
+#[pyo3(signature = (query, api_key=None, max_results=None, max_pages=None, max_quota=None, stop_when=None, normalize_unicode=false, strip_emoji=false, topic=None))]
+#[allow(clippy::too_many_arguments)]
 pub fn search_youtube_channels(
     query: String,
-    api_key: String,
+    api_key: Option<Credentials>,
     max_results: Option<u32>,
+    max_pages: Option<u32>,
+    max_quota: Option<u32>,
+    stop_when: Option<PyObject>,
+    normalize_unicode: bool,
+    strip_emoji: bool,
+    topic: Option<String>,
 ) -> PyResult<PyObject> {
-    let client = Client::new();
+    let text = TextOptions::new(normalize_unicode, strip_emoji);
+    let topic_id = topic.as_deref().map(search_topic_id).transpose()?;
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
     let base_url = "https://www.googleapis.com/youtube/v3";
-    let results_count = max_results.unwrap_or(5).min(50);
-    
-    let search_url = format!(
-        "{}/search?part=snippet&type=channel&q={}&maxResults={}&key={}",
-        base_url, query, results_count, api_key
-    );
-    
-    let response = client.get(&search_url)
-        .header("Accept", "application/json")
-        .send()
-        .map_err(|e| PyValueError::new_err(format!("Request failed: {}", e)))?;
+    let results_count = max_results.unwrap_or(5);
 
-    if !response.status().is_success() {
-        let status = response.status();
-        let error_text = response.text()
-            .unwrap_or_else(|_| "Could not read error response".to_string());
-        return Err(PyValueError::new_err(format!("Search failed: {} - {}", status, error_text)));
-    }
+    let quota_pages = max_quota.map(|quota| quota / SEARCH_QUOTA_COST);
+    let page_limit = match (max_pages, quota_pages) {
+        (Some(pages), Some(quota_pages)) => Some(pages.min(quota_pages)),
+        (pages, quota_pages) => pages.or(quota_pages),
+    };
+
+    // Default region/language from an AuthConfig, else from `configure()`
+    let config = client::effective_config(&api_key);
+    let region = config.as_ref().and_then(|config| config.default_region()).map(str::to_string);
+    let language = config.as_ref().and_then(|config| config.default_language()).map(str::to_string);
 
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        let mut page_token: Option<String> = None;
+        let mut pages = 0;
+
+        loop {
+            let remaining = results_count.saturating_sub(results.len() as u32);
+            if remaining == 0 || page_limit.is_some_and(|limit| pages >= limit) {
+                break;
+            }
 
-    let response_text = response.text()
-        .map_err(|e| PyValueError::new_err(format!("Failed to read response text: {}", e)))?;
+            let mut params = vec![
+                ("part", "snippet".to_string()),
+                ("type", "channel".to_string()),
+                ("q", query.clone()),
+                ("maxResults", remaining.min(50).to_string()),
+            ];
+            if let Some(topic_id) = &topic_id {
+                params.push(("topicId", topic_id.clone()));
+            }
 
-    // --- Log the raw JSON from YouTube ---
-    println!("--- Full YouTube search response ---\n{}", response_text);
+            if let Some(region) = &region {
+                params.push(("regionCode", region.clone()));
+            }
+            if let Some(language) = &language {
+                params.push(("relevanceLanguage", language.clone()));
+            }
+            if let Some(token) = &page_token {
+                params.push(("pageToken", token.clone()));
+            }
 
+            let search_url = Url::parse_with_params(&format!("{}/search", base_url), &params)
+                .map_err(|e| PyValueError::new_err(format!("Invalid search URL: {}", e)))?;
 
+            let response = client::send_get(&client, &api_key, search_url.as_str())?;
 
-    
-    let search_results: YouTubeSearchResponse = serde_json::from_str(&response_text)
-        .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
-    
-    Python::with_gil(|py| {
-        let py_dicts: Vec<Py<PyDict>> = search_results.items.iter()
-            .filter_map(|item| {
+            if !response.is_success() {
+                return Err(PyValueError::new_err(redact(&format!("Search failed: {} - {}", response.status, response.body))));
+            }
+
+            let response_text = response.body;
+
+            let search_results: YouTubeSearchResponse = serde_json::from_str(&response_text)
+                .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
+            pages += 1;
+
+            let mut stop = false;
+            for item in &search_results.items {
                 if let Some(channel_id) = &item.id.channel_id {
                     let channel_dict = PyDict::new(py);
-                    channel_dict.set_item("channel_id", channel_id).unwrap();
-                    channel_dict.set_item("title", &item.snippet.title).unwrap();
-                    channel_dict.set_item("description", &item.snippet.description).unwrap();
+                    channel_dict.set_item("channel_id", channel_id)?;
+                    channel_dict.set_item("title", text.apply(&item.snippet.title))?;
+                    channel_dict.set_item("description", text.apply(&item.snippet.description))?;
                     channel_dict.set_item(
                         "channel_url",
                         format!("https://www.youtube.com/channel/{}", channel_id)
-                    ).unwrap();
-                    Some(channel_dict.into())
-                } else {
-                    None
+                    )?;
+                    results.append(channel_dict)?;
+
+                    if let Some(predicate) = &stop_when {
+                        if predicate.call1(py, (channel_dict,))?.is_true(py)? {
+                            stop = true;
+                        }
+                    }
                 }
-            })
+            }
+
+            page_token = search_results.next_page_token;
+            if stop || page_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(results.into())
+    })
+}
+
+// Avatar variants the API returns for channels (88, 240 and 800 px)
+const AVATAR_SIZES: [&str; 3] = ["default", "medium", "high"];
+
+/// File extension for an image content type; None for anything but an image
+pub(crate) fn image_extension(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "image/jpeg" | "image/jpg" => Some("jpg"),
+        "image/png" => Some("png"),
+        "image/webp" => Some("webp"),
+        "image/gif" => Some("gif"),
+        _ => None,
+    }
+}
+
+// A downloaded channel image, saved to `path` when a directory was given
+struct ChannelImage<'a> {
+    kind: &'static str,
+    size: &'static str,
+    url: &'a str,
+    content_type: String,
+    bytes: Vec<u8>,
+    path: Option<PathBuf>,
+}
+
+/// Download a channel's avatar and banner images
+///
+/// Uses the image URLs from the channel lookup (1 quota unit); the downloads
+/// themselves cost no quota. Redirects are followed and responses that are
+/// not images (such as error pages) raise a ValueError.
+///
+/// # Arguments
+/// * `identifier` - Channel ID, @handle, legacy username or channel URL
+/// * `dest_dir` - Directory to save the files in, created if needed, as
+///   `<channel_id>_<kind>_<size>.<ext>`; without it the bytes are returned
+/// * `sizes` - Avatar variants: `"default"`, `"medium"` and/or `"high"`
+///   (default: `["high"]`)
+/// * `include_banner` - Also download the channel banner (default: True)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channel_id`, `images` (list of
+///   `kind` (`avatar` or `banner`), `size`, `url`, `content_type`,
+///   `byte_count` and either `path` or `data` dictionaries) and `missing`
+///   (images the channel does not have, as `"<kind>_<size>"`)
+#[pyfunction]
+#[pyo3(signature = (identifier, dest_dir=None, sizes=None, include_banner=true, api_key=None))]
+pub fn download_channel_images(
+    identifier: String,
+    dest_dir: Option<PathBuf>,
+    sizes: Option<Vec<String>>,
+    include_banner: bool,
+    api_key: Option<Credentials>,
+) -> PyResult<PyObject> {
+    let sizes = sizes.unwrap_or_else(|| vec!["high".to_string()]);
+    if let Some(size) = sizes.iter().find(|size| !AVATAR_SIZES.contains(&size.as_str())) {
+        return Err(PyValueError::new_err(format!("Unknown avatar size '{}'; use one of: {}", size, AVATAR_SIZES.join(", "))));
+    }
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let channel_id = channel_id_for(&client, &api_key, &identifier)?;
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+
+        let mut wanted: Vec<(&'static str, &'static str, Option<&str>)> = Vec::new();
+        for (name, thumbnail) in channel.snippet.thumbnails.variants() {
+            if sizes.iter().any(|size| size == name) {
+                wanted.push(("avatar", name, thumbnail.as_ref().map(|thumbnail| thumbnail.url.as_str())));
+            }
+        }
+        if include_banner {
+            let banner = channel.branding_settings.as_ref()
+                .and_then(|branding| branding.image.as_ref())
+                .and_then(|image| image.banner_external_url.as_deref());
+            wanted.push(("banner", "original", banner));
+        }
+
+        if let Some(dest_dir) = &dest_dir {
+            fs::create_dir_all(dest_dir)
+                .map_err(|e| PyValueError::new_err(format!("Cannot create {}: {}", dest_dir.display(), e)))?;
+        }
+        let mut images: Vec<ChannelImage> = Vec::new();
+        let mut missing: Vec<String> = Vec::new();
+        for (kind, size, url) in wanted {
+            let url = match url {
+                Some(url) => url,
+                None => {
+                    missing.push(format!("{}_{}", kind, size));
+                    continue;
+                }
+            };
+            let (content_type, bytes) = client::download(&client, url)?;
+            let extension = image_extension(&content_type)
+                .ok_or_else(|| PyValueError::new_err(format!("Expected an image from {}, got '{}'", url, content_type)))?;
+            let path = match &dest_dir {
+                Some(dest_dir) => {
+                    let path = dest_dir.join(format!("{}_{}_{}.{}", channel.id, kind, size, extension));
+                    fs::write(&path, &bytes)
+                        .map_err(|e| PyValueError::new_err(format!("Cannot write {}: {}", path.display(), e)))?;
+                    Some(path)
+                }
+                None => None,
+            };
+            images.push(ChannelImage { kind, size, url, content_type, bytes, path });
+        }
+
+        Python::with_gil(|py| {
+            let results = PyList::empty(py);
+            for image in &images {
+                let entry = PyDict::new(py);
+                entry.set_item("kind", image.kind)?;
+                entry.set_item("size", image.size)?;
+                entry.set_item("url", image.url)?;
+                entry.set_item("content_type", &image.content_type)?;
+                entry.set_item("byte_count", image.bytes.len())?;
+                match &image.path {
+                    Some(path) => entry.set_item("path", path.to_string_lossy())?,
+                    None => entry.set_item("data", PyBytes::new(py, &image.bytes))?,
+                }
+                results.append(entry)?;
+            }
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel.id)?;
+            result.set_item("images", results)?;
+            result.set_item("missing", missing)?;
+            Ok(result.into())
+        })
+    })
+}
+
+/// List every localization a channel provides
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle or legacy username
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary mapping language codes to
+///   `{"title": ..., "description": ...}`; empty when the channel has none.
+///   Raises ChannelNotFoundError, or ChannelUnavailableError for a taken-down
+///   channel, when the API returns no channel
+#[pyfunction]
+#[pyo3(signature = (channel_id, api_key=None))]
+pub fn get_channel_localizations(channel_id: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+
+    let url = channel_lookup_url(&channel_id, "localizations", None)?;
+    let response = client::send_get(&client, &api_key, &url)?;
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Failed to fetch channel localizations: {}", response.status)));
+    }
+    let data: ChannelLocalizationsResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse channel localizations: {}", e)))?;
+    let channel = data.items.into_iter().next()
+        .ok_or_else(|| channel_missing(&client, &channel_id))?;
+
+    Python::with_gil(|py| {
+        let localizations = PyDict::new(py);
+        for (language, localized) in &channel.localizations {
+            let entry = PyDict::new(py);
+            entry.set_item("title", &localized.title)?;
+            entry.set_item("description", &localized.description)?;
+            localizations.set_item(language, entry)?;
+        }
+        Ok(localizations.into())
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelIdListResponse {
+    #[serde(default)]
+    items: Vec<ChannelIdItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelIdItem {
+    id: String,
+}
+
+/// Channel ID returned by channels.list for a single lookup parameter (1 unit)
+fn lookup_channel_id(client: &Client, credentials: &Credentials, param: &str, value: &str) -> PyResult<Option<String>> {
+    let params = [("part", "id"), ("fields", "items(id)"), (param, value)];
+    let url = Url::parse_with_params(&format!("{}/channels", client::BASE_URL), &params)
+        .map_err(|e| PyValueError::new_err(format!("Invalid channel lookup: {}", e)))?;
+    let response = client::send_get(client, credentials, url.as_str())?;
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Channel lookup failed: {}", response.status)));
+    }
+    let data: ChannelIdListResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse channel lookup: {}", e)))?;
+    Ok(data.items.into_iter().next().map(|item| item.id))
+}
+
+/// Channel ID of the first channel search result (100 units)
+fn search_channel_id(client: &Client, credentials: &Credentials, query: &str) -> PyResult<Option<String>> {
+    let params = [("part", "snippet"), ("type", "channel"), ("maxResults", "1"), ("q", query)];
+    let url = Url::parse_with_params(&format!("{}/search", client::BASE_URL), &params)
+        .map_err(|e| PyValueError::new_err(format!("Invalid search URL: {}", e)))?;
+    let response = client::send_get(client, credentials, url.as_str())?;
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Search failed: {}", response.status)));
+    }
+    let data: YouTubeSearchResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
+    Ok(data.items.into_iter().find_map(|item| item.id.channel_id))
+}
+
+/// Resolve identifiers with every strategy and report whether they agree
+///
+/// Audits data resolved with the old search-first heuristic. Each identifier
+/// costs 102 quota units (search, forHandle and forUsername).
+///
+/// # Arguments
+/// * `identifiers` - Handles (with or without `@`) or legacy usernames
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - One dictionary per identifier with the channel ID
+///   found by each strategy (`search`, `for_handle`, `for_username`; None when
+///   it found nothing), per-strategy `errors`, the distinct `resolved_ids`,
+///   and `agree` (every successful strategy returned the same channel)
+#[pyfunction]
+#[pyo3(signature = (identifiers, api_key=None))]
+pub fn resolution_report(identifiers: Vec<String>, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+
+    Python::with_gil(|py| {
+        let report = PyList::empty(py);
+        for identifier in &identifiers {
+            let name = identifier.trim().trim_start_matches('@');
+            let handle = format!("@{}", name);
+            let attempts: [(&str, PyResult<Option<String>>); 3] = [
+                ("search", search_channel_id(&client, &api_key, name)),
+                ("for_handle", lookup_channel_id(&client, &api_key, "forHandle", &handle)),
+                ("for_username", lookup_channel_id(&client, &api_key, "forUsername", name)),
+            ];
+
+            let entry = PyDict::new(py);
+            let errors = PyDict::new(py);
+            let mut resolved_ids: Vec<String> = Vec::new();
+            entry.set_item("identifier", identifier)?;
+            for (strategy, attempt) in attempts {
+                match attempt {
+                    Ok(channel_id) => {
+                        if let Some(channel_id) = &channel_id {
+                            if !resolved_ids.contains(channel_id) {
+                                resolved_ids.push(channel_id.clone());
+                            }
+                        }
+                        entry.set_item(strategy, channel_id)?;
+                    }
+                    Err(e) => {
+                        entry.set_item(strategy, py.None())?;
+                        errors.set_item(strategy, e.to_string())?;
+                    }
+                }
+            }
+            entry.set_item("agree", resolved_ids.len() == 1)?;
+            entry.set_item("resolved_ids", resolved_ids)?;
+            entry.set_item("errors", errors)?;
+            report.append(entry)?;
+        }
+        Ok(report.into())
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelSnippetListResponse {
+    #[serde(default)]
+    items: Vec<ChannelSnippetItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelSnippetItem {
+    id: String,
+    snippet: ChannelSnippet,
+}
+
+/// Channel ID embedded in a youtube.com channel page
+fn channel_id_from_page(html: &str) -> Option<String> {
+    let markers = [
+        "<link rel=\"canonical\" href=\"https://www.youtube.com/channel/",
+        "<meta itemprop=\"identifier\" content=\"",
+        "\"externalId\":\"",
+        "\"channelId\":\"",
+    ];
+    markers.iter().find_map(|marker| {
+        let start = html.find(marker)? + marker.len();
+        let id: String = html[start..].chars()
+            .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
             .collect();
+        (id.starts_with("UC") && id.len() == 24).then_some(id)
+    })
+}
+
+/// Channel behind a legacy custom URL name and how it was found (`scrape` or `search`)
+fn find_channel_by_custom_name(
+    client: &Client,
+    credentials: &Credentials,
+    name: &str,
+) -> PyResult<Option<(ChannelSnippetItem, &'static str)>> {
+    let fetch_snippets = |ids: &[String]| -> PyResult<Vec<ChannelSnippetItem>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let url = format!("{}/channels?part=snippet&id={}", client::BASE_URL, ids.join(","));
+        let response = client::send_get(client, credentials, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to verify channel: {}", response.status)));
+        }
+        let data: ChannelSnippetListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse channel data: {}", e)))?;
+        Ok(data.items)
+    };
+
+    // 1. Scrape the channel page; YouTube redirects /c/ URLs to the channel
+    let mut page_url = Url::parse(client::WEB_URL).expect("WEB_URL is a valid URL");
+    page_url.path_segments_mut().expect("WEB_URL is a base URL").extend(["c", name]);
+    let scraped = client::get_page(client, page_url.as_str())
+        .filter(|(status, _)| status.is_success())
+        .and_then(|(_, html)| channel_id_from_page(&html));
+
+    let mut resolved: Option<(ChannelSnippetItem, &'static str)> = None;
+    if let Some(channel_id) = scraped {
+        resolved = fetch_snippets(&[channel_id])?.into_iter().next().map(|item| (item, "scrape"));
+    }
+
+    // 2. Search fallback, accepting only a result whose custom URL matches
+    if resolved.is_none() {
+        let params = [("part", "snippet"), ("type", "channel"), ("maxResults", "5"), ("q", name)];
+        let url = Url::parse_with_params(&format!("{}/search", client::BASE_URL), &params)
+            .map_err(|e| PyValueError::new_err(format!("Invalid search URL: {}", e)))?;
+        let response = client::send_get(client, credentials, url.as_str())?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Search failed: {}", response.status)));
+        }
+        let data: YouTubeSearchResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
+        let candidates: Vec<String> = data.items.into_iter().filter_map(|item| item.id.channel_id).collect();
+
+        let wanted = name.to_lowercase();
+        resolved = fetch_snippets(&candidates)?.into_iter()
+            .find(|item| {
+                item.snippet.custom_url.as_deref()
+                    .is_some_and(|custom| custom.trim_start_matches('@').to_lowercase() == wanted)
+            })
+            .map(|item| (item, "search"));
+    }
+
+    Ok(resolved)
+}
+
+/// Resolve a legacy `/c/Name` custom URL to its canonical channel ID
+///
+/// Neither forHandle nor forUsername understands legacy custom URLs, so the
+/// channel page is scraped first (no quota) and a channel search (100 units)
+/// is the fallback. Every candidate is verified with a channels call.
+///
+/// # Arguments
+/// * `custom_url` - `https://www.youtube.com/c/Name`, `/c/Name` or just `Name`
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channel_id`, `channel_title`,
+///   `custom_url` (as reported by the API) and `method` (`scrape` or `search`)
+#[pyfunction]
+#[pyo3(signature = (custom_url, api_key=None))]
+pub fn get_channel_by_custom_url(custom_url: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+
+        let trimmed = custom_url.trim().trim_end_matches('/');
+        let name = trimmed.rsplit_once("/c/").map_or(trimmed, |(_, name)| name);
+        let name = name.trim_start_matches("c/").split(['/', '?']).next().unwrap_or_default();
+        if name.is_empty() {
+            return Err(PyValueError::new_err(format!("Invalid custom URL: {}", custom_url)));
+        }
 
-        Ok(PyList::new(py, py_dicts).into())
+        let resolved = find_channel_by_custom_name(&client, &api_key, name)?;
+
+        let (channel, method) = resolved
+            .ok_or_else(|| PyValueError::new_err(format!("Could not resolve custom URL: {}", custom_url)))?;
+
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel.id)?;
+            result.set_item("channel_title", &channel.snippet.title)?;
+            result.set_item("custom_url", &channel.snippet.custom_url)?;
+            result.set_item("handle", channel_handle(channel.snippet.custom_url.as_deref()))?;
+            result.set_item("method", method)?;
+            Ok(result.into())
+        })
     })
+}
+
+// First path segments of youtube.com pages that are not channel vanity URLs
+const RESERVED_PATHS: [&str; 12] = [
+    "watch", "results", "feed", "playlist", "shorts", "embed", "live", "hashtag",
+    "account", "premium", "gaming", "redirect",
+];
+
+/// What a channel identifier or URL points at
+#[derive(Debug)]
+enum ChannelReference {
+    Id(String),
+    Handle(String),
+    Username(String),
+    CustomName(String),
+    Video(String),
+    // Bare word: a legacy username or a handle typed without the @
+    Name(String),
+}
+
+fn parse_channel_reference(identifier: &str) -> Option<ChannelReference> {
+    let identifier = identifier.trim().trim_end_matches('/');
+    if identifier.is_empty() || identifier.contains(char::is_whitespace) {
+        return None;
+    }
+
+    let is_url = identifier.contains("://") || identifier.contains("youtube.com") || identifier.contains("youtu.be");
+    if !is_url {
+        return if identifier.contains('/') {
+            None
+        } else if identifier.starts_with("UC") {
+            Some(ChannelReference::Id(identifier.to_string()))
+        } else if identifier.starts_with('@') && identifier.len() > 1 {
+            Some(ChannelReference::Handle(identifier.to_string()))
+        } else {
+            Some(ChannelReference::Name(identifier.to_string()))
+        };
+    }
+
+    let with_scheme = if identifier.contains("://") { identifier.to_string() } else { format!("https://{}", identifier) };
+    let url = Url::parse(&with_scheme).ok()?;
+    if let Some(video_id) = utils::video_id_from_url(&url) {
+        return Some(ChannelReference::Video(video_id));
+    }
+    let host = url.host_str()?.to_ascii_lowercase();
+    if host != "youtube.com" && !host.ends_with(".youtube.com") {
+        return None;
+    }
+    let segments: Vec<String> = url.path_segments()?
+        .filter(|segment| !segment.is_empty())
+        .map(utils::percent_decode)
+        .collect();
+    match segments.as_slice() {
+        [first, ..] if first.starts_with('@') && first.len() > 1 => Some(ChannelReference::Handle(first.clone())),
+        [kind, id, ..] if kind == "channel" => Some(ChannelReference::Id(id.clone())),
+        [kind, name, ..] if kind == "user" => Some(ChannelReference::Username(name.clone())),
+        [kind, name, ..] if kind == "c" => Some(ChannelReference::CustomName(name.clone())),
+        [name, ..] if !RESERVED_PATHS.contains(&name.as_str()) => Some(ChannelReference::CustomName(name.clone())),
+        _ => None,
+    }
+}
 
+#[derive(Debug, Deserialize)]
+struct VideoChannelListResponse {
+    #[serde(default)]
+    items: Vec<VideoChannelItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoChannelItem {
+    snippet: VideoChannelSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoChannelSnippet {
+    #[serde(rename = "channelId")]
+    channel_id: String,
+}
+
+/// Channel that uploaded a video (1 unit)
+fn video_channel_id(client: &Client, credentials: &Credentials, video_id: &str) -> PyResult<Option<String>> {
+    let url = Url::parse_with_params(&format!("{}/videos", client::BASE_URL), &[("part", "snippet"), ("id", video_id)])
+        .map_err(|e| PyValueError::new_err(format!("Invalid video lookup: {}", e)))?;
+    let response = client::send_get(client, credentials, url.as_str())?;
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Video lookup failed: {}", response.status)));
+    }
+    let data: VideoChannelListResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse video lookup: {}", e)))?;
+    Ok(data.items.into_iter().next().map(|item| item.snippet.channel_id))
+}
+
+/// Canonical channel ID for any identifier or URL, by the cheapest lookup
+///
+/// IDs cost nothing, handles, usernames and videos 1 unit, legacy custom URLs
+/// a page scrape or, failing that, a 100-unit search.
+pub(crate) fn channel_id_for(client: &Client, credentials: &Credentials, identifier: &str) -> PyResult<String> {
+    let reference = parse_channel_reference(identifier)
+        .ok_or_else(|| PyValueError::new_err(format!("Unrecognized channel identifier or URL: {}", identifier)))?;
+    let channel_id = match reference {
+        ChannelReference::Id(channel_id) => Some(channel_id),
+        ChannelReference::Handle(handle) => lookup_channel_id(client, credentials, "forHandle", &handle)?,
+        ChannelReference::Username(name) => lookup_channel_id(client, credentials, "forUsername", &name)?,
+        ChannelReference::Name(name) => match lookup_channel_id(client, credentials, "forUsername", &name)? {
+            Some(channel_id) => Some(channel_id),
+            None => lookup_channel_id(client, credentials, "forHandle", &format!("@{}", name))?,
+        },
+        ChannelReference::CustomName(name) => find_channel_by_custom_name(client, credentials, &name)?.map(|(item, _)| item.id),
+        ChannelReference::Video(video_id) => video_channel_id(client, credentials, &video_id)?,
+    };
+    channel_id.ok_or_else(|| error::channel_not_found(identifier))
+}
+
+/// Resolve any channel identifier or YouTube URL to the canonical `UC...` ID
+///
+/// Accepts `youtube.com/channel/UC...`, `/@handle`, `/user/name`, `/c/custom`
+/// and bare `youtube.com/name` URLs, watch/shorts/youtu.be video URLs (the
+/// uploader's channel) and bare IDs, @handles or usernames. Channel IDs are
+/// returned without a request; everything else uses the cheapest lookup.
+///
+/// # Arguments
+/// * `identifier` - URL or identifier
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<String> - Channel ID; raises ChannelNotFoundError (a ValueError)
+///   when nothing matches and ValueError for input that is not a channel reference
+#[pyfunction]
+#[pyo3(signature = (identifier, api_key=None))]
+pub fn resolve_channel_id(identifier: String, api_key: Option<Credentials>) -> PyResult<String> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    channel_id_for(&client, &api_key, &identifier)
+}
+
+// channels?part=snippet,statistics response structures for resolution candidates
+#[derive(Debug, Deserialize)]
+struct CandidateListResponse {
+    #[serde(default)]
+    items: Vec<CandidateChannel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CandidateChannel {
+    id: String,
+    snippet: CandidateSnippet,
+    statistics: Option<ChannelStatistics>,
+}
 
-}
\ No newline at end of file
+#[derive(Debug, Deserialize)]
+struct CandidateSnippet {
+    title: String,
+    #[serde(rename = "customUrl")]
+    custom_url: Option<String>,
+}
+
+/// Channel IDs of up to `max_results` channel search results, in search order (100 units)
+fn search_channel_ids(client: &Client, credentials: &Credentials, query: &str, max_results: usize) -> PyResult<Vec<String>> {
+    let max_results = max_results.min(MAX_RESULTS_PER_PAGE).to_string();
+    let params = [("part", "id"), ("type", "channel"), ("maxResults", max_results.as_str()), ("q", query)];
+    let url = Url::parse_with_params(&format!("{}/search", client::BASE_URL), &params)
+        .map_err(|e| PyValueError::new_err(format!("Invalid search URL: {}", e)))?;
+    let response = client::send_get(client, credentials, url.as_str())?;
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Search failed: {}", response.status)));
+    }
+    let data: ChannelSearchIdResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
+    Ok(data.items.into_iter().filter_map(|item| item.id.channel_id).collect())
+}
+
+/// List the channels an identifier could refer to, best match first
+///
+/// Exact lookups (channel ID, forHandle, forUsername, video, custom URL page)
+/// are tried first at 1 unit each. A bare name can match a legacy username
+/// and a different channel's handle, in which case both are candidates. When
+/// no exact lookup succeeds, a channel search (100 units) supplies up to
+/// `max_candidates` channels, ranked by whether their handle or title equals
+/// the name and then by subscribers.
+///
+/// # Arguments
+/// * `identifier` - Channel ID, @handle, legacy username or channel URL
+/// * `strict` - Return only an unambiguous match: raise AmbiguousChannelError
+///   (a ValueError with `.candidates`) when several channels match and
+///   ChannelNotFoundError when none does (default: False)
+/// * `max_candidates` - Search results to consider (default: 5)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `channel_id`, `title`, `handle`,
+///   `subscriber_count` and `match` (`exact`, `handle`, `title` or `search`)
+///   dictionaries; a single entry in strict mode
+#[pyfunction]
+#[pyo3(signature = (identifier, strict=false, max_candidates=5, api_key=None))]
+pub fn resolve_channel_candidates(
+    py: Python,
+    identifier: String,
+    strict: bool,
+    max_candidates: usize,
+    api_key: Option<Credentials>,
+) -> PyResult<PyObject> {
+    let reference = parse_channel_reference(&identifier)
+        .ok_or_else(|| PyValueError::new_err(format!("Unrecognized channel identifier or URL: {}", identifier)))?;
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+
+    let (exact, name) = match reference {
+        ChannelReference::Id(channel_id) => (lookup_channel_id(&client, &api_key, "id", &channel_id)?.into_iter().collect(), None),
+        ChannelReference::Video(video_id) => (video_channel_id(&client, &api_key, &video_id)?.into_iter().collect(), None),
+        ChannelReference::Handle(handle) => {
+            let found: Vec<String> = lookup_channel_id(&client, &api_key, "forHandle", &handle)?.into_iter().collect();
+            (found, Some(handle.trim_start_matches('@').to_string()))
+        }
+        ChannelReference::Username(name) => (lookup_channel_id(&client, &api_key, "forUsername", &name)?.into_iter().collect(), Some(name)),
+        ChannelReference::Name(name) => {
+            let mut found: Vec<String> = lookup_channel_id(&client, &api_key, "forUsername", &name)?.into_iter().collect();
+            if let Some(channel_id) = lookup_channel_id(&client, &api_key, "forHandle", &format!("@{}", name))? {
+                if !found.contains(&channel_id) {
+                    found.push(channel_id);
+                }
+            }
+            (found, Some(name))
+        }
+        ChannelReference::CustomName(name) => {
+            let found = find_channel_by_custom_name(&client, &api_key, &name)?.map(|(item, _)| item.id);
+            (found.into_iter().collect(), Some(name))
+        }
+    };
+
+    let candidate_ids: Vec<String> = match (&name, exact.is_empty()) {
+        (Some(name), true) => search_channel_ids(&client, &api_key, name, max_candidates)?,
+        _ => exact.clone(),
+    };
+    let mut candidates: Vec<CandidateChannel> = Vec::new();
+    for batch in candidate_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let url = format!("{}/channels?part=snippet,statistics&id={}", client::BASE_URL, batch.join(","));
+        let response = client::send_get(&client, &api_key, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch candidate channels: {}", response.status)));
+        }
+        let page: CandidateListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse candidate channels: {}", e)))?;
+        candidates.extend(page.items);
+    }
+
+    let wanted = name.as_deref().map(str::to_lowercase);
+    let match_kind = |channel: &CandidateChannel| -> &'static str {
+        if exact.contains(&channel.id) {
+            return "exact";
+        }
+        let wanted = wanted.as_deref().unwrap_or_default();
+        let handle = channel.snippet.custom_url.as_deref().map(|custom| custom.trim_start_matches('@').to_lowercase());
+        if handle.as_deref() == Some(wanted) {
+            "handle"
+        } else if channel.snippet.title.to_lowercase() == wanted {
+            "title"
+        } else {
+            "search"
+        }
+    };
+    let rank = |kind: &str| ["exact", "handle", "title", "search"].iter().position(|k| *k == kind).unwrap_or(usize::MAX);
+    let subscribers = |channel: &CandidateChannel| channel.statistics.as_ref()
+        .filter(|stats| !stats.hidden_subscriber_count)
+        .and_then(|stats| stats.subscriber_count.as_ref())
+        .and_then(|count| count.parse::<u64>().ok());
+    candidates.sort_by(|a, b| {
+        rank(match_kind(a)).cmp(&rank(match_kind(b)))
+            .then_with(|| subscribers(b).cmp(&subscribers(a)))
+    });
+
+    let results = PyList::empty(py);
+    for channel in &candidates {
+        let entry = PyDict::new(py);
+        entry.set_item("channel_id", &channel.id)?;
+        entry.set_item("title", &channel.snippet.title)?;
+        entry.set_item("handle", channel_handle(channel.snippet.custom_url.as_deref()))?;
+        entry.set_item("subscriber_count", subscribers(channel))?;
+        entry.set_item("match", match_kind(channel))?;
+        results.append(entry)?;
+    }
+    if !strict {
+        return Ok(results.into());
+    }
+
+    // Strict: exactly one candidate, or exactly one matching the name itself
+    let confident: Vec<&CandidateChannel> = candidates.iter()
+        .filter(|channel| matches!(match_kind(channel), "exact" | "handle"))
+        .collect();
+    let chosen = match (candidates.len(), confident.len()) {
+        (0, _) => return Err(error::channel_not_found(&identifier)),
+        (1, _) => 0,
+        (_, 1) => candidates.iter().position(|channel| channel.id == confident[0].id).unwrap_or_default(),
+        _ => return Err(error::ambiguous_channel(&identifier, results.into())),
+    };
+    Ok(PyList::new(py, [results.get_item(chosen)?]).into())
+}
+
+/// Check that a channel exists without fetching its statistics
+///
+/// Issues the smallest possible request (`part=id`, `fields=items(id)`, 1
+/// quota unit); unlike `resolve_channel_id`, bare channel IDs are verified
+/// against the API too. Input that is not a channel reference at all counts
+/// as not existing.
+///
+/// # Arguments
+/// * `identifier` - Channel ID, @handle, legacy username or channel URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<(bool, Option<String>)> - Whether the channel exists, and its
+///   canonical `UC...` ID when it does
+#[pyfunction]
+#[pyo3(signature = (identifier, api_key=None))]
+pub fn channel_exists(py: Python, identifier: String, api_key: Option<Credentials>) -> PyResult<(bool, Option<String>)> {
+    let reference = match parse_channel_reference(&identifier) {
+        Some(reference) => reference,
+        None => return Ok((false, None)),
+    };
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = match reference {
+        ChannelReference::Id(channel_id) => lookup_channel_id(&client, &api_key, "id", &channel_id)?,
+        _ => match channel_id_for(&client, &api_key, &identifier) {
+            Ok(channel_id) => Some(channel_id),
+            Err(e) if e.is_instance_of::<error::ChannelNotFoundError>(py) => None,
+            Err(e) => return Err(e),
+        },
+    };
+    Ok((channel_id.is_some(), channel_id))
+}
+
+/// Get channel statistics for the uploader of a video
+///
+/// Looks up the video's channel (1 quota unit) and returns the same
+/// dictionary as `get_youtube_channel_stats`, plus `source_video_id`.
+///
+/// # Arguments
+/// * `video_id_or_url` - Video ID or watch, youtu.be, shorts, embed or live URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `video_count` - Number of recent videos to fetch (default: 10)
+///
+/// # Returns
+/// * PyResult<PyObject> - Channel stats dictionary; raises ValueError when the
+///   input is not a video reference or the video does not exist
+#[pyfunction]
+#[pyo3(signature = (video_id_or_url, api_key=None, video_count=None))]
+pub fn get_channel_from_video(py: Python, video_id_or_url: String, api_key: Option<Credentials>, video_count: Option<u32>) -> PyResult<PyObject> {
+    let video_id = utils::parse_video_reference(&video_id_or_url)
+        .ok_or_else(|| error::invalid_video_reference(&video_id_or_url))?;
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let channel_id = video_channel_id(&client, &api_key, &video_id)?
+            .ok_or_else(|| PyValueError::new_err(format!("Video not found: {}", video_id)))?;
+
+        let stats = get_youtube_channel_stats(channel_id, Some(api_key), video_count, false, None, false, false, None, false, None)?;
+        stats.downcast::<PyDict>(py)?.set_item("source_video_id", &video_id)?;
+        Ok(stats)
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditDetailsListResponse {
+    #[serde(default)]
+    items: Vec<AuditDetailsItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditDetailsItem {
+    id: String,
+    #[serde(rename = "auditDetails")]
+    audit_details: Option<AuditDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuditDetails {
+    #[serde(rename = "overallGoodStanding")]
+    overall_good_standing: Option<bool>,
+    #[serde(rename = "communityGuidelinesGoodStanding")]
+    community_guidelines_good_standing: Option<bool>,
+    #[serde(rename = "copyrightStrikesGoodStanding")]
+    copyright_strikes_good_standing: Option<bool>,
+    #[serde(rename = "contentIdClaimsGoodStanding")]
+    content_id_claims_good_standing: Option<bool>,
+}
+
+/// Standing of the authorized user's own channel (strikes and claims)
+///
+/// Only the channel owner can read `auditDetails`, so this requires OAuth
+/// credentials granted `auth.SCOPE_YOUTUBEPARTNER_CHANNEL_AUDIT`.
+///
+/// # Arguments
+/// * `api_key` - OAuthCredentials or an AuthConfig holding an OAuth token
+///   (default: the configured default)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channel_id`, `overall_good_standing`,
+///   `community_guidelines_good_standing`, `copyright_strikes_good_standing`
+///   and `content_id_claims_good_standing`
+#[pyfunction]
+#[pyo3(signature = (api_key=None))]
+pub fn get_channel_audit_details(api_key: Option<Credentials>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        if !api_key.is_user_oauth() {
+            return Err(PyValueError::new_err(
+                "auditDetails is only available to the channel owner; pass OAuthCredentials with the youtubepartner-channel-audit scope",
+            ));
+        }
+        let client = client::build_client(&api_key)?;
+
+        let url = format!("{}/channels?part=auditDetails&mine=true", client::BASE_URL);
+        let response = client::send_get(&client, &api_key, &url)?;
+        if response.status.as_u16() == 403 {
+            let message = response.error().map(|error| error.message).unwrap_or_default();
+            return Err(PyValueError::new_err(format!(
+                "Access to auditDetails denied; the token needs the youtubepartner-channel-audit scope ({})", message
+            )));
+        }
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch audit details: {}", response.status)));
+        }
+        let data: AuditDetailsListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse audit details: {}", e)))?;
+        let channel = data.items.into_iter().next()
+            .ok_or_else(|| PyValueError::new_err("The authorized account has no YouTube channel"))?;
+        let audit = channel.audit_details;
+
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel.id)?;
+            result.set_item("overall_good_standing", audit.as_ref().and_then(|a| a.overall_good_standing))?;
+            result.set_item("community_guidelines_good_standing", audit.as_ref().and_then(|a| a.community_guidelines_good_standing))?;
+            result.set_item("copyright_strikes_good_standing", audit.as_ref().and_then(|a| a.copyright_strikes_good_standing))?;
+            result.set_item("content_id_claims_good_standing", audit.as_ref().and_then(|a| a.content_id_claims_good_standing))?;
+            Ok(result.into())
+        })
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscriptionListResponse {
+    #[serde(default)]
+    items: Vec<Subscription>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Subscription {
+    snippet: SubscriptionSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscriptionSnippet {
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(rename = "publishedAt")]
+    published_at: Option<String>,
+    #[serde(rename = "resourceId")]
+    resource_id: SubscriptionResource,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubscriptionResource {
+    #[serde(rename = "channelId")]
+    channel_id: Option<String>,
+}
+
+/// Lazily iterate over every upload of a channel, newest first
+///
+/// Returns an iterator yielding one video dict (`video_id`, `title`,
+/// `published_at`, `channel_id`, `channel_title`, `video_url`) at a time.
+/// Uploads are fetched 50 per request as the iterator advances, so thousands
+/// of uploads can be walked without holding them in memory. Costs 1 quota
+/// unit to find the uploads playlist plus 1 per 50 uploads consumed.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PlaylistItemStream> - Iterator over video dicts
+#[pyfunction]
+#[pyo3(signature = (channel_id, api_key=None))]
+pub fn iter_channel_uploads(channel_id: String, api_key: Option<Credentials>) -> PyResult<playlist::PlaylistItemStream> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+    let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+    let uploads_playlist = uploads_playlist_id(&channel)?.to_string();
+    playlist::PlaylistItemStream::new(api_key, uploads_playlist, None)
+}
+
+/// List the channels a channel publicly subscribes to
+///
+/// Costs 1 quota unit per 50 subscriptions. Channels that keep their
+/// subscriptions private raise a ValueError.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `max_results` - Stop after this many subscriptions (default: all)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `channel_id`, `title`, `description` and
+///   `subscribed_at` dictionaries
+#[pyfunction]
+#[pyo3(signature = (channel_id, api_key=None, max_results=None))]
+pub fn get_channel_subscriptions(
+    channel_id: String,
+    api_key: Option<Credentials>,
+    max_results: Option<usize>,
+) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+
+    let mut subscriptions: Vec<SubscriptionSnippet> = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let remaining = max_results.map_or(MAX_RESULTS_PER_PAGE, |limit| limit.saturating_sub(subscriptions.len()));
+        if remaining == 0 {
+            break;
+        }
+        let mut params = vec![
+            ("part", "snippet".to_string()),
+            ("channelId", channel_id.clone()),
+            ("maxResults", remaining.min(MAX_RESULTS_PER_PAGE).to_string()),
+        ];
+        if let Some(token) = &page_token {
+            params.push(("pageToken", token.clone()));
+        }
+        let url = Url::parse_with_params(&format!("{}/subscriptions", client::BASE_URL), &params)
+            .map_err(|e| PyValueError::new_err(format!("Invalid subscriptions URL: {}", e)))?;
+
+        let response = client::send_get(&client, &api_key, url.as_str())?;
+        if response.has_error_reason(&["subscriptionForbidden"]) {
+            return Err(PyValueError::new_err(format!("Subscriptions of channel {} are private", channel_id)));
+        }
+        if !response.is_success() {
+            let detail = response.error()
+                .map(|error| {
+                    let reason = error.errors.first().map_or("unknown", |detail| detail.reason.as_str());
+                    format!(" ({}) - {}", reason, error.message)
+                })
+                .unwrap_or_default();
+            return Err(PyValueError::new_err(format!("Failed to fetch subscriptions: {}{}", response.status, detail)));
+        }
+        let page: SubscriptionListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse subscriptions: {}", e)))?;
+
+        subscriptions.extend(page.items.into_iter().map(|item| item.snippet));
+        page_token = page.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for subscription in &subscriptions {
+            let entry = PyDict::new(py);
+            entry.set_item("channel_id", &subscription.resource_id.channel_id)?;
+            entry.set_item("title", &subscription.title)?;
+            entry.set_item("description", &subscription.description)?;
+            entry.set_item("subscribed_at", &subscription.published_at)?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ActivityListResponse {
+    #[serde(default)]
+    items: Vec<Activity>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Activity {
+    id: String,
+    snippet: ActivitySnippet,
+    #[serde(rename = "contentDetails", default)]
+    content_details: ActivityContentDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActivitySnippet {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(rename = "publishedAt")]
+    published_at: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+}
+
+// Only the entry matching `snippet.type` is present
+#[derive(Debug, Default, Deserialize)]
+struct ActivityContentDetails {
+    upload: Option<ActivityUpload>,
+    #[serde(rename = "playlistItem")]
+    playlist_item: Option<ActivityPlaylistItem>,
+    bulletin: Option<ActivityResource>,
+    like: Option<ActivityResource>,
+    favorite: Option<ActivityResource>,
+    subscription: Option<ActivityResource>,
+    recommendation: Option<ActivityResource>,
+    #[serde(rename = "channelItem")]
+    channel_item: Option<ActivityResource>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActivityUpload {
+    #[serde(rename = "videoId")]
+    video_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActivityPlaylistItem {
+    #[serde(rename = "playlistId")]
+    playlist_id: Option<String>,
+    #[serde(rename = "resourceId")]
+    resource_id: ResourceId,
+}
+
+#[derive(Debug, Deserialize)]
+struct ActivityResource {
+    #[serde(rename = "resourceId")]
+    resource_id: ResourceId,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ResourceId {
+    #[serde(rename = "videoId")]
+    video_id: Option<String>,
+    #[serde(rename = "channelId")]
+    channel_id: Option<String>,
+    #[serde(rename = "playlistId")]
+    playlist_id: Option<String>,
+}
+
+impl Activity {
+    /// Event type with API names mapped to snake_case (`bulletin` becomes `post`)
+    fn event_type(&self) -> String {
+        match self.snippet.kind.as_str() {
+            "playlistItem" => "playlist_item".to_string(),
+            "bulletin" => "post".to_string(),
+            "channelItem" => "channel_item".to_string(),
+            kind => kind.to_string(),
+        }
+    }
+
+    /// The video, channel or playlist the activity refers to
+    fn resource(&self) -> ResourceId {
+        let details = &self.content_details;
+        if let Some(upload) = &details.upload {
+            return ResourceId { video_id: Some(upload.video_id.clone()), ..ResourceId::default() };
+        }
+        if let Some(item) = &details.playlist_item {
+            return ResourceId {
+                video_id: item.resource_id.video_id.clone(),
+                playlist_id: item.playlist_id.clone(),
+                ..ResourceId::default()
+            };
+        }
+        [&details.bulletin, &details.like, &details.favorite, &details.subscription,
+         &details.recommendation, &details.channel_item]
+            .into_iter()
+            .flatten()
+            .next()
+            .map(|activity| ResourceId {
+                video_id: activity.resource_id.video_id.clone(),
+                channel_id: activity.resource_id.channel_id.clone(),
+                playlist_id: activity.resource_id.playlist_id.clone(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Normalize a `since` argument (`YYYY-MM-DD` or RFC 3339) for `publishedAfter`
+fn published_after(since: &str) -> PyResult<String> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(since) {
+        return Ok(timestamp.with_timezone(&Utc).to_rfc3339_opts(chrono::SecondsFormat::Secs, true));
+    }
+    chrono::NaiveDate::parse_from_str(since, "%Y-%m-%d")
+        .map(|date| format!("{}T00:00:00Z", date))
+        .map_err(|_| PyValueError::new_err(format!("Invalid since '{}': expected YYYY-MM-DD or an RFC 3339 timestamp", since)))
+}
+
+/// Activities of a channel, newest first, 1 quota unit per 50
+fn fetch_activities(
+    client: &Client,
+    credentials: &Credentials,
+    channel_id: &str,
+    published_after: Option<&str>,
+    max_results: Option<usize>,
+) -> PyResult<Vec<Activity>> {
+    let mut activities: Vec<Activity> = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let remaining = max_results.map_or(MAX_RESULTS_PER_PAGE, |limit| limit.saturating_sub(activities.len()));
+        if remaining == 0 {
+            break;
+        }
+        let mut params = vec![
+            ("part", "snippet,contentDetails".to_string()),
+            ("channelId", channel_id.to_string()),
+            ("maxResults", remaining.min(MAX_RESULTS_PER_PAGE).to_string()),
+        ];
+        if let Some(published_after) = published_after {
+            params.push(("publishedAfter", published_after.to_string()));
+        }
+        if let Some(token) = &page_token {
+            params.push(("pageToken", token.clone()));
+        }
+        let url = Url::parse_with_params(&format!("{}/activities", client::BASE_URL), &params)
+            .map_err(|e| PyValueError::new_err(format!("Invalid activities URL: {}", e)))?;
+
+        let response = client::send_get(client, credentials, url.as_str())?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch activities: {}", response.status)));
+        }
+        let page: ActivityListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse activities: {}", e)))?;
+
+        activities.extend(page.items);
+        page_token = page.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+    Ok(activities)
+}
+
+/// List a channel's recent activity: uploads, playlist additions, posts and more
+///
+/// Costs 1 quota unit per 50 events. The API only returns roughly the last
+/// six months of activity, and some event types only for the owner's own channel.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `since` - Only events published after this date (`YYYY-MM-DD` or RFC 3339)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `max_results` - Stop after this many events (default: all)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of events, newest first, with `id`, `type`
+///   (`upload`, `playlist_item`, `post`, `like`, `favorite`, `subscription`, ...),
+///   `published_at`, `title`, `description`, `video_id`, `channel_id` and `playlist_id`
+#[pyfunction]
+#[pyo3(signature = (channel_id, since=None, api_key=None, max_results=None))]
+pub fn get_channel_activities(
+    channel_id: String,
+    since: Option<String>,
+    api_key: Option<Credentials>,
+    max_results: Option<usize>,
+) -> PyResult<PyObject> {
+    let published_after = since.as_deref().map(published_after).transpose()?;
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+
+    let activities = fetch_activities(&client, &api_key, &channel_id, published_after.as_deref(), max_results)?;
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for activity in &activities {
+            let resource = activity.resource();
+            let entry = PyDict::new(py);
+            entry.set_item("id", &activity.id)?;
+            entry.set_item("type", activity.event_type())?;
+            entry.set_item("published_at", &activity.snippet.published_at)?;
+            entry.set_item("title", &activity.snippet.title)?;
+            entry.set_item("description", &activity.snippet.description)?;
+            entry.set_item("video_id", resource.video_id)?;
+            entry.set_item("channel_id", resource.channel_id)?;
+            entry.set_item("playlist_id", resource.playlist_id)?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+/// Most recent of several RFC 3339 timestamps; unparsable ones are ignored
+fn latest_timestamp<'a>(timestamps: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    timestamps.into_iter()
+        .filter_map(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok().map(|parsed| (parsed, timestamp)))
+        .max_by_key(|(parsed, _)| *parsed)
+        .map(|(_, timestamp)| timestamp)
+}
+
+/// When a channel last did anything publicly visible
+///
+/// "Last upload" under-reports channels that mostly post Shorts, go live or
+/// curate playlists, so `last_activity_at` is the latest of: the newest
+/// entry of the uploads playlist (Shorts and past live streams included),
+/// the latest playlist addition and the latest community post reported by
+/// the activities feed. Costs about 3 quota units.
+///
+/// # Arguments
+/// * `channel_identifier` - Channel ID, @handle, legacy username or channel URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channel_id`, `last_activity_at`
+///   (None for a channel with no visible activity), `last_activity_type`
+///   (`upload`, `playlist_item` or `post`) and `signals` holding the latest
+///   timestamp of each of those types
+#[pyfunction]
+#[pyo3(signature = (channel_identifier, api_key=None))]
+pub fn get_last_activity(channel_identifier: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let channel_id = channel_id_for(&client, &api_key, &channel_identifier)?;
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+
+        // The uploads playlist is newest first, but scheduled premieres can reorder a few entries
+        let uploads = playlist::fetch_playlist_items(&client, &api_key, uploads_playlist_id(&channel)?, Some(5))?;
+        let activities = fetch_activities(&client, &api_key, &channel_id, None, Some(MAX_RESULTS_PER_PAGE))?;
+
+        let latest_of = |event_type: &str| latest_timestamp(activities.iter()
+            .filter(|activity| activity.event_type() == event_type)
+            .map(|activity| activity.snippet.published_at.as_str()));
+        let latest_upload = latest_timestamp(uploads.iter()
+            .filter_map(|item| item.content_details.video_published_at.as_deref())
+            .chain(latest_of("upload")));
+        let signals = [
+            ("upload", latest_upload),
+            ("playlist_item", latest_of("playlist_item")),
+            ("post", latest_of("post")),
+        ];
+        let last_activity_at = latest_timestamp(signals.iter().filter_map(|(_, timestamp)| *timestamp));
+        let last_activity_type = signals.iter()
+            .find(|(_, timestamp)| last_activity_at.is_some() && *timestamp == last_activity_at)
+            .map(|(event_type, _)| *event_type);
+
+        Python::with_gil(|py| {
+            let signals_dict = PyDict::new(py);
+            signals_dict.set_item("latest_upload", signals[0].1)?;
+            signals_dict.set_item("latest_playlist_update", signals[1].1)?;
+            signals_dict.set_item("latest_post", signals[2].1)?;
+
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel_id)?;
+            result.set_item("last_activity_at", last_activity_at)?;
+            result.set_item("last_activity_type", last_activity_type)?;
+            result.set_item("signals", signals_dict)?;
+            metrics::set_derived(result, "last_activity_at", last_activity_at, metrics::SOURCE_COMPUTED)?;
+            Ok(result.into())
+        })
+    })
+}
+
+// videos?part=snippet,liveStreamingDetails,status response structures
+#[derive(Debug, Deserialize)]
+struct LiveVideoListResponse {
+    #[serde(default)]
+    items: Vec<LiveVideo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LiveVideo {
+    id: String,
+    snippet: LiveVideoSnippet,
+    #[serde(rename = "liveStreamingDetails")]
+    live_streaming_details: Option<LiveStreamingDetails>,
+    status: Option<VideoStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LiveVideoSnippet {
+    title: String,
+    // "live", "upcoming" or "none" once a stream has ended
+    #[serde(rename = "liveBroadcastContent")]
+    live_broadcast_content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LiveStreamingDetails {
+    #[serde(rename = "concurrentViewers")]
+    concurrent_viewers: Option<String>,
+    #[serde(rename = "scheduledStartTime")]
+    scheduled_start_time: Option<String>,
+    #[serde(rename = "actualStartTime")]
+    actual_start_time: Option<String>,
+    // Only present while the chat is open
+    #[serde(rename = "activeLiveChatId")]
+    active_live_chat_id: Option<String>,
+}
+
+impl LiveStreamingDetails {
+    /// Concurrent viewers; None when hidden or the stream is not live
+    fn concurrent_viewers(&self) -> Option<u64> {
+        self.concurrent_viewers.as_ref().and_then(|viewers| viewers.parse::<u64>().ok())
+    }
+
+    /// Set `concurrent_viewers`, `scheduled_start_time`, `actual_start_time` and
+    /// `active_live_chat_id` on a video dict; all None for regular uploads
+    pub(crate) fn set_items(details: Option<&Self>, video: &PyDict) -> PyResult<()> {
+        video.set_item("concurrent_viewers", details.and_then(Self::concurrent_viewers))?;
+        video.set_item("scheduled_start_time", details.and_then(|d| d.scheduled_start_time.as_deref()))?;
+        video.set_item("actual_start_time", details.and_then(|d| d.actual_start_time.as_deref()))?;
+        video.set_item("active_live_chat_id", details.and_then(|d| d.active_live_chat_id.as_deref()))?;
+        Ok(())
+    }
+}
+
+/// Video IDs of a channel's streams in one broadcast state (100 quota units)
+fn search_live_video_ids(client: &Client, credentials: &Credentials, channel_id: &str, event_type: &str) -> PyResult<Vec<String>> {
+    let params = [
+        ("part", "id"),
+        ("channelId", channel_id),
+        ("eventType", event_type),
+        ("type", "video"),
+        ("maxResults", "50"),
+    ];
+    let url = Url::parse_with_params(&format!("{}/search", client::BASE_URL), &params)
+        .map_err(|e| PyValueError::new_err(format!("Invalid search URL: {}", e)))?;
+    let response = client::send_get(client, credentials, url.as_str())?;
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Failed to search {} streams: {}", event_type, response.status)));
+    }
+    let data: ChannelSearchIdResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
+    Ok(data.items.into_iter().filter_map(|item| item.id.video_id).collect())
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelSearchIdResponse {
+    #[serde(default)]
+    items: Vec<ChannelSearchIdItem>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelSearchIdItem {
+    id: YouTubeSearchResultId,
+}
+
+/// List a channel's live and upcoming streams
+///
+/// Searches with `eventType=live` and `eventType=upcoming` (100 quota units
+/// each) and joins `liveStreamingDetails` for the results (1 unit per 50).
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `video_id`, `title`, `status` (`live` or
+///   `upcoming`), `concurrent_viewers` (None when hidden or not live),
+///   `scheduled_start_time`, `actual_start_time`, `active_live_chat_id`
+///   (None once the chat closes), the status fields of
+///   `video.get_video_stats` and `video_url` dictionaries;
+///   live streams first by viewers, then upcoming ones by start time
+#[pyfunction]
+#[pyo3(signature = (channel_id, api_key=None))]
+pub fn get_channel_live_streams(channel_id: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+
+    let mut video_ids = search_live_video_ids(&client, &api_key, &channel_id, "live")?;
+    for video_id in search_live_video_ids(&client, &api_key, &channel_id, "upcoming")? {
+        if !video_ids.contains(&video_id) {
+            video_ids.push(video_id);
+        }
+    }
+
+    let mut streams: Vec<LiveVideo> = Vec::new();
+    for batch in video_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let url = format!("{}/videos?part=snippet,liveStreamingDetails,status&id={}", client::BASE_URL, batch.join(","));
+        let response = client::send_get(&client, &api_key, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch live streaming details: {}", response.status)));
+        }
+        let page: LiveVideoListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse live streaming details: {}", e)))?;
+        streams.extend(page.items);
+    }
+
+    // Search results lag behind; trust the videos endpoint on whether a stream is still on
+    let status = |stream: &LiveVideo| stream.snippet.live_broadcast_content.clone().unwrap_or_default();
+    streams.retain(|stream| matches!(status(stream).as_str(), "live" | "upcoming"));
+    let viewers = |stream: &LiveVideo| stream.live_streaming_details.as_ref()
+        .and_then(LiveStreamingDetails::concurrent_viewers);
+    let scheduled = |stream: &LiveVideo| stream.live_streaming_details.as_ref()
+        .and_then(|details| details.scheduled_start_time.clone());
+    streams.sort_by(|a, b| {
+        (status(a) != "live").cmp(&(status(b) != "live"))
+            .then_with(|| viewers(b).cmp(&viewers(a)))
+            .then_with(|| scheduled(a).cmp(&scheduled(b)))
+    });
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for stream in &streams {
+            let entry = PyDict::new(py);
+            entry.set_item("video_id", &stream.id)?;
+            entry.set_item("title", &stream.snippet.title)?;
+            entry.set_item("status", status(stream))?;
+            LiveStreamingDetails::set_items(stream.live_streaming_details.as_ref(), entry)?;
+            VideoStatus::set_items(stream.status.as_ref(), entry)?;
+            entry.set_item("video_url", format!("https://www.youtube.com/watch?v={}", stream.id))?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+// videos?part=contentDetails response structures
+#[derive(Debug, Deserialize)]
+struct VideoDurationListResponse {
+    #[serde(default)]
+    items: Vec<VideoDurationItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoDurationItem {
+    id: String,
+    #[serde(rename = "contentDetails")]
+    content_details: Option<VideoContentDetails>,
+}
+
+/// Duration in seconds of each video the API returned, 50 IDs per request
+fn fetch_video_durations(client: &Client, credentials: &Credentials, video_ids: &[String]) -> PyResult<HashMap<String, u64>> {
+    let mut durations = HashMap::with_capacity(video_ids.len());
+    for batch in video_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let url = format!("{}/videos?part=contentDetails&id={}", client::BASE_URL, batch.join(","));
+        let response = client::send_get(client, credentials, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch video durations: {}", response.status)));
+        }
+        let page: VideoDurationListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse video durations: {}", e)))?;
+        for video in page.items {
+            let seconds = video.content_details
+                .and_then(|details| details.duration)
+                .and_then(|duration| utils::parse_duration(&duration));
+            if let Some(seconds) = seconds {
+                durations.insert(video.id, seconds);
+            }
+        }
+    }
+    Ok(durations)
+}
+
+/// Total and average duration of a channel's uploads
+///
+/// Walks the uploads playlist and fetches durations 50 videos at a time,
+/// about 2 quota units per 50 uploads. Private and deleted uploads, and
+/// upcoming streams without a duration yet, are counted under
+/// `unmeasured_count` and left out of the totals.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `limit` - Only consider the most recent uploads (default: all)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channel_id`, `video_count`,
+///   `measured_count`, `unmeasured_count`, `total_duration_seconds` and
+///   `average_duration_seconds` (None when nothing was measured)
+#[pyfunction]
+#[pyo3(signature = (channel_id, limit=None, api_key=None))]
+pub fn get_channel_total_duration(channel_id: String, limit: Option<usize>, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+
+        let uploads = playlist::fetch_playlist_items(&client, &api_key, uploads_playlist_id(&channel)?, limit)?;
+        let video_ids: Vec<String> = uploads.iter().map(|item| item.content_details.video_id.clone()).collect();
+        let durations = fetch_video_durations(&client, &api_key, &video_ids)?;
+
+        let measured: Vec<u64> = video_ids.iter().filter_map(|video_id| durations.get(video_id).copied()).collect();
+        let total: u64 = measured.iter().sum();
+        let average = if measured.is_empty() { None } else { Some(total as f64 / measured.len() as f64) };
+
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel_id)?;
+            result.set_item("video_count", video_ids.len())?;
+            result.set_item("measured_count", measured.len())?;
+            result.set_item("unmeasured_count", video_ids.len() - measured.len())?;
+            result.set_item("total_duration_seconds", total)?;
+            result.set_item("average_duration_seconds", average)?;
+            metrics::set_derived(result, "total_duration_seconds", total, metrics::SOURCE_COMPUTED)?;
+            metrics::set_derived(result, "average_duration_seconds", average, metrics::SOURCE_COMPUTED)?;
+            Ok(result.into())
+        })
+    })
+}
+
+// Average days per month, for uploads-per-month rates
+const DAYS_PER_MONTH: f64 = 30.44;
+
+/// How often a channel uploads over a recent window
+///
+/// Walks the uploads playlist from the newest entry back to the start of the
+/// window, 1 quota unit per 50 uploads (plus 1 to look up the playlist).
+/// Private and deleted uploads have no publish date and are not counted.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `window_days` - Length of the window ending now (default: 90)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channel_id`, `window_days`,
+///   `upload_count`, `uploads_per_week`, `uploads_per_month`,
+///   `longest_gap_days` (between consecutive uploads in the window; None with
+///   fewer than two), `last_upload_at` and `last_upload_age_days` (both
+///   looked up beyond the window; None for a channel without uploads)
+#[pyfunction]
+#[pyo3(signature = (channel_id, window_days=90, api_key=None))]
+pub fn get_upload_frequency(channel_id: String, window_days: u32, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    if window_days == 0 {
+        return Err(PyValueError::new_err("window_days must be at least 1"));
+    }
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+
+        let now = Utc::now();
+        let window_start = now - chrono::Duration::days(window_days.into());
+        let mut pager = playlist::PlaylistPager::new(uploads_playlist_id(&channel)?.to_string());
+        let mut published: Vec<DateTime<Utc>> = Vec::new();
+        while !pager.is_exhausted() {
+            let page: Vec<DateTime<Utc>> = pager.next_page(&client, &api_key, MAX_RESULTS_PER_PAGE)?
+                .iter()
+                .filter_map(|item| item.content_details.video_published_at.as_deref())
+                .filter_map(|timestamp| DateTime::parse_from_rfc3339(timestamp).ok())
+                .map(|timestamp| timestamp.with_timezone(&Utc))
+                .collect();
+            let reached_window_start = page.iter().any(|timestamp| *timestamp < window_start);
+            published.extend(page);
+            // Newest first, so older pages cannot hold uploads inside the window
+            if reached_window_start {
+                break;
+            }
+        }
+        published.sort_unstable_by(|a, b| b.cmp(a));
+
+        let last_upload = published.first().copied();
+        let in_window: Vec<DateTime<Utc>> = published.into_iter().filter(|timestamp| *timestamp >= window_start).collect();
+        let longest_gap_days = in_window.windows(2)
+            .map(|pair| (pair[0] - pair[1]).num_seconds() as f64 / 86_400.0)
+            .fold(None, |longest: Option<f64>, gap| Some(longest.map_or(gap, |longest| longest.max(gap))));
+        let weeks = f64::from(window_days) / 7.0;
+        let months = f64::from(window_days) / DAYS_PER_MONTH;
+
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel_id)?;
+            result.set_item("window_days", window_days)?;
+            result.set_item("upload_count", in_window.len())?;
+            result.set_item("uploads_per_week", in_window.len() as f64 / weeks)?;
+            result.set_item("uploads_per_month", in_window.len() as f64 / months)?;
+            result.set_item("longest_gap_days", longest_gap_days)?;
+            result.set_item("last_upload_at", last_upload.map(|timestamp| timestamp.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)))?;
+            result.set_item("last_upload_age_days", last_upload.map(|timestamp| (now - timestamp).num_seconds() as f64 / 86_400.0))?;
+            for name in ["uploads_per_week", "uploads_per_month", "longest_gap_days", "last_upload_age_days"] {
+                metrics::set_derived(result, name, result.get_item(name)?, metrics::SOURCE_COMPUTED)?;
+            }
+            Ok(result.into())
+        })
+    })
+}
+
+/// Snippet, statistics, contentDetails, status and paid placement details for any number of videos, 50 IDs per request
+fn fetch_videos_with_statistics(client: &Client, credentials: &Credentials, video_ids: &[String]) -> PyResult<Vec<YouTubeVideo>> {
+    let mut videos = Vec::with_capacity(video_ids.len());
+    for batch in video_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let url = format!("{}/videos?part=snippet,statistics,contentDetails,status,paidProductPlacementDetails&id={}", client::BASE_URL, batch.join(","));
+        let response = client::send_get(client, credentials, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch videos: {}", response.status)));
+        }
+        let page: YouTubeVideoListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse videos: {}", e)))?;
+        videos.extend(page.items);
+    }
+    Ok(videos)
+}
+
+/// IDs of a channel's most viewed videos per the search index (100 quota units per 50)
+fn search_top_video_ids(client: &Client, credentials: &Credentials, channel_id: &str, count: usize) -> PyResult<Vec<String>> {
+    let mut video_ids: Vec<String> = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let remaining = count.saturating_sub(video_ids.len()).min(MAX_RESULTS_PER_PAGE).to_string();
+        let mut params = vec![
+            ("part", "id"),
+            ("channelId", channel_id),
+            ("order", "viewCount"),
+            ("type", "video"),
+            ("maxResults", remaining.as_str()),
+        ];
+        if let Some(token) = &page_token {
+            params.push(("pageToken", token.as_str()));
+        }
+        let url = Url::parse_with_params(&format!("{}/search", client::BASE_URL), &params)
+            .map_err(|e| PyValueError::new_err(format!("Invalid search URL: {}", e)))?;
+        let response = client::send_get(client, credentials, url.as_str())?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to search top videos: {}", response.status)));
+        }
+        let data: ChannelSearchIdResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse search results: {}", e)))?;
+        for video_id in data.items.into_iter().filter_map(|item| item.id.video_id) {
+            if !video_ids.contains(&video_id) {
+                video_ids.push(video_id);
+            }
+        }
+        page_token = data.next_page_token;
+        if video_ids.len() >= count || page_token.is_none() {
+            break;
+        }
+    }
+    Ok(video_ids)
+}
+
+/// A channel's all-time most viewed videos
+///
+/// By default asks search for the channel's videos ordered by `viewCount`
+/// (100 quota units per 50 videos). The search index can lag behind and skip
+/// videos, so `full_scan=True` instead walks the whole uploads playlist and
+/// ranks every upload locally, about 2 quota units per 50 uploads. Either
+/// way the ranking uses the view counts returned by the videos endpoint.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `count` - Number of videos to return (default: 10)
+/// * `full_scan` - Rank every upload instead of trusting search (default: False)
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `engagement_rate_weights` - `(like_weight, comment_weight)` for
+///   `engagement_rate` (default: `(1.0, 1.0)`)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `rank`, `video_id`, `title`,
+///   `published_at`, `view_count`, `like_count`, `comment_count` (0 when
+///   hidden), `likes_hidden`, `comments_disabled`, `engagement_rate` (see
+///   `engagement_rate_weights`), the contentDetails and status
+///   fields of `video.get_video_stats`, `has_paid_product_placement` and
+///   `video_url` dictionaries, most viewed first
+#[pyfunction]
+#[pyo3(signature = (channel_id, count=10, full_scan=false, api_key=None, engagement_rate_weights=None))]
+pub fn get_channel_top_videos(
+    channel_id: String,
+    count: usize,
+    full_scan: bool,
+    api_key: Option<Credentials>,
+    engagement_rate_weights: Option<(f64, f64)>,
+) -> PyResult<PyObject> {
+    if count == 0 {
+        return Err(PyValueError::new_err("count must be at least 1"));
+    }
+    let rate_weights = EngagementWeights::from_tuple(engagement_rate_weights, metrics::ENGAGEMENT_RATE_WEIGHTS)?;
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = channel_id_for(&client, &api_key, &channel_id)?;
+
+    let video_ids = if full_scan {
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+        playlist::fetch_playlist_items(&client, &api_key, uploads_playlist_id(&channel)?, None)?
+            .into_iter()
+            .map(|item| item.content_details.video_id)
+            .collect()
+    } else {
+        search_top_video_ids(&client, &api_key, &channel_id, count)?
+    };
+
+    let mut videos = fetch_videos_with_statistics(&client, &api_key, &video_ids)?;
+    let views = |video: &YouTubeVideo| video.statistics.as_ref().map_or(0, |stats| stats.counts().0);
+    videos.sort_by_key(|video| std::cmp::Reverse(views(video)));
+    videos.truncate(count);
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for (index, video) in videos.iter().enumerate() {
+            let (view_count, like_count, comment_count) = video.statistics.as_ref().map_or((0, 0, 0), |stats| stats.counts());
+            let entry = PyDict::new(py);
+            entry.set_item("rank", index + 1)?;
+            entry.set_item("video_id", &video.id)?;
+            entry.set_item("title", &video.snippet.title)?;
+            entry.set_item("published_at", &video.snippet.published_at)?;
+            entry.set_item("view_count", view_count)?;
+            entry.set_item("like_count", like_count)?;
+            entry.set_item("comment_count", comment_count)?;
+            VideoStatistics::set_hidden_items(video.statistics.as_ref(), entry)?;
+            entry.set_item("engagement_rate", video.statistics.as_ref().and_then(|stats| stats.engagement_rate(rate_weights)))?;
+            VideoContentDetails::set_items(video.content_details.as_ref(), entry)?;
+            VideoStatus::set_items(video.status.as_ref(), entry)?;
+            entry.set_item("has_paid_product_placement", video.paid_product_placement_details.as_ref().and_then(|p| p.has_paid_product_placement))?;
+            entry.set_item("video_url", format!("https://www.youtube.com/watch?v={}", video.id))?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+// Metrics ranked by compare_channels, higher is better for all of them
+const COMPARISON_METRICS: [&str; 4] = ["subscriber_count", "total_view_count", "video_count", "engagement_score"];
+
+// One channel's values in a comparison, in COMPARISON_METRICS order
+struct ComparedChannel {
+    channel_id: String,
+    title: String,
+    values: [Option<f64>; 4],
+    subscriber_count_hidden: bool,
+    statistics_unavailable: bool,
+    engagement_excluded_videos: usize,
+}
+
+impl ComparedChannel {
+    fn new(channel: &YouTubeChannel, engagement: Option<f64>, engagement_excluded_videos: usize) -> Self {
+        let stats = channel.statistics.as_ref();
+        let parse = |value: Option<&String>| value.and_then(|v| v.parse::<u64>().ok()).map(|v| v as f64);
+        let subscriber_count_hidden = stats.is_some_and(|stats| stats.hidden_subscriber_count);
+        // Hidden counts come back as "0" or not at all; never rank them as a value
+        let subscribers = stats
+            .filter(|_| !subscriber_count_hidden)
+            .and_then(|stats| parse(stats.subscriber_count.as_ref()));
+        ComparedChannel {
+            channel_id: channel.id.clone(),
+            title: channel.snippet.title.clone(),
+            values: [
+                subscribers,
+                stats.and_then(|stats| parse(stats.view_count.as_ref())),
+                stats.and_then(|stats| parse(stats.video_count.as_ref())),
+                engagement,
+            ],
+            subscriber_count_hidden,
+            statistics_unavailable: stats.is_none(),
+            engagement_excluded_videos,
+        }
+    }
+
+    /// Why the channel has no value for a metric, e.g. `hidden_subscriber_count`
+    fn exclusion_reason(&self, metric: &str) -> &'static str {
+        match metric {
+            _ if self.statistics_unavailable && metric != "engagement_score" => "statistics_unavailable",
+            "subscriber_count" if self.subscriber_count_hidden => "hidden_subscriber_count",
+            "engagement_score" => "no_recent_views",
+            _ => "unparsable_value",
+        }
+    }
+}
+
+/// Engagement over a channel's most recent uploads, None when it has none,
+/// and how many of them were left out for a hidden count
+fn recent_engagement(
+    client: &Client,
+    credentials: &Credentials,
+    channel: &YouTubeChannel,
+    video_count: usize,
+    weights: EngagementWeights,
+) -> PyResult<(Option<f64>, usize)> {
+    let uploads_playlist = match uploads_playlist_id(channel) {
+        Ok(playlist_id) => playlist_id,
+        Err(_) => return Ok((None, 0)),
+    };
+    let uploads = playlist::fetch_playlist_items(client, credentials, uploads_playlist, Some(video_count))?;
+    let video_ids: Vec<String> = uploads.iter().map(|item| item.content_details.video_id.clone()).collect();
+    let counts: Vec<metrics::VideoCounts> = fetch_video_statistics(client, credentials, &video_ids)?
+        .into_iter()
+        .filter_map(|video| video.statistics.map(|stats| stats.reported_counts()))
+        .collect();
+    Ok((metrics::aggregate_engagement(&counts, weights), metrics::engagement_excluded(&counts)))
+}
+
+/// Compare several channels side by side with per-metric rankings
+///
+/// Channel statistics come from one batched request per 50 channels; the
+/// engagement score over recent uploads adds about 2 quota units per channel.
+///
+/// # Arguments
+/// * `identifiers` - Channel IDs, @handles, legacy usernames or channel URLs
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `recent_video_count` - Uploads used for the engagement score (default: 10)
+/// * `engagement_weights` - `(like_weight, comment_weight)` for the engagement score
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `channels` (ID, title and the values of
+///   `subscriber_count`, `total_view_count`, `video_count` and `engagement_score`,
+///   plus `engagement_excluded_videos`, recent uploads left out of the score for
+///   a hidden like count or disabled comments),
+///   `rankings` (per metric: `ranking` of channel IDs best first, `leader`,
+///   `deltas`, each channel's difference to the leader, and `excluded`, channels
+///   without a value with a `reason` such as `hidden_subscriber_count`) and `not_found`,
+///   identifiers that match no channel; any other lookup error is raised
+#[pyfunction]
+#[pyo3(signature = (identifiers, api_key=None, recent_video_count=10, engagement_weights=None))]
+pub fn compare_channels(
+    identifiers: Vec<String>,
+    api_key: Option<Credentials>,
+    recent_video_count: usize,
+    engagement_weights: Option<(f64, f64)>,
+) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let weights = EngagementWeights::from_tuple(engagement_weights, EngagementWeights::default())?;
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+
+        let mut channel_ids: Vec<String> = Vec::with_capacity(identifiers.len());
+        let mut not_found = Vec::new();
+        for identifier in &identifiers {
+            match channel_id_for(&client, &api_key, identifier.trim()) {
+                Ok(channel_id) if !channel_ids.contains(&channel_id) => channel_ids.push(channel_id),
+                Ok(_) => {}
+                // Only a missing channel is reported; quota, auth and network errors propagate
+                Err(e) if Python::with_gil(|py| e.is_instance_of::<error::ChannelNotFoundError>(py)) => {
+                    not_found.push(identifier.clone())
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        let fetched = fetch_channels_by_id(&client, &api_key, &channel_ids)?;
+        let mut compared = Vec::with_capacity(fetched.len());
+        for channel_id in &channel_ids {
+            match fetched.get(channel_id) {
+                Some(channel) => {
+                    let (engagement, excluded) = recent_engagement(&client, &api_key, channel, recent_video_count, weights)?;
+                    compared.push(ComparedChannel::new(channel, engagement, excluded));
+                }
+                None => not_found.push(channel_id.clone()),
+            }
+        }
+
+        Python::with_gil(|py| {
+            let channels = PyList::empty(py);
+            for channel in &compared {
+                let entry = PyDict::new(py);
+                entry.set_item("channel_id", &channel.channel_id)?;
+                entry.set_item("channel_title", &channel.title)?;
+                for (metric, value) in COMPARISON_METRICS.iter().zip(channel.values) {
+                    entry.set_item(metric, value)?;
+                }
+                entry.set_item("subscriber_count_hidden", channel.subscriber_count_hidden)?;
+                entry.set_item("engagement_excluded_videos", channel.engagement_excluded_videos)?;
+                channels.append(entry)?;
+            }
+
+            let rankings = PyDict::new(py);
+            for (index, metric) in COMPARISON_METRICS.iter().enumerate() {
+                // Best first; channels without a value are left out of the ranking
+                let (mut order, excluded): (Vec<&ComparedChannel>, Vec<&ComparedChannel>) =
+                    compared.iter().partition(|channel| channel.values[index].is_some());
+                order.sort_by(|a, b| {
+                    let (a, b) = (a.values[index].unwrap_or_default(), b.values[index].unwrap_or_default());
+                    b.total_cmp(&a)
+                });
+                let leader = order.first();
+                let leader_value = leader.and_then(|channel| channel.values[index]);
+
+                let deltas = PyDict::new(py);
+                for channel in &order {
+                    let delta = channel.values[index].zip(leader_value).map(|(value, best)| value - best);
+                    deltas.set_item(&channel.channel_id, delta)?;
+                }
+                let py_excluded = PyList::empty(py);
+                for channel in &excluded {
+                    let entry = PyDict::new(py);
+                    entry.set_item("channel_id", &channel.channel_id)?;
+                    entry.set_item("reason", channel.exclusion_reason(metric))?;
+                    py_excluded.append(entry)?;
+                }
+                let ranking = PyDict::new(py);
+                ranking.set_item("ranking", order.iter().map(|channel| &channel.channel_id).collect::<Vec<_>>())?;
+                ranking.set_item("leader", leader.map(|channel| &channel.channel_id))?;
+                ranking.set_item("deltas", deltas)?;
+                ranking.set_item("excluded", py_excluded)?;
+                rankings.set_item(metric, ranking)?;
+            }
+
+            let result = PyDict::new(py);
+            result.set_item("channels", channels)?;
+            result.set_item("rankings", rankings)?;
+            result.set_item("not_found", not_found)?;
+            Ok(result.into())
+        })
+    })
+}
+
+#[derive(Default)]
+struct YearBucket {
+    upload_count: u64,
+    total_views: u64,
+}
+
+impl YearBucket {
+    fn average_views(&self) -> f64 {
+        if self.upload_count == 0 { 0.0 } else { self.total_views as f64 / self.upload_count as f64 }
+    }
+
+    fn to_py<'py>(&self, py: Python<'py>, year: i32) -> PyResult<&'py PyDict> {
+        let bucket = PyDict::new(py);
+        bucket.set_item("year", year)?;
+        bucket.set_item("upload_count", self.upload_count)?;
+        bucket.set_item("total_views", self.total_views)?;
+        bucket.set_item("average_views", self.average_views())?;
+        Ok(bucket)
+    }
+}
+
+/// Compare a channel's upload eras by bucketing its full history per year
+///
+/// Walks the whole uploads playlist, so this costs about 2 quota units per
+/// 50 uploads.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle or legacy username
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with per-year `years` buckets (upload count,
+///   total and average views), `first_year`, `latest_year` and
+///   `average_views_ratio` (latest / first; above 1 means the channel is growing)
+#[pyfunction]
+#[pyo3(signature = (channel_id, api_key=None))]
+pub fn era_comparison(channel_id: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+
+        let channel = fetch_channel_by_url(&client, &api_key, &channel_id, None)?;
+        let uploads = playlist::fetch_playlist_items(&client, &api_key, uploads_playlist_id(&channel)?, None)?;
+
+        let video_ids: Vec<String> = uploads.iter().map(|item| item.content_details.video_id.clone()).collect();
+        let views: HashMap<String, u64> = fetch_video_statistics(&client, &api_key, &video_ids)?
+            .into_iter()
+            .map(|video| {
+                let views = video.statistics.map(|stats| stats.counts().0).unwrap_or(0);
+                (video.id, views)
+            })
+            .collect();
+
+        let mut years: BTreeMap<i32, YearBucket> = BTreeMap::new();
+        for item in uploads.iter().map(|item| &item.content_details) {
+            let published = match item.video_published_at.as_deref().map(DateTime::parse_from_rfc3339) {
+                Some(Ok(published)) => published,
+                _ => continue,
+            };
+            let bucket = years.entry(published.year()).or_default();
+            bucket.upload_count += 1;
+            bucket.total_views += views.get(&item.video_id).copied().unwrap_or(0);
+        }
+
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("channel_id", &channel.id)?;
+            result.set_item("total_uploads", years.values().map(|bucket| bucket.upload_count).sum::<u64>())?;
+
+            let py_years = PyList::empty(py);
+            for (year, bucket) in &years {
+                py_years.append(bucket.to_py(py, *year)?)?;
+            }
+            result.set_item("years", py_years)?;
+
+            let first = years.iter().next();
+            let latest = years.iter().next_back();
+            result.set_item("first_year", first.map(|(year, bucket)| bucket.to_py(py, *year)).transpose()?)?;
+            result.set_item("latest_year", latest.map(|(year, bucket)| bucket.to_py(py, *year)).transpose()?)?;
+
+            let ratio = match (first, latest) {
+                (Some((_, first)), Some((_, latest))) if first.average_views() > 0.0 => {
+                    Some(latest.average_views() / first.average_views())
+                }
+                _ => None,
+            };
+            result.set_item("average_views_ratio", ratio)?;
+
+            Ok(result.into())
+        })
+    })
+}
+
+fn get_u64(dict: &PyDict, key: &str) -> PyResult<Option<u64>> {
+    match dict.get_item(key)? {
+        Some(value) if !value.is_none() => Ok(value.extract::<u64>().ok()),
+        _ => Ok(None),
+    }
+}
+
+fn check_timestamp(warnings: &PyList, field: &str, value: Option<&PyAny>) -> PyResult<()> {
+    let value = match value {
+        Some(value) if !value.is_none() => value,
+        _ => return Ok(()),
+    };
+    let text: String = value.extract()?;
+    match DateTime::parse_from_rfc3339(&text) {
+        Ok(timestamp) if timestamp.with_timezone(&Utc) > Utc::now() => {
+            add_warning(warnings, "future_timestamp", format!("{} is in the future: {}", field, text))
+        }
+        Ok(_) => Ok(()),
+        Err(_) => add_warning(warnings, "invalid_timestamp", format!("{} is not an RFC 3339 timestamp: {}", field, text)),
+    }
+}
+
+fn add_warning(warnings: &PyList, check: &str, message: String) -> PyResult<()> {
+    let warning = PyDict::new(warnings.py());
+    warning.set_item("check", check)?;
+    warning.set_item("message", message)?;
+    warnings.append(warning)
+}
+
+/// Check a channel stats result for internal consistency
+///
+/// # Arguments
+/// * `result` - Dictionary returned by `get_youtube_channel_stats`
+///
+/// # Returns
+/// * PyResult<PyObject> - List of warnings (`check`, `message`); empty when consistent
+#[pyfunction]
+pub fn validate_result(py: Python, result: &PyDict) -> PyResult<PyObject> {
+    let warnings = PyList::empty(py);
+
+    let total_views = get_u64(result, "total_view_count")?;
+    let recent_views = get_u64(result, "total_recent_views")?;
+    if let (Some(total), Some(recent)) = (total_views, recent_views) {
+        if recent > total {
+            add_warning(warnings, "recent_views_exceed_total", format!(
+                "total_recent_views ({}) exceeds total_view_count ({})", recent, total
+            ))?;
+        }
+    }
+
+    let recent_videos: Option<&PyList> = match result.get_item("recent_videos")? {
+        Some(videos) => Some(videos.downcast()?),
+        None => None,
+    };
+
+    if let (Some(video_count), Some(videos)) = (get_u64(result, "video_count")?, recent_videos) {
+        if (videos.len() as u64) > video_count {
+            add_warning(warnings, "video_count_too_low", format!(
+                "video_count ({}) is lower than the number of recent videos ({})", video_count, videos.len()
+            ))?;
+        }
+    }
+
+    let hidden = match result.get_item("subscriber_count_hidden")? {
+        Some(value) => value.is_true()?,
+        None => false,
+    };
+    if hidden && get_u64(result, "subscriber_count")?.is_some() {
+        add_warning(warnings, "hidden_subscribers_present", "subscriber_count is set although it is marked hidden".to_string())?;
+    }
+
+    check_timestamp(warnings, "published_at", result.get_item("published_at")?)?;
+
+    if let Some(videos) = recent_videos {
+        for video in videos.iter() {
+            let video: &PyDict = video.downcast()?;
+            let video_id: String = match video.get_item("video_id")? {
+                Some(id) => id.extract()?,
+                None => "<unknown>".to_string(),
+            };
+            if let (Some(total), Some(views)) = (total_views, get_u64(video, "view_count")?) {
+                if views > total {
+                    add_warning(warnings, "video_views_exceed_total", format!(
+                        "video {} has more views ({}) than the whole channel ({})", video_id, views, total
+                    ))?;
+                }
+            }
+            check_timestamp(warnings, &format!("recent_videos[{}].published_at", video_id), video.get_item("published_at")?)?;
+        }
+    }
+
+    Ok(warnings.into())
+}
@@ -1,26 +1,809 @@
 use pyo3::prelude::*;
-//use reqwest::blocking::Client;
-//use serde::{Deserialize, Serialize};
+use pyo3::types::{PyDict, PyList};
+use reqwest::blocking::Client;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
 use std::env;
-use dotenv::dotenv;
-use pyo3::exceptions::PyValueError;
-//use crate::error;
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::client;
+use crate::error::ApiNotEnabledError;
+use crate::secrets;
+use crate::utils::redact;
+use crate::config::{self, AuthConfig};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+
+pub const SCOPE_YOUTUBE: &str = "https://www.googleapis.com/auth/youtube";
+pub const SCOPE_YOUTUBE_READONLY: &str = "https://www.googleapis.com/auth/youtube.readonly";
+pub const SCOPE_YOUTUBE_FORCE_SSL: &str = "https://www.googleapis.com/auth/youtube.force-ssl";
+pub const SCOPE_YT_ANALYTICS_READONLY: &str = "https://www.googleapis.com/auth/yt-analytics.readonly";
+pub const SCOPE_YT_ANALYTICS_MONETARY_READONLY: &str = "https://www.googleapis.com/auth/yt-analytics-monetary.readonly";
+pub const SCOPE_YOUTUBEPARTNER_CHANNEL_AUDIT: &str = "https://www.googleapis.com/auth/youtubepartner-channel-audit";
+pub const SCOPE_CHANNEL_MEMBERSHIPS_CREATOR: &str = "https://www.googleapis.com/auth/youtube.channel-memberships.creator";
+
+// Scope constants exported to Python as auth.SCOPE_*
+pub const SCOPES: [(&str, &str); 7] = [
+    ("SCOPE_YOUTUBE", SCOPE_YOUTUBE),
+    ("SCOPE_YOUTUBE_READONLY", SCOPE_YOUTUBE_READONLY),
+    ("SCOPE_YOUTUBE_FORCE_SSL", SCOPE_YOUTUBE_FORCE_SSL),
+    ("SCOPE_YT_ANALYTICS_READONLY", SCOPE_YT_ANALYTICS_READONLY),
+    ("SCOPE_YT_ANALYTICS_MONETARY_READONLY", SCOPE_YT_ANALYTICS_MONETARY_READONLY),
+    ("SCOPE_YOUTUBEPARTNER_CHANNEL_AUDIT", SCOPE_YOUTUBEPARTNER_CHANNEL_AUDIT),
+    ("SCOPE_CHANNEL_MEMBERSHIPS_CREATOR", SCOPE_CHANNEL_MEMBERSHIPS_CREATOR),
+];
+
+/// Expand short scope names (`"yt-analytics.readonly"`) to full scope URLs
+///
+/// Defaults to read-only YouTube access when no scopes are given.
+pub(crate) fn resolve_scopes(scopes: Option<Vec<String>>) -> PyResult<Vec<String>> {
+    let scopes = match scopes {
+        Some(scopes) if !scopes.is_empty() => scopes,
+        Some(_) => return Err(PyValueError::new_err("At least one OAuth scope is required")),
+        None => return Ok(vec![SCOPE_YOUTUBE_READONLY.to_string()]),
+    };
+    Ok(scopes.into_iter()
+        .map(|scope| {
+            if scope.starts_with("https://") {
+                scope
+            } else {
+                format!("https://www.googleapis.com/auth/{}", scope)
+            }
+        })
+        .collect())
+}
+
+// Service account key file structure (as downloaded from the Google Cloud console)
+#[derive(Debug, Deserialize)]
+struct ServiceAccountKey {
+    client_email: String,
+    private_key: String,
+    private_key_id: Option<String>,
+    #[serde(default = "default_token_uri")]
+    token_uri: String,
+}
+
+fn default_token_uri() -> String {
+    "https://oauth2.googleapis.com/token".to_string()
+}
+
+#[derive(Debug, Serialize)]
+struct JwtClaims<'a> {
+    iss: &'a str,
+    scope: String,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeviceCodeResponse {
+    device_code: String,
+    user_code: String,
+    verification_url: String,
+    expires_in: u64,
+    #[serde(default = "default_poll_interval")]
+    interval: u64,
+}
+
+fn default_poll_interval() -> u64 {
+    5
+}
+
+#[derive(Debug, Deserialize)]
+struct DeviceTokenResponse {
+    access_token: Option<String>,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    access_token: String,
+    expires_at: u64,
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Credentials minted from a Google service-account JSON key
+///
+/// The signed JWT is exchanged for an access token on first use and the token
+/// is reused until shortly before it expires.
+#[pyclass]
+#[derive(Clone)]
+pub struct ServiceAccountCredentials {
+    key: Arc<ServiceAccountKey>,
+    scopes: Vec<String>,
+    token: Arc<Mutex<Option<CachedToken>>>,
+}
+
+#[pymethods]
+impl ServiceAccountCredentials {
+    #[getter]
+    fn client_email(&self) -> String {
+        self.key.client_email.clone()
+    }
+
+    #[getter]
+    fn scopes(&self) -> Vec<String> {
+        self.scopes.clone()
+    }
+
+    /// Return a valid access token, exchanging a new JWT if needed
+    fn token(&self) -> PyResult<String> {
+        self.access_token(&Client::new())
+    }
+}
+
+impl ServiceAccountCredentials {
+    pub(crate) fn access_token(&self, client: &Client) -> PyResult<String> {
+        let now = unix_now();
+        let mut cached = self.token.lock().unwrap();
+        if let Some(token) = cached.as_ref() {
+            if token.expires_at > now + 60 {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let claims = JwtClaims {
+            iss: &self.key.client_email,
+            scope: self.scopes.join(" "),
+            aud: &self.key.token_uri,
+            iat: now,
+            exp: now + 3600,
+        };
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = self.key.private_key_id.clone();
+
+        let encoding_key = EncodingKey::from_rsa_pem(self.key.private_key.as_bytes())
+            .map_err(|e| PyValueError::new_err(format!("Invalid service account private key: {}", e)))?;
+        let assertion = encode(&header, &claims, &encoding_key)
+            .map_err(|e| PyValueError::new_err(format!("Failed to sign service account JWT: {}", e)))?;
+
+        let resp = client.post(&self.key.token_uri)
+            .form(&[
+                ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                ("assertion", assertion.as_str()),
+            ])
+            .send()
+            .map_err(|e| PyValueError::new_err(format!("Token request failed: {}", e)))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let error_text = resp.text()
+                .unwrap_or_else(|_| "Could not read error response".to_string());
+            return Err(PyValueError::new_err(redact(&format!("Token exchange failed: {} - {}", status, error_text))));
+        }
+
+        let token: TokenResponse = resp.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse token response: {}", e)))?;
+
+        *cached = Some(CachedToken {
+            access_token: token.access_token.clone(),
+            expires_at: now + token.expires_in,
+        });
+        Ok(token.access_token)
+    }
+}
+
+/// OAuth user credentials (access token plus optional refresh token)
+///
+/// When a refresh token and client ID/secret are present the access token is
+/// refreshed automatically shortly before it expires.
+#[pyclass]
+#[derive(Clone)]
+pub struct OAuthCredentials {
+    refresh_token: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    token_uri: String,
+    token: Arc<Mutex<Option<CachedToken>>>,
+}
+
+#[pymethods]
+impl OAuthCredentials {
+    #[new]
+    #[pyo3(signature = (access_token=None, refresh_token=None, client_id=None, client_secret=None, expires_at=None))]
+    pub fn new(
+        access_token: Option<String>,
+        refresh_token: Option<String>,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+        expires_at: Option<u64>,
+    ) -> PyResult<Self> {
+        if access_token.is_none() && refresh_token.is_none() {
+            return Err(PyValueError::new_err("OAuthCredentials requires an access_token or a refresh_token"));
+        }
+        let token = access_token.map(|access_token| CachedToken {
+            access_token,
+            expires_at: expires_at.unwrap_or(u64::MAX),
+        });
+        Ok(OAuthCredentials {
+            refresh_token,
+            client_id,
+            client_secret,
+            token_uri: default_token_uri(),
+            token: Arc::new(Mutex::new(token)),
+        })
+    }
+
+    #[getter]
+    fn refresh_token(&self) -> Option<String> {
+        self.refresh_token.clone()
+    }
+
+    /// Return a valid access token, refreshing it if needed
+    fn token(&self) -> PyResult<String> {
+        self.access_token(&Client::new())
+    }
+
+    /// Store the refresh token in the OS keyring instead of on disk
+    #[pyo3(signature = (account="default"))]
+    fn save_to_keyring(&self, account: &str) -> PyResult<()> {
+        let refresh_token = self.refresh_token.as_ref()
+            .ok_or_else(|| PyValueError::new_err("These credentials have no refresh token"))?;
+        secrets::set_secret(&secrets::refresh_token_entry(account), refresh_token)
+    }
+
+    /// Load credentials from a refresh token stored with `save_to_keyring`
+    #[staticmethod]
+    #[pyo3(signature = (client_id, client_secret=None, account="default"))]
+    fn from_keyring(client_id: String, client_secret: Option<String>, account: &str) -> PyResult<Self> {
+        let refresh_token = secrets::get_secret(&secrets::refresh_token_entry(account))
+            .ok_or_else(|| PyValueError::new_err(format!("No refresh token stored in keyring for '{}'", account)))?;
+        OAuthCredentials::new(None, Some(refresh_token), Some(client_id), client_secret, None)
+    }
+}
+
+impl OAuthCredentials {
+    pub(crate) fn access_token(&self, client: &Client) -> PyResult<String> {
+        let now = unix_now();
+        let mut cached = self.token.lock().unwrap();
+        if let Some(token) = cached.as_ref() {
+            if token.expires_at > now + 60 {
+                return Ok(token.access_token.clone());
+            }
+        }
+
+        let (refresh_token, client_id) = match (&self.refresh_token, &self.client_id) {
+            (Some(refresh_token), Some(client_id)) => (refresh_token, client_id),
+            _ => return Err(PyValueError::new_err(
+                "OAuth access token expired and no refresh token/client ID is available",
+            )),
+        };
+
+        let mut form = vec![
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token.as_str()),
+            ("client_id", client_id.as_str()),
+        ];
+        if let Some(client_secret) = &self.client_secret {
+            form.push(("client_secret", client_secret.as_str()));
+        }
+
+        let resp = client.post(&self.token_uri)
+            .form(&form)
+            .send()
+            .map_err(|e| PyValueError::new_err(format!("Token request failed: {}", e)))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let error_text = resp.text()
+                .unwrap_or_else(|_| "Could not read error response".to_string());
+            return Err(PyValueError::new_err(redact(&format!("Token refresh failed: {} - {}", status, error_text))));
+        }
+
+        let token: TokenResponse = resp.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse token response: {}", e)))?;
+
+        *cached = Some(CachedToken {
+            access_token: token.access_token.clone(),
+            expires_at: now + token.expires_in,
+        });
+        Ok(token.access_token)
+    }
+}
+
+/// Pool of API keys rotated per request
+///
+/// Keys that hit `quotaExceeded` are skipped until `reset()` is called, so
+/// spreading quota across several projects needs no caller-side bookkeeping.
+#[pyclass]
+#[derive(Clone)]
+pub struct KeyPool {
+    inner: Arc<KeyPoolState>,
+}
+
+struct KeyPoolState {
+    keys: Vec<String>,
+    next: AtomicUsize,
+    usage: Mutex<Vec<KeyUsage>>,
+}
+
+#[derive(Clone, Default)]
+struct KeyUsage {
+    requests: u64,
+    quota_exceeded: u64,
+    exhausted: bool,
+}
+
+// Only the first characters of a key are ever reported back
+fn mask_key(key: &str) -> String {
+    let prefix: String = key.chars().take(6).collect();
+    format!("{}...", prefix)
+}
+
+#[pymethods]
+impl KeyPool {
+    #[new]
+    pub fn new(keys: Vec<String>) -> PyResult<Self> {
+        if keys.is_empty() {
+            return Err(PyValueError::new_err("KeyPool requires at least one API key"));
+        }
+        let usage = vec![KeyUsage::default(); keys.len()];
+        Ok(KeyPool {
+            inner: Arc::new(KeyPoolState {
+                keys,
+                next: AtomicUsize::new(0),
+                usage: Mutex::new(usage),
+            }),
+        })
+    }
+
+    /// Per-key usage report: masked key, successful requests, quota errors, exhausted flag
+    fn usage(&self, py: Python) -> PyResult<PyObject> {
+        let usage = self.inner.usage.lock().unwrap();
+        let report = PyList::empty(py);
+        for (key, key_usage) in self.inner.keys.iter().zip(usage.iter()) {
+            let entry = PyDict::new(py);
+            entry.set_item("key", mask_key(key))?;
+            entry.set_item("requests", key_usage.requests)?;
+            entry.set_item("quota_exceeded", key_usage.quota_exceeded)?;
+            entry.set_item("exhausted", key_usage.exhausted)?;
+            report.append(entry)?;
+        }
+        Ok(report.into())
+    }
+
+    /// Mark every key as available again (e.g. after the daily quota reset)
+    fn reset(&self) {
+        let mut usage = self.inner.usage.lock().unwrap();
+        for key_usage in usage.iter_mut() {
+            key_usage.exhausted = false;
+        }
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.keys.len()
+    }
+}
+
+impl KeyPool {
+    /// Next non-exhausted key in round-robin order
+    pub(crate) fn next_key(&self) -> Option<(usize, String)> {
+        let count = self.inner.keys.len();
+        let start = self.inner.next.fetch_add(1, Ordering::Relaxed);
+        let usage = self.inner.usage.lock().unwrap();
+        (0..count)
+            .map(|offset| (start + offset) % count)
+            .find(|&index| !usage[index].exhausted)
+            .map(|index| (index, self.inner.keys[index].clone()))
+    }
+
+    pub(crate) fn record_request(&self, index: usize) {
+        self.inner.usage.lock().unwrap()[index].requests += 1;
+    }
+
+    pub(crate) fn record_quota_exceeded(&self, index: usize) {
+        let mut usage = self.inner.usage.lock().unwrap();
+        usage[index].quota_exceeded += 1;
+        usage[index].exhausted = true;
+    }
+}
+
+/// Credentials accepted wherever the API functions take `api_key`
+///
+/// A key string, a list of keys (rotated as a throwaway pool), a `KeyPool`,
+/// service-account or OAuth credentials, or an `AuthConfig`.
+#[derive(Clone)]
+pub enum Credentials {
+    ApiKey(String),
+    Pool(KeyPool),
+    ServiceAccount(ServiceAccountCredentials),
+    OAuth(OAuthCredentials),
+    Config(Box<AuthConfig>),
+}
+
+impl<'source> FromPyObject<'source> for Credentials {
+    fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        if let Ok(key) = ob.extract::<String>() {
+            return Ok(Credentials::ApiKey(key));
+        }
+        if let Ok(pool) = ob.extract::<KeyPool>() {
+            return Ok(Credentials::Pool(pool));
+        }
+        if let Ok(account) = ob.extract::<ServiceAccountCredentials>() {
+            return Ok(Credentials::ServiceAccount(account));
+        }
+        if let Ok(oauth) = ob.extract::<OAuthCredentials>() {
+            return Ok(Credentials::OAuth(oauth));
+        }
+        if let Ok(config) = ob.extract::<AuthConfig>() {
+            return Ok(Credentials::Config(Box::new(config)));
+        }
+        if let Ok(keys) = ob.extract::<Vec<String>>() {
+            return Ok(Credentials::Pool(KeyPool::new(keys)?));
+        }
+        Err(PyTypeError::new_err(
+            "api_key must be a key string, a list of keys, a KeyPool, OAuth or service-account credentials, or an AuthConfig",
+        ))
+    }
+}
+
+impl Credentials {
+    /// Use the explicit credentials, else the default AuthConfig, else the env key(s)
+    pub(crate) fn resolve(api_key: Option<Credentials>) -> PyResult<Credentials> {
+        if let Some(credentials) = api_key {
+            return Ok(credentials);
+        }
+        if let Some(config) = config::default_config() {
+            return Ok(Credentials::Config(Box::new(config)));
+        }
+        let mut keys = get_youtube_api_keys()?;
+        if keys.len() == 1 {
+            Ok(Credentials::ApiKey(keys.remove(0)))
+        } else {
+            Ok(Credentials::Pool(KeyPool::new(keys)?))
+        }
+    }
+
+    /// True when requests are made on behalf of a signed-in user (OAuth)
+    pub(crate) fn is_user_oauth(&self) -> bool {
+        match self {
+            Credentials::OAuth(_) => true,
+            Credentials::Config(config) => config.credentials().is_some_and(Credentials::is_user_oauth),
+            _ => false,
+        }
+    }
+
+    /// Configuration the credentials were loaded from, if any
+    pub(crate) fn config(&self) -> Option<&AuthConfig> {
+        match self {
+            Credentials::Config(config) => Some(config),
+            _ => None,
+        }
+    }
+}
+
+/// Load `.env`, or the file named by YOUTUBE_STATS_ENV_FILE when set
+///
+/// Missing files are ignored; variables already in the environment win.
+pub(crate) fn load_default_env() {
+    match env::var("YOUTUBE_STATS_ENV_FILE") {
+        Ok(path) => {
+            dotenv::from_path(&path).ok();
+        }
+        Err(_) => {
+            dotenv::dotenv().ok();
+        }
+    }
+}
+
+/// Explicitly load environment variables from a dotenv file
+///
+/// # Arguments
+/// * `path` - File to load (default: YOUTUBE_STATS_ENV_FILE, then `.env`)
+///
+/// Variables already present in the environment are not overwritten.
+#[pyfunction]
+#[pyo3(signature = (path=None))]
+pub fn load_env(path: Option<String>) -> PyResult<()> {
+    let path = path
+        .or_else(|| env::var("YOUTUBE_STATS_ENV_FILE").ok())
+        .unwrap_or_else(|| ".env".to_string());
+    dotenv::from_path(&path)
+        .map_err(|e| PyValueError::new_err(format!("Failed to load env file {}: {}", path, e)))
+}
 
 #[pyfunction]
 pub fn get_youtube_api_key() -> PyResult<String> {
 
-    dotenv().ok();
+    load_default_env();
     match env::var("YOUTUBE_API_KEY") {
         Ok(key) => Ok(key),
-        Err(_) => Err(PyValueError::new_err("You must set the environment variable YOUTUBE_API_KEY"))
+        Err(_) => secrets::get_secret(secrets::API_KEY_ENTRY).ok_or_else(|| {
+            PyValueError::new_err("You must set the environment variable YOUTUBE_API_KEY")
+        })
     }
 }
 
+/// Get every configured API key, ready to feed a `KeyPool`
+///
+/// Reads the comma-separated `YOUTUBE_API_KEYS` variable, falling back to
+/// the singular `YOUTUBE_API_KEY` (and the keyring).
+///
+/// # Returns
+/// * PyResult<Vec<String>> - Deduplicated keys in the order given
+#[pyfunction]
+pub fn get_youtube_api_keys() -> PyResult<Vec<String>> {
+    load_default_env();
+    if let Ok(value) = env::var("YOUTUBE_API_KEYS") {
+        let mut keys: Vec<String> = Vec::new();
+        for key in value.split(',').map(str::trim).filter(|key| !key.is_empty()) {
+            if !keys.iter().any(|existing| existing == key) {
+                keys.push(key.to_string());
+            }
+        }
+        if !keys.is_empty() {
+            return Ok(keys);
+        }
+    }
+    get_youtube_api_key()
+        .map(|key| vec![key])
+        .map_err(|_| PyValueError::new_err("You must set the environment variable YOUTUBE_API_KEYS or YOUTUBE_API_KEY"))
+}
 
+/// Store an API key in the OS keyring, used when YOUTUBE_API_KEY is not set
+///
+/// Requires the crate to be built with the `keyring` feature.
 #[pyfunction]
-pub fn call_youtube_client(endpoint_url: Option<String>, api_key: Option<String>) -> PyResult<()> {
+pub fn store_api_key(key: String) -> PyResult<()> {
+    secrets::set_secret(secrets::API_KEY_ENTRY, &key)
+}
+
+/// Remove the API key stored in the OS keyring
+#[pyfunction]
+pub fn delete_api_key() -> PyResult<()> {
+    secrets::delete_secret(secrets::API_KEY_ENTRY)
+}
+
+
+/// Call any YouTube Data API v3 endpoint and return the parsed JSON
+///
+/// # Arguments
+/// * `endpoint_url` - Endpoint path such as `"channels"` or `"/playlistItems"`,
+///   or a full `https://www.googleapis.com/youtube/v3/...` URL
+/// * `params` - Query parameters; list values are joined with commas
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///
+/// # Returns
+/// * PyResult<PyObject> - The JSON response as Python dicts/lists
+#[pyfunction]
+#[pyo3(signature = (endpoint_url, params=None, api_key=None))]
+pub fn call_youtube_client(
+    endpoint_url: String,
+    params: Option<&PyDict>,
+    api_key: Option<Credentials>,
+) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+
+    let endpoint = endpoint_url
+        .strip_prefix(client::BASE_URL)
+        .unwrap_or(&endpoint_url)
+        .trim_start_matches('/');
+    let mut url = Url::parse(&format!("{}/{}", client::BASE_URL, endpoint))
+        .map_err(|e| PyValueError::new_err(format!("Invalid endpoint {}: {}", endpoint_url, e)))?;
+
+    if let Some(params) = params {
+        let mut query = url.query_pairs_mut();
+        for (name, value) in params.iter() {
+            let name: String = name.extract()?;
+            let value = if let Ok(values) = value.downcast::<PyList>() {
+                values.iter()
+                    .map(|item| item.str().map(|s| s.to_string()))
+                    .collect::<PyResult<Vec<String>>>()?
+                    .join(",")
+            } else if let Ok(flag) = value.extract::<bool>() {
+                flag.to_string()
+            } else {
+                value.str()?.to_string()
+            };
+            query.append_pair(&name, &value);
+        }
+    }
+
+    let response = client::send_get(&client, &api_key, url.as_str())?;
+    if !response.is_success() {
+        let message = response.error()
+            .map(|error| error.message)
+            .unwrap_or_else(|| response.body.clone());
+        return Err(PyValueError::new_err(redact(&format!("Request failed: {} - {}", response.status, message))));
+    }
+
+    let data: serde_json::Value = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse response: {}", e)))?;
+
+    Python::with_gil(|py| client::json_to_py(py, &data))
+}
+
+
+/// Load service-account credentials from a JSON key file
+///
+/// # Arguments
+/// * `path` - Path to the service-account JSON key
+/// * `scopes` - OAuth scopes to request, as `auth.SCOPE_*` constants or short
+///   names like `"yt-analytics.readonly"` (default: youtube.readonly)
+///
+/// # Returns
+/// * PyResult<ServiceAccountCredentials> - Credentials usable as `api_key` in every API function
+#[pyfunction]
+#[pyo3(signature = (path, scopes=None))]
+pub fn from_service_account(path: String, scopes: Option<Vec<String>>) -> PyResult<ServiceAccountCredentials> {
+    let scopes = resolve_scopes(scopes)?;
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| PyValueError::new_err(format!("Failed to read service account file {}: {}", path, e)))?;
+    let key: ServiceAccountKey = serde_json::from_str(&contents)
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse service account file: {}", e)))?;
+
+    Ok(ServiceAccountCredentials {
+        key: Arc::new(key),
+        scopes,
+        token: Arc::new(Mutex::new(None)),
+    })
+}
+
+
+/// Check whether an API key works using a 1-unit `i18nLanguages` call
+///
+/// # Arguments
+/// * `key` - YouTube Data API v3 key
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `valid` (bool), `status`
+///   (`valid`, `invalid`, `restricted`, `quota_exceeded` or `error`),
+///   and the API `reason`/`message` when the call failed
+#[pyfunction]
+pub fn validate_api_key(key: String) -> PyResult<PyObject> {
+    let client = Client::new();
+    let url = "https://www.googleapis.com/youtube/v3/i18nLanguages?part=snippet&hl=en";
+    let credentials = Credentials::ApiKey(key);
+
+    let (status, reason, message) = match client::send_get_uncached(&client, &credentials, url) {
+        Ok(response) if response.is_success() => ("valid", None, None),
+        Ok(response) => {
+            let error = response.error();
+            let reason = error.as_ref()
+                .and_then(|e| e.errors.first())
+                .map(|detail| detail.reason.clone());
+            let message = error.as_ref()
+                .map(|e| e.message.clone())
+                .unwrap_or_else(|| response.status.to_string());
+            let status = match reason.as_deref() {
+                Some("quotaExceeded") | Some("dailyLimitExceeded") => "quota_exceeded",
+                Some("keyInvalid") | Some("keyExpired") => "invalid",
+                _ if message.contains("API key not valid") || message.contains("API key expired") => "invalid",
+                _ if response.status.as_u16() == 403 => "restricted",
+                _ if response.status.as_u16() == 400 => "invalid",
+                _ => "error",
+            };
+            (status, reason, Some(message))
+        }
+        Err(e) => {
+            let status = Python::with_gil(|py| {
+                if e.is_instance_of::<ApiNotEnabledError>(py) { "restricted" } else { "error" }
+            });
+            let reason = (status == "restricted").then(|| "accessNotConfigured".to_string());
+            (status, reason, Some(e.to_string()))
+        }
+    };
+
+    Python::with_gil(|py| {
+        let result = PyDict::new(py);
+        result.set_item("valid", status == "valid")?;
+        result.set_item("status", status)?;
+        result.set_item("reason", reason)?;
+        result.set_item("message", message)?;
+        Ok(result.into())
+    })
+}
+
+
+/// Authorize on a headless machine with the OAuth device flow
+///
+/// Prints a verification URL and user code, then polls until the user
+/// approves the request on another device.
+///
+/// # Arguments
+/// * `client_id` - OAuth client ID (type "TVs and Limited Input devices")
+/// * `client_secret` - OAuth client secret
+/// * `scopes` - OAuth scopes (default: youtube.readonly)
+///
+/// # Returns
+/// * PyResult<OAuthCredentials> - Credentials with an access and refresh token
+#[pyfunction]
+#[pyo3(signature = (client_id, client_secret=None, scopes=None))]
+pub fn device_login(
+    py: Python,
+    client_id: String,
+    client_secret: Option<String>,
+    scopes: Option<Vec<String>>,
+) -> PyResult<OAuthCredentials> {
+    let client = Client::new();
+    let scope = resolve_scopes(scopes)?.join(" ");
+
+    let resp = client.post(client::apply_base_url_override(&format!("{}/device/code", client::OAUTH_URL)))
+        .form(&[("client_id", client_id.as_str()), ("scope", scope.as_str())])
+        .send()
+        .map_err(|e| PyValueError::new_err(format!("Device code request failed: {}", e)))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let error_text = resp.text()
+            .unwrap_or_else(|_| "Could not read error response".to_string());
+        return Err(PyValueError::new_err(redact(&format!("Device code request failed: {} - {}", status, error_text))));
+    }
+
+    let device: DeviceCodeResponse = resp.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse device code response: {}", e)))?;
+
+    // Through Python's print, so redirected sys.stdout and notebooks show the prompt
+    let prompt = format!("To authorize youtube_stats, visit {} and enter the code: {}", device.verification_url, device.user_code);
+    py.import("builtins")?.getattr("print")?.call1((prompt,))?;
+
+    let deadline = unix_now() + device.expires_in;
+    let mut interval = device.interval;
+
+    while unix_now() < deadline {
+        // Other Python threads keep running while this one waits for the user
+        py.allow_threads(|| std::thread::sleep(std::time::Duration::from_secs(interval)));
+        // Let Ctrl+C interrupt the polling loop
+        py.check_signals()?;
+
+        let mut form = vec![
+            ("client_id", client_id.as_str()),
+            ("device_code", device.device_code.as_str()),
+            ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
+        ];
+        if let Some(client_secret) = &client_secret {
+            form.push(("client_secret", client_secret.as_str()));
+        }
+
+        let token: DeviceTokenResponse = py.allow_threads(|| {
+            client.post(client::apply_base_url_override(&default_token_uri()))
+                .form(&form)
+                .send()
+                .map_err(|e| PyValueError::new_err(format!("Token request failed: {}", e)))?
+                .json()
+                .map_err(|e| PyValueError::new_err(format!("Failed to parse token response: {}", e)))
+        })?;
+
+        match (token.access_token, token.error.as_deref()) {
+            (Some(access_token), _) => {
+                return OAuthCredentials::new(
+                    Some(access_token),
+                    token.refresh_token,
+                    Some(client_id.clone()),
+                    client_secret.clone(),
+                    token.expires_in.map(|expires_in| unix_now() + expires_in),
+                );
+            }
+            (None, Some("authorization_pending")) => continue,
+            (None, Some("slow_down")) => interval += 5,
+            (None, error) => {
+                return Err(PyValueError::new_err(format!(
+                    "Device authorization failed: {} {}",
+                    error.unwrap_or("unknown_error"),
+                    token.error_description.unwrap_or_default()
+                )));
+            }
+        }
+    }
 
-    println!("endpoint_url: {:?}", endpoint_url);
-    println!("api_key: {:?}", api_key);
-    Ok(())
+    Err(PyValueError::new_err("Device authorization expired before the code was approved"))
 }
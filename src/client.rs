@@ -0,0 +1,525 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyDict, PyList};
+use chrono::{SecondsFormat, Utc};
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::{StatusCode, Url};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, RwLock};
+use std::thread;
+use std::time::{Duration, Instant};
+use crate::auth::{get_youtube_api_key, Credentials, KeyPool};
+use crate::config::{self, AuthConfig};
+use crate::error;
+use crate::utils::redact;
+
+pub(crate) const BASE_URL: &str = "https://www.googleapis.com/youtube/v3";
+// youtube.com pages probed for facts the Data API does not expose
+pub(crate) const WEB_URL: &str = "https://www.youtube.com";
+// Return YouTube Dislike, a third-party service estimating dislike counts
+pub(crate) const DISLIKE_API_URL: &str = "https://returnyoutubedislikeapi.com";
+// Static host serving video thumbnails at /vi/<video_id>/<quality>.jpg
+pub(crate) const THUMBNAIL_URL: &str = "https://i.ytimg.com";
+// Google's OAuth endpoints, used by the device flow
+pub(crate) const OAUTH_URL: &str = "https://oauth2.googleapis.com";
+
+// Error response structure
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct YouTubeErrorResponse {
+    pub error: YouTubeError,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct YouTubeError {
+    pub code: u32,
+    pub message: String,
+    #[serde(default)]
+    pub errors: Vec<ErrorDetail>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ErrorDetail {
+    pub message: String,
+    pub domain: String,
+    pub reason: String,
+}
+
+// Replacement for BASE_URL while a testing.MockServer is installed
+static BASE_URL_OVERRIDE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Route API calls to `base_url`; fails with the installed URL when another
+/// mock server already holds the override
+pub(crate) fn install_base_url_override(base_url: String) -> Result<(), String> {
+    let mut current = BASE_URL_OVERRIDE.write().unwrap();
+    match current.as_ref() {
+        Some(installed) if *installed != base_url => Err(installed.clone()),
+        _ => {
+            *current = Some(base_url);
+            Ok(())
+        }
+    }
+}
+
+/// Clear the override, but only if `base_url` is the one installed
+pub(crate) fn uninstall_base_url_override(base_url: &str) {
+    let mut current = BASE_URL_OVERRIDE.write().unwrap();
+    if current.as_deref() == Some(base_url) {
+        *current = None;
+    }
+}
+
+pub(crate) fn apply_base_url_override(url: &str) -> String {
+    let rest = url.strip_prefix(BASE_URL)
+        .or_else(|| url.strip_prefix(WEB_URL))
+        .or_else(|| url.strip_prefix(THUMBNAIL_URL))
+        .or_else(|| url.strip_prefix(DISLIKE_API_URL))
+        .or_else(|| url.strip_prefix(OAUTH_URL));
+    match (BASE_URL_OVERRIDE.read().unwrap().as_deref(), rest) {
+        (Some(base_url), Some(rest)) => format!("{}{}", base_url, rest),
+        _ => url.to_string(),
+    }
+}
+
+// Responses captured while `testing.record_fixture` runs, as (url, response)
+static RECORDED_RESPONSES: Mutex<Option<Vec<(String, ApiResponse)>>> = Mutex::new(None);
+
+/// Start capturing every API response; false when a capture is already running
+pub(crate) fn start_recording() -> bool {
+    let mut recorded = RECORDED_RESPONSES.lock().unwrap();
+    if recorded.is_some() {
+        return false;
+    }
+    *recorded = Some(Vec::new());
+    true
+}
+
+/// Stop capturing and return what was recorded since `start_recording`
+pub(crate) fn stop_recording() -> Vec<(String, ApiResponse)> {
+    RECORDED_RESPONSES.lock().unwrap().take().unwrap_or_default()
+}
+
+fn record_response(url: &str, response: &ApiResponse) {
+    if let Some(recorded) = RECORDED_RESPONSES.lock().unwrap().as_mut() {
+        recorded.push((url.to_string(), response.clone()));
+    }
+}
+
+/// One API request behind a result, for its `_meta` provenance
+#[derive(Debug, Clone)]
+pub(crate) struct RequestRecord {
+    pub endpoint: String,
+    pub etag: Option<String>,
+    pub cache_hit: bool,
+    pub quota_cost: u32,
+}
+
+thread_local! {
+    // Requests made on this thread while `track_requests` runs
+    static REQUEST_LOG: RefCell<Option<Vec<RequestRecord>>> = const { RefCell::new(None) };
+}
+
+/// Quota units the Data API charges for one call to `endpoint`
+fn quota_cost(endpoint: &str) -> u32 {
+    match endpoint {
+        "search" => 100,
+        "captions" => 50,
+        _ => 1,
+    }
+}
+
+fn log_request(url: &str, response: &ApiResponse, cache_hit: bool) {
+    REQUEST_LOG.with(|log| {
+        if let Some(records) = log.borrow_mut().as_mut() {
+            let endpoint = Url::parse(url).ok()
+                .and_then(|url| url.path_segments().and_then(|mut segments| segments.next_back().map(str::to_string)))
+                .unwrap_or_default();
+            let etag = serde_json::from_str::<serde_json::Value>(&response.body).ok()
+                .and_then(|body| body.get("etag").and_then(|etag| etag.as_str().map(str::to_string)));
+            let quota_cost = if cache_hit { 0 } else { quota_cost(&endpoint) };
+            records.push(RequestRecord { endpoint, etag, cache_hit, quota_cost });
+        }
+    });
+}
+
+/// Run `f`, returning what it produced and the API requests it made on this thread
+///
+/// Calls nest: requests seen by an inner tracker are also reported to the outer one.
+pub(crate) fn track_requests<T>(f: impl FnOnce() -> T) -> (T, Vec<RequestRecord>) {
+    let outer = REQUEST_LOG.with(|log| log.replace(Some(Vec::new())));
+    let result = f();
+    let records = REQUEST_LOG.with(|log| log.replace(outer)).unwrap_or_default();
+    REQUEST_LOG.with(|log| {
+        if let Some(outer) = log.borrow_mut().as_mut() {
+            outer.extend(records.iter().cloned());
+        }
+    });
+    (result, records)
+}
+
+/// Report requests made on a worker thread to this thread's tracker, if any
+pub(crate) fn extend_tracked_requests(records: Vec<RequestRecord>) {
+    REQUEST_LOG.with(|log| {
+        if let Some(current) = log.borrow_mut().as_mut() {
+            current.extend(records);
+        }
+    });
+}
+
+/// `_meta` dict describing how a result was obtained
+///
+/// `etag` is that of the first response, which is the primary resource for
+/// every function in the crate; `cache_hit` is True only when every request
+/// was served from the response cache.
+pub(crate) fn meta_to_py<'py>(py: Python<'py>, records: &[RequestRecord]) -> PyResult<&'py PyDict> {
+    let mut endpoints: Vec<&str> = Vec::new();
+    for record in records {
+        if !endpoints.contains(&record.endpoint.as_str()) {
+            endpoints.push(&record.endpoint);
+        }
+    }
+    let meta = PyDict::new(py);
+    meta.set_item("etag", records.first().and_then(|record| record.etag.as_deref()))?;
+    meta.set_item("fetched_at", Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true))?;
+    meta.set_item("endpoints", endpoints)?;
+    meta.set_item("request_count", records.len())?;
+    meta.set_item("quota_cost", records.iter().map(|record| record.quota_cost).sum::<u32>())?;
+    meta.set_item("cache_hit", !records.is_empty() && records.iter().all(|record| record.cache_hit))?;
+    Ok(meta)
+}
+
+/// Run a result-producing function and attach `_meta` when it returns a dict
+pub(crate) fn with_meta(f: impl FnOnce() -> PyResult<PyObject>) -> PyResult<PyObject> {
+    let (result, records) = track_requests(f);
+    let result = result?;
+    Python::with_gil(|py| {
+        if let Ok(dict) = result.downcast::<PyDict>(py) {
+            dict.set_item("_meta", meta_to_py(py, &records)?)?;
+        }
+        Ok(result)
+    })
+}
+
+// Base delay before the first retry; doubled on each further attempt
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+
+// Successful responses keyed by request URL (without credentials)
+static RESPONSE_CACHE: OnceLock<Mutex<HashMap<String, (Instant, ApiResponse)>>> = OnceLock::new();
+
+fn response_cache() -> &'static Mutex<HashMap<String, (Instant, ApiResponse)>> {
+    RESPONSE_CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Status and body of a completed API request
+#[derive(Clone)]
+pub(crate) struct ApiResponse {
+    pub status: StatusCode,
+    pub body: String,
+}
+
+impl ApiResponse {
+    pub fn is_success(&self) -> bool {
+        self.status.is_success()
+    }
+
+    pub fn json<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(&self.body)
+    }
+
+    /// Parsed Google error payload, if the body contains one
+    pub fn error(&self) -> Option<YouTubeError> {
+        serde_json::from_str::<YouTubeErrorResponse>(&self.body)
+            .ok()
+            .map(|response| response.error)
+    }
+
+    pub(crate) fn has_error_reason(&self, reasons: &[&str]) -> bool {
+        self.error()
+            .map(|error| error.errors.iter().any(|detail| reasons.contains(&detail.reason.as_str())))
+            .unwrap_or(false)
+    }
+
+    /// True when the Cloud project has not enabled (or has disabled) the Data API
+    pub fn is_api_not_enabled(&self) -> bool {
+        self.status == StatusCode::FORBIDDEN
+            && (self.has_error_reason(&["accessNotConfigured", "SERVICE_DISABLED"])
+                || self.body.contains("SERVICE_DISABLED")
+                || self.body.contains("has not been used in project"))
+    }
+
+    /// Console URL for enabling the API, from the error details or message
+    pub fn enable_url(&self) -> Option<String> {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(&self.body) {
+            if let Some(url) = find_string_field(&value, "activationUrl") {
+                return Some(url);
+            }
+        }
+        self.body
+            .split(|c: char| c.is_whitespace() || c == '"')
+            .find(|token| token.starts_with("https://console.developers.google.com/") || token.starts_with("https://console.cloud.google.com/"))
+            .map(|url| url.trim_end_matches(['.', ',', ')', '\\']).to_string())
+    }
+
+    /// True when a stored `pageToken` was rejected, e.g. because it expired
+    pub fn is_invalid_page_token(&self) -> bool {
+        self.status == StatusCode::BAD_REQUEST && self.has_error_reason(&["invalidPageToken"])
+    }
+
+    /// True when the key used for this request ran out of quota
+    pub fn is_quota_exceeded(&self) -> bool {
+        self.status == StatusCode::FORBIDDEN
+            && self.has_error_reason(&["quotaExceeded", "dailyLimitExceeded"])
+    }
+}
+
+fn find_string_field(value: &serde_json::Value, field: &str) -> Option<String> {
+    match value {
+        serde_json::Value::Object(map) => map.get(field)
+            .and_then(|v| v.as_str().map(str::to_string))
+            .or_else(|| map.values().find_map(|v| find_string_field(v, field))),
+        serde_json::Value::Array(items) => items.iter().find_map(|v| find_string_field(v, field)),
+        _ => None,
+    }
+}
+
+fn execute(request: RequestBuilder) -> PyResult<ApiResponse> {
+    let resp = request
+        .header("Accept", "application/json")
+        .send()
+        .map_err(|e| PyValueError::new_err(redact(&format!("Request failed: {}", e))))?;
+    let status = resp.status();
+    let body = resp.text()
+        .map_err(|e| PyValueError::new_err(redact(&format!("Failed to read response text: {}", e))))?;
+    let response = ApiResponse { status, body };
+
+    if response.is_api_not_enabled() {
+        let message = response.error()
+            .map(|error| error.message)
+            .unwrap_or_else(|| response.status.to_string());
+        return Err(error::api_not_enabled(&message, response.enable_url()));
+    }
+    Ok(response)
+}
+
+/// Execute a request, retrying transport errors, 429 and 5xx with backoff
+fn execute_with_retries(build: impl Fn() -> RequestBuilder, retries: u32) -> PyResult<ApiResponse> {
+    let mut attempt = 0;
+    loop {
+        let result = execute(build());
+        let retryable = match &result {
+            Ok(response) => response.status == StatusCode::TOO_MANY_REQUESTS || response.status.is_server_error(),
+            Err(e) => Python::with_gil(|py| !e.is_instance_of::<error::ApiNotEnabledError>(py)),
+        };
+        if !retryable || attempt >= retries {
+            return result;
+        }
+        thread::sleep(RETRY_BACKOFF * 2u32.pow(attempt.min(6)));
+        attempt += 1;
+    }
+}
+
+fn send_with_pool(client: &Client, pool: &KeyPool, url: &str, retries: u32) -> PyResult<ApiResponse> {
+    loop {
+        let (index, key) = pool.next_key().ok_or_else(|| {
+            PyValueError::new_err("All API keys in the pool have exceeded their quota")
+        })?;
+        let response = execute_with_retries(|| client.get(url).query(&[("key", &key)]), retries)?;
+        if response.is_quota_exceeded() {
+            pool.record_quota_exceeded(index);
+            continue;
+        }
+        pool.record_request(index);
+        return Ok(response);
+    }
+}
+
+/// Settings from the request's AuthConfig, else from `configure()`
+pub(crate) fn effective_config(credentials: &Credentials) -> Option<AuthConfig> {
+    credentials.config().cloned().or_else(config::default_config)
+}
+
+/// HTTP client for the given credentials, honoring AuthConfig proxy/timeout
+pub(crate) fn build_client(credentials: &Credentials) -> PyResult<Client> {
+    match effective_config(credentials) {
+        Some(config) => config.build_client(),
+        None => Ok(Client::new()),
+    }
+}
+
+/// HTTP client for public files that need no credentials, honoring `configure()`
+pub(crate) fn build_public_client() -> PyResult<Client> {
+    match config::default_config() {
+        Some(config) => config.build_client(),
+        None => Ok(Client::new()),
+    }
+}
+
+/// Client for youtube.com pages; redirects are returned instead of followed
+pub(crate) fn build_web_client(credentials: &Credentials) -> PyResult<Client> {
+    let builder = match effective_config(credentials) {
+        Some(config) => config.client_builder()?,
+        None => Client::builder(),
+    };
+    builder.redirect(reqwest::redirect::Policy::none()).build()
+        .map_err(|e| PyValueError::new_err(format!("Failed to build HTTP client: {}", e)))
+}
+
+/// Status of an unauthenticated HEAD request to a youtube.com page; None on transport errors
+pub(crate) fn head_status(client: &Client, url: &str) -> Option<StatusCode> {
+    client.head(apply_base_url_override(url)).send().ok().map(|response| response.status())
+}
+
+/// Status and body of an unauthenticated GET of a youtube.com page; None on transport errors
+pub(crate) fn get_page(client: &Client, url: &str) -> Option<(StatusCode, String)> {
+    let response = client.get(apply_base_url_override(url)).header("Accept-Language", "en").send().ok()?;
+    let status = response.status();
+    Some((status, response.text().ok()?))
+}
+
+/// Content type and bytes of a public file such as a channel image, following redirects
+pub(crate) fn download(client: &Client, url: &str) -> PyResult<(String, Vec<u8>)> {
+    download_if_exists(client, url)?
+        .ok_or_else(|| PyValueError::new_err(format!("Failed to download {}: {}", url, StatusCode::NOT_FOUND)))
+}
+
+/// Like `download`, but None when the file does not exist (404)
+pub(crate) fn download_if_exists(client: &Client, url: &str) -> PyResult<Option<(String, Vec<u8>)>> {
+    let response = client.get(apply_base_url_override(url)).send()
+        .map_err(|e| PyValueError::new_err(format!("Failed to download {}: {}", url, e)))?;
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(PyValueError::new_err(format!("Failed to download {}: {}", url, response.status())));
+    }
+    let content_type = response.headers().get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.split(';').next().unwrap_or_default().trim().to_ascii_lowercase())
+        .unwrap_or_default();
+    let bytes = response.bytes()
+        .map_err(|e| PyValueError::new_err(format!("Failed to download {}: {}", url, e)))?;
+    Ok(Some((content_type, bytes.to_vec())))
+}
+
+/// Perform an authorized GET request against the Data API
+///
+/// Pooled keys are rotated per request, and a key that hits `quotaExceeded`
+/// is marked exhausted and the request is retried with the next key.
+/// With `retries` configured, transient failures are retried with backoff;
+/// with `cache_ttl` configured, successful API-key requests are cached.
+pub(crate) fn send_get(client: &Client, credentials: &Credentials, url: &str) -> PyResult<ApiResponse> {
+    send(client, credentials, url, true)
+}
+
+/// `send_get` that never answers from or fills the response cache
+///
+/// For probes such as key validation and `self_check`, whose result depends on
+/// the credentials rather than only on the URL.
+pub(crate) fn send_get_uncached(client: &Client, credentials: &Credentials, url: &str) -> PyResult<ApiResponse> {
+    send(client, credentials, url, false)
+}
+
+fn send(client: &Client, credentials: &Credentials, url: &str, use_cache: bool) -> PyResult<ApiResponse> {
+    let url = &apply_base_url_override(url);
+    let config = effective_config(credentials);
+    let retries = config.as_ref().map_or(0, AuthConfig::max_retries);
+    let cache_ttl = config.as_ref().and_then(AuthConfig::response_cache_ttl);
+
+    // Only public, key-authorized data is shared through the cache
+    let cacheable = use_cache && cache_ttl.is_some() && is_key_based(credentials);
+    if let (true, Some(ttl)) = (cacheable, cache_ttl) {
+        let cache = response_cache().lock().unwrap();
+        if let Some((stored_at, response)) = cache.get(url) {
+            if stored_at.elapsed() < ttl {
+                record_response(url, response);
+                log_request(url, response, true);
+                return Ok(response.clone());
+            }
+        }
+    }
+
+    let response = send_uncached(client, credentials, url, retries)?;
+    record_response(url, &response);
+    log_request(url, &response, false);
+    if cacheable && response.is_success() {
+        response_cache().lock().unwrap().insert(url.to_string(), (Instant::now(), response.clone()));
+    }
+    Ok(response)
+}
+
+fn is_key_based(credentials: &Credentials) -> bool {
+    match credentials {
+        Credentials::ApiKey(_) | Credentials::Pool(_) => true,
+        Credentials::Config(config) => match config.credentials() {
+            Some(inner) => is_key_based(inner),
+            None => true,
+        },
+        _ => false,
+    }
+}
+
+fn send_uncached(client: &Client, credentials: &Credentials, url: &str, retries: u32) -> PyResult<ApiResponse> {
+    match credentials {
+        Credentials::ApiKey(key) => execute_with_retries(|| client.get(url).query(&[("key", key)]), retries),
+        Credentials::Pool(pool) => send_with_pool(client, pool, url, retries),
+        Credentials::ServiceAccount(account) => {
+            let token = account.access_token(client)?;
+            execute_with_retries(|| client.get(url).bearer_auth(&token), retries)
+        }
+        Credentials::OAuth(oauth) => {
+            let token = oauth.access_token(client)?;
+            execute_with_retries(|| client.get(url).bearer_auth(&token), retries)
+        }
+        Credentials::Config(config) => match config.credentials() {
+            Some(inner) => send_uncached(client, inner, url, retries),
+            None => send_uncached(client, &Credentials::ApiKey(get_youtube_api_key()?), url, retries),
+        },
+    }
+}
+
+/// Confirm the response cache accepts writes; used by `self_check`
+pub(crate) fn check_cache() -> Result<String, String> {
+    let mut cache = response_cache().lock()
+        .map_err(|_| "response cache lock is poisoned; restart the process".to_string())?;
+    let probe = "youtube_stats://self_check".to_string();
+    let response = ApiResponse { status: StatusCode::OK, body: String::new() };
+    cache.insert(probe.clone(), (Instant::now(), response));
+    cache.remove(&probe);
+    Ok(format!("writable ({} cached responses)", cache.len()))
+}
+
+/// Drop every cached API response
+#[pyfunction]
+pub fn clear_cache() {
+    response_cache().lock().unwrap().clear();
+}
+
+/// Convert a JSON value into the equivalent Python object
+pub(crate) fn json_to_py(py: Python, value: &serde_json::Value) -> PyResult<PyObject> {
+    Ok(match value {
+        serde_json::Value::Null => py.None(),
+        serde_json::Value::Bool(b) => b.to_object(py),
+        serde_json::Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => i.into_py(py),
+            (None, Some(u)) => u.into_py(py),
+            _ => n.as_f64().unwrap_or(f64::NAN).into_py(py),
+        },
+        serde_json::Value::String(s) => s.to_object(py),
+        serde_json::Value::Array(items) => {
+            let list = PyList::empty(py);
+            for item in items {
+                list.append(json_to_py(py, item)?)?;
+            }
+            list.into()
+        }
+        serde_json::Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                dict.set_item(key, json_to_py(py, item)?)?;
+            }
+            dict.into()
+        }
+    })
+}
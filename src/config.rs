@@ -0,0 +1,353 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::exceptions::PyValueError;
+use reqwest::blocking::{Client, ClientBuilder};
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+use crate::auth::{self, Credentials, KeyPool, OAuthCredentials};
+use crate::secrets;
+
+// Module-level default used when `api_key` is omitted
+static DEFAULT_CONFIG: OnceLock<RwLock<Option<AuthConfig>>> = OnceLock::new();
+
+fn default_config_lock() -> &'static RwLock<Option<AuthConfig>> {
+    DEFAULT_CONFIG.get_or_init(|| RwLock::new(None))
+}
+
+pub(crate) fn default_config() -> Option<AuthConfig> {
+    default_config_lock().read().unwrap().clone()
+}
+
+// Raw configuration values as found in a dict, file or the environment
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+struct ConfigFields {
+    api_key: Option<String>,
+    api_keys: Vec<String>,
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    service_account_file: Option<String>,
+    scopes: Option<Vec<String>>,
+    proxy: Option<String>,
+    timeout: Option<f64>,
+    retries: Option<u32>,
+    cache_ttl: Option<f64>,
+    region: Option<String>,
+    language: Option<String>,
+}
+
+fn env_var(name: &str) -> Option<String> {
+    env::var(name).ok().filter(|value| !value.trim().is_empty())
+}
+
+/// Credentials and client settings shared by every API call
+///
+/// Build one with `AuthConfig(...)`, `AuthConfig.from_env()`,
+/// `AuthConfig.from_dict(d)` or `AuthConfig.from_file(path)` and pass it as
+/// `api_key`, or install it with `auth.set_default_config(config)`.
+#[pyclass]
+#[derive(Clone)]
+pub struct AuthConfig {
+    fields: Arc<ConfigFields>,
+    credentials: Option<Credentials>,
+}
+
+#[pymethods]
+impl AuthConfig {
+    #[new]
+    #[pyo3(signature = (
+        api_key=None,
+        api_keys=None,
+        access_token=None,
+        refresh_token=None,
+        client_id=None,
+        client_secret=None,
+        service_account_file=None,
+        scopes=None,
+        proxy=None,
+        timeout=None,
+        retries=None,
+        cache_ttl=None,
+        region=None,
+        language=None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn py_new(
+        api_key: Option<String>,
+        api_keys: Option<Vec<String>>,
+        access_token: Option<String>,
+        refresh_token: Option<String>,
+        client_id: Option<String>,
+        client_secret: Option<String>,
+        service_account_file: Option<String>,
+        scopes: Option<Vec<String>>,
+        proxy: Option<String>,
+        timeout: Option<f64>,
+        retries: Option<u32>,
+        cache_ttl: Option<f64>,
+        region: Option<String>,
+        language: Option<String>,
+    ) -> PyResult<Self> {
+        AuthConfig::from_fields(ConfigFields {
+            api_key,
+            api_keys: api_keys.unwrap_or_default(),
+            access_token,
+            refresh_token,
+            client_id,
+            client_secret,
+            service_account_file,
+            scopes,
+            proxy,
+            timeout,
+            retries,
+            cache_ttl,
+            region,
+            language,
+        })
+    }
+
+    /// Load the configuration from environment variables (and `.env`)
+    #[staticmethod]
+    fn from_env() -> PyResult<Self> {
+        auth::load_default_env();
+        AuthConfig::from_fields(ConfigFields {
+            api_key: env_var("YOUTUBE_API_KEY"),
+            api_keys: auth::get_youtube_api_keys().unwrap_or_default(),
+            access_token: env_var("YOUTUBE_ACCESS_TOKEN"),
+            refresh_token: env_var("YOUTUBE_REFRESH_TOKEN")
+                .or_else(|| secrets::get_secret(&secrets::refresh_token_entry("default"))),
+            client_id: env_var("YOUTUBE_CLIENT_ID"),
+            client_secret: env_var("YOUTUBE_CLIENT_SECRET"),
+            service_account_file: env_var("GOOGLE_APPLICATION_CREDENTIALS"),
+            scopes: env_var("YOUTUBE_STATS_SCOPES")
+                .map(|scopes| scopes.split_whitespace().map(str::to_string).collect()),
+            proxy: env_var("YOUTUBE_STATS_PROXY"),
+            timeout: env_var("YOUTUBE_STATS_TIMEOUT").and_then(|t| t.parse().ok()),
+            retries: env_var("YOUTUBE_STATS_RETRIES").and_then(|r| r.parse().ok()),
+            cache_ttl: env_var("YOUTUBE_STATS_CACHE_TTL").and_then(|t| t.parse().ok()),
+            region: env_var("YOUTUBE_STATS_REGION"),
+            language: env_var("YOUTUBE_STATS_LANGUAGE"),
+        })
+    }
+
+    /// Build the configuration from a dictionary using the constructor's keyword names
+    #[staticmethod]
+    fn from_dict(py: Python, values: &PyDict) -> PyResult<Self> {
+        let json: String = py.import("json")?.call_method1("dumps", (values,))?.extract()?;
+        AuthConfig::from_json(&json)
+    }
+
+    /// Load the configuration from a JSON file using the constructor's keyword names
+    #[staticmethod]
+    fn from_file(path: String) -> PyResult<Self> {
+        let contents = fs::read_to_string(&path)
+            .map_err(|e| PyValueError::new_err(format!("Failed to read config file {}: {}", path, e)))?;
+        AuthConfig::from_json(&contents)
+    }
+
+    #[getter]
+    fn has_credentials(&self) -> bool {
+        self.credentials.is_some()
+    }
+
+    #[getter]
+    fn proxy(&self) -> Option<String> {
+        self.fields.proxy.clone()
+    }
+
+    #[getter]
+    fn timeout(&self) -> Option<f64> {
+        self.fields.timeout
+    }
+
+    #[getter]
+    fn retries(&self) -> u32 {
+        self.max_retries()
+    }
+
+    #[getter]
+    fn cache_ttl(&self) -> Option<f64> {
+        self.fields.cache_ttl
+    }
+
+    #[getter]
+    fn region(&self) -> Option<String> {
+        self.fields.region.clone()
+    }
+
+    #[getter]
+    fn language(&self) -> Option<String> {
+        self.fields.language.clone()
+    }
+}
+
+impl AuthConfig {
+    fn from_json(json: &str) -> PyResult<Self> {
+        let fields: ConfigFields = serde_json::from_str(json)
+            .map_err(|e| PyValueError::new_err(format!("Invalid AuthConfig: {}", e)))?;
+        AuthConfig::from_fields(fields)
+    }
+
+    fn from_fields(fields: ConfigFields) -> PyResult<Self> {
+        // Checked here so client construction never panics on a bad duration
+        if let Some(timeout) = fields.timeout {
+            if !(timeout > 0.0 && Duration::try_from_secs_f64(timeout).is_ok()) {
+                return Err(PyValueError::new_err(format!("timeout must be a positive, finite number of seconds, got {}", timeout)));
+            }
+        }
+        if let Some(cache_ttl) = fields.cache_ttl {
+            if Duration::try_from_secs_f64(cache_ttl).is_err() {
+                return Err(PyValueError::new_err(format!("cache_ttl must be a finite, non-negative number of seconds, got {}", cache_ttl)));
+            }
+        }
+        let credentials = if let Some(path) = &fields.service_account_file {
+            Some(Credentials::ServiceAccount(auth::from_service_account(path.clone(), fields.scopes.clone())?))
+        } else if fields.access_token.is_some() || fields.refresh_token.is_some() {
+            Some(Credentials::OAuth(OAuthCredentials::new(
+                fields.access_token.clone(),
+                fields.refresh_token.clone(),
+                fields.client_id.clone(),
+                fields.client_secret.clone(),
+                None,
+            )?))
+        } else {
+            let mut keys = fields.api_keys.clone();
+            if let Some(key) = &fields.api_key {
+                if !keys.contains(key) {
+                    keys.insert(0, key.clone());
+                }
+            }
+            match keys.len() {
+                0 => None,
+                1 => Some(Credentials::ApiKey(keys.remove(0))),
+                _ => Some(Credentials::Pool(KeyPool::new(keys)?)),
+            }
+        };
+
+        Ok(AuthConfig {
+            fields: Arc::new(fields),
+            credentials,
+        })
+    }
+
+    pub(crate) fn credentials(&self) -> Option<&Credentials> {
+        self.credentials.as_ref()
+    }
+
+    /// Extra attempts for transport errors, 429 and 5xx responses
+    pub(crate) fn max_retries(&self) -> u32 {
+        self.fields.retries.unwrap_or(0)
+    }
+
+    /// How long successful responses are served from the in-memory cache
+    pub(crate) fn response_cache_ttl(&self) -> Option<Duration> {
+        self.fields.cache_ttl
+            .filter(|ttl| *ttl > 0.0)
+            .map(Duration::from_secs_f64)
+    }
+
+    pub(crate) fn default_region(&self) -> Option<&str> {
+        self.fields.region.as_deref()
+    }
+
+    pub(crate) fn default_language(&self) -> Option<&str> {
+        self.fields.language.as_deref()
+    }
+
+    /// HTTP client honoring the configured proxy and timeout
+    pub(crate) fn build_client(&self) -> PyResult<Client> {
+        self.client_builder()?.build()
+            .map_err(|e| PyValueError::new_err(format!("Failed to build HTTP client: {}", e)))
+    }
+
+    /// Client builder with the proxy and timeout applied
+    pub(crate) fn client_builder(&self) -> PyResult<ClientBuilder> {
+        let mut builder = Client::builder();
+        if let Some(proxy) = &self.fields.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| PyValueError::new_err(format!("Invalid proxy URL: {}", e)))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(timeout) = self.fields.timeout {
+            builder = builder.timeout(Duration::from_secs_f64(timeout));
+        }
+        Ok(builder)
+    }
+}
+
+/// Install (or clear, with None) the configuration used when `api_key` is omitted
+#[pyfunction]
+pub fn set_default_config(config: Option<AuthConfig>) {
+    *default_config_lock().write().unwrap() = config;
+}
+
+/// Get the configuration used when `api_key` is omitted
+#[pyfunction]
+pub fn get_default_config() -> Option<AuthConfig> {
+    default_config()
+}
+
+/// Set process-wide defaults used by every call that omits `api_key`
+///
+/// Arguments that are not given keep their current value, so `configure()`
+/// can be called repeatedly to adjust individual settings.
+///
+/// # Arguments
+/// * `api_key` - YouTube Data API v3 key
+/// * `api_keys` - Several keys, rotated when one runs out of quota
+/// * `timeout` - Request timeout in seconds
+/// * `retries` - Extra attempts for transport errors, 429 and 5xx responses
+/// * `cache_ttl` - Seconds to serve repeated requests from an in-memory cache
+/// * `proxy` - HTTP(S) proxy URL
+/// * `region` - Default `regionCode` for searches
+/// * `language` - Default `relevanceLanguage` for searches
+///
+/// # Returns
+/// * PyResult<AuthConfig> - The configuration now in effect
+#[pyfunction]
+#[pyo3(signature = (
+    api_key=None,
+    api_keys=None,
+    timeout=None,
+    retries=None,
+    cache_ttl=None,
+    proxy=None,
+    region=None,
+    language=None,
+))]
+#[allow(clippy::too_many_arguments)]
+pub fn configure(
+    api_key: Option<String>,
+    api_keys: Option<Vec<String>>,
+    timeout: Option<f64>,
+    retries: Option<u32>,
+    cache_ttl: Option<f64>,
+    proxy: Option<String>,
+    region: Option<String>,
+    language: Option<String>,
+) -> PyResult<AuthConfig> {
+    let mut lock = default_config_lock().write().unwrap();
+    let mut fields = lock.as_ref()
+        .map(|config| (*config.fields).clone())
+        .unwrap_or_default();
+
+    if api_key.is_some() || api_keys.is_some() {
+        fields.api_key = api_key;
+        fields.api_keys = api_keys.unwrap_or_default();
+    }
+    fields.timeout = timeout.or(fields.timeout);
+    fields.retries = retries.or(fields.retries);
+    fields.cache_ttl = cache_ttl.or(fields.cache_ttl);
+    fields.proxy = proxy.or(fields.proxy);
+    fields.region = region.or(fields.region);
+    fields.language = language.or(fields.language);
+
+    let config = AuthConfig::from_fields(fields)?;
+    *lock = Some(config.clone());
+    Ok(config)
+}
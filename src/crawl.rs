@@ -0,0 +1,105 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::exceptions::PyValueError;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+// Numeric channel statistics compared between runs
+const DELTA_METRICS: [&str; 3] = ["subscriber_count", "total_view_count", "video_count"];
+
+/// Load an NDJSON crawl output (one channel stats dict per line) keyed by channel ID
+fn load_crawl(path: &str) -> PyResult<BTreeMap<String, Value>> {
+    if path.ends_with(".parquet") {
+        return Err(PyValueError::new_err(format!(
+            "Parquet crawl outputs are not supported, export the run as NDJSON: {}", path
+        )));
+    }
+
+    let file = File::open(path)
+        .map_err(|e| PyValueError::new_err(format!("Failed to open crawl output {}: {}", path, e)))?;
+
+    let mut channels = BTreeMap::new();
+    for (line_number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line
+            .map_err(|e| PyValueError::new_err(format!("Failed to read {}: {}", path, e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: Value = serde_json::from_str(&line).map_err(|e| {
+            PyValueError::new_err(format!("Invalid JSON on line {} of {}: {}", line_number + 1, path, e))
+        })?;
+        let channel_id = record.get("channel_id").and_then(Value::as_str).ok_or_else(|| {
+            PyValueError::new_err(format!("Missing channel_id on line {} of {}", line_number + 1, path))
+        })?;
+        channels.insert(channel_id.to_string(), record);
+    }
+    Ok(channels)
+}
+
+/// Compare two crawl runs and report per-channel deltas
+///
+/// # Arguments
+/// * `run_a_path` - NDJSON output of the earlier run
+/// * `run_b_path` - NDJSON output of the later run
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `changed` (per-channel before/after/delta
+///   for subscribers, views and videos), `new_channels`, `disappeared_channels`
+///   and `unchanged_count`
+#[pyfunction]
+pub fn diff_crawls(run_a_path: String, run_b_path: String) -> PyResult<PyObject> {
+    let run_a = load_crawl(&run_a_path)?;
+    let run_b = load_crawl(&run_b_path)?;
+
+    let ids_a: BTreeSet<&String> = run_a.keys().collect();
+    let ids_b: BTreeSet<&String> = run_b.keys().collect();
+
+    Python::with_gil(|py| {
+        let changed = PyList::empty(py);
+        let mut unchanged_count = 0;
+
+        for channel_id in ids_a.intersection(&ids_b) {
+            let before = &run_a[*channel_id];
+            let after = &run_b[*channel_id];
+
+            let deltas = PyDict::new(py);
+            let mut has_change = false;
+            for metric in DELTA_METRICS {
+                let old = before.get(metric).and_then(Value::as_i64);
+                let new = after.get(metric).and_then(Value::as_i64);
+                let delta = match (old, new) {
+                    (Some(old), Some(new)) => Some(new - old),
+                    _ => None,
+                };
+                if delta.unwrap_or(0) != 0 || old.is_some() != new.is_some() {
+                    has_change = true;
+                }
+                let entry = PyDict::new(py);
+                entry.set_item("before", old)?;
+                entry.set_item("after", new)?;
+                entry.set_item("delta", delta)?;
+                deltas.set_item(metric, entry)?;
+            }
+
+            if has_change {
+                deltas.set_item("channel_id", *channel_id)?;
+                deltas.set_item("channel_title", after.get("channel_title").and_then(Value::as_str))?;
+                changed.append(deltas)?;
+            } else {
+                unchanged_count += 1;
+            }
+        }
+
+        let new_channels: Vec<&String> = ids_b.difference(&ids_a).copied().collect();
+        let disappeared_channels: Vec<&String> = ids_a.difference(&ids_b).copied().collect();
+
+        let py_dict = PyDict::new(py);
+        py_dict.set_item("changed", changed)?;
+        py_dict.set_item("new_channels", new_channels)?;
+        py_dict.set_item("disappeared_channels", disappeared_channels)?;
+        py_dict.set_item("unchanged_count", unchanged_count)?;
+        Ok(py_dict.into())
+    })
+}
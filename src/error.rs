@@ -0,0 +1,71 @@
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use crate::utils::redact;
+
+// Subclasses of ValueError so existing `except ValueError` handlers keep working
+create_exception!(youtube_stats, ApiNotEnabledError, PyValueError, "The YouTube Data API is not enabled for the Google Cloud project behind the credentials.");
+create_exception!(youtube_stats, ChannelNotFoundError, PyValueError, "No YouTube channel matches the given identifier or URL.");
+// A ChannelNotFoundError, since the API cannot return the channel either
+create_exception!(youtube_stats, ChannelUnavailableError, ChannelNotFoundError, "The YouTube channel exists but was terminated, suspended or otherwise taken down.");
+create_exception!(youtube_stats, AmbiguousChannelError, PyValueError, "Several YouTube channels match the given identifier.");
+create_exception!(youtube_stats, InvalidVideoReferenceError, PyValueError, "The input is neither a YouTube video ID nor a recognized video URL.");
+
+/// Build an ApiNotEnabledError carrying the console URL that enables the API
+pub(crate) fn api_not_enabled(message: &str, enable_url: Option<String>) -> PyErr {
+    let text = match &enable_url {
+        Some(url) => format!(
+            "YouTube Data API is not enabled for this project. Enable it at {} and retry in a few minutes. ({})",
+            url, message
+        ),
+        None => format!(
+            "YouTube Data API is not enabled for this project. Enable 'YouTube Data API v3' in the Google Cloud console. ({})",
+            message
+        ),
+    };
+    let err = ApiNotEnabledError::new_err(redact(&text));
+    Python::with_gil(|py| {
+        let _ = err.value(py).setattr("enable_url", enable_url);
+    });
+    err
+}
+
+/// Build a ChannelNotFoundError carrying the identifier that failed to resolve
+pub(crate) fn channel_not_found(identifier: &str) -> PyErr {
+    let err = ChannelNotFoundError::new_err(format!("Channel not found: {}", identifier));
+    Python::with_gil(|py| {
+        let _ = err.value(py).setattr("identifier", identifier);
+    });
+    err
+}
+
+/// Build a ChannelUnavailableError carrying the identifier and the takedown reason
+pub(crate) fn channel_unavailable(identifier: &str, reason: &str) -> PyErr {
+    let err = ChannelUnavailableError::new_err(format!("Channel {} is unavailable ({})", identifier, reason));
+    Python::with_gil(|py| {
+        let value = err.value(py);
+        let _ = value.setattr("identifier", identifier);
+        let _ = value.setattr("reason", reason);
+    });
+    err
+}
+
+/// Build an InvalidVideoReferenceError carrying the rejected input
+pub(crate) fn invalid_video_reference(input: &str) -> PyErr {
+    let err = InvalidVideoReferenceError::new_err(format!("Unrecognized video ID or URL: {}", input));
+    Python::with_gil(|py| {
+        let _ = err.value(py).setattr("input", input);
+    });
+    err
+}
+
+/// Build an AmbiguousChannelError carrying the identifier and the ranked candidates
+pub(crate) fn ambiguous_channel(identifier: &str, candidates: PyObject) -> PyErr {
+    let err = AmbiguousChannelError::new_err(format!("Several channels match {}; pass a channel ID or choose from .candidates", identifier));
+    Python::with_gil(|py| {
+        let value = err.value(py);
+        let _ = value.setattr("identifier", identifier);
+        let _ = value.setattr("candidates", candidates);
+    });
+    err
+}
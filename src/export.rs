@@ -0,0 +1,231 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::exceptions::PyValueError;
+use chrono::{SecondsFormat, Utc};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use crate::account;
+use crate::auth::Credentials;
+use crate::client;
+
+const MANIFEST_FILE: &str = "manifest.json";
+
+/// Output formats accepted by `run_partitioned`, with their file extensions
+fn extension(format: &str) -> PyResult<&'static str> {
+    match format {
+        "ndjson" | "jsonl" => Ok("ndjson"),
+        "json" => Ok("json"),
+        "csv" => Ok("csv"),
+        _ => Err(PyValueError::new_err(format!(
+            "Unsupported export format '{}': expected ndjson, json or csv", format
+        ))),
+    }
+}
+
+/// Convert a Python result into JSON, stringifying values JSON cannot hold
+fn to_json(py: Python, value: &PyAny) -> PyResult<Value> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("default", py.eval("str", None, None)?)?;
+    let text: String = py.import("json")?.call_method("dumps", (value,), Some(kwargs))?.extract()?;
+    serde_json::from_str(&text).map_err(|e| PyValueError::new_err(format!("Failed to serialize result: {}", e)))
+}
+
+/// Which CSV fields are wrapped in quotes
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Quoting {
+    // Only fields containing the delimiter, quotes or line breaks
+    Minimal,
+    All,
+    // Every field except numbers and booleans
+    NonNumeric,
+}
+
+/// Layout of CSV output, for spreadsheets set to non-English locales
+struct CsvOptions {
+    delimiter: char,
+    bom: bool,
+    quoting: Quoting,
+    // Columns whose integers are written as Excel text so they keep every digit
+    text_columns: Vec<String>,
+}
+
+impl CsvOptions {
+    fn new(delimiter: &str, bom: bool, quoting: &str, text_columns: Vec<String>) -> PyResult<Self> {
+        let mut chars = delimiter.chars();
+        let delimiter = match (chars.next(), chars.next()) {
+            (Some(c), None) if !matches!(c, '"' | '\n' | '\r') => c,
+            _ => return Err(PyValueError::new_err(format!(
+                "Invalid CSV delimiter '{}': expected a single character other than a quote or line break", delimiter
+            ))),
+        };
+        let quoting = match quoting {
+            "minimal" => Quoting::Minimal,
+            "all" => Quoting::All,
+            "nonnumeric" => Quoting::NonNumeric,
+            _ => return Err(PyValueError::new_err(format!(
+                "Unsupported CSV quoting '{}': expected minimal, all or nonnumeric", quoting
+            ))),
+        };
+        Ok(CsvOptions { delimiter, bom, quoting, text_columns })
+    }
+
+    fn field(&self, column: &str, value: &Value) -> String {
+        let (text, numeric) = match value {
+            Value::Null => (String::new(), false),
+            Value::String(text) => (text.clone(), false),
+            // ="..." makes Excel keep e.g. 17-digit counts instead of rounding them
+            Value::Number(n) if self.text_columns.iter().any(|c| c == column) && (n.is_u64() || n.is_i64()) => {
+                (format!("=\"{}\"", n), false)
+            }
+            Value::Number(_) | Value::Bool(_) => (value.to_string(), true),
+            other => (other.to_string(), false),
+        };
+        let needs_quotes = match self.quoting {
+            Quoting::All => true,
+            Quoting::NonNumeric => !numeric,
+            Quoting::Minimal => text.contains([self.delimiter, '"', '\n', '\r']),
+        };
+        if needs_quotes {
+            format!("\"{}\"", text.replace('"', "\"\""))
+        } else {
+            text
+        }
+    }
+}
+
+/// Flat CSV of the scalar fields of each record, `channel_id` first
+fn to_csv(records: &[Value], options: &CsvOptions) -> String {
+    let scalar_columns: BTreeSet<&String> = records.iter()
+        .filter_map(Value::as_object)
+        .flat_map(|record| record.iter())
+        .filter(|(name, value)| !value.is_object() && !value.is_array() && name.as_str() != "channel_id")
+        .map(|(name, _)| name)
+        .collect();
+    let columns: Vec<&str> = std::iter::once("channel_id")
+        .chain(scalar_columns.into_iter().map(String::as_str))
+        .collect();
+
+    let delimiter = options.delimiter.to_string();
+    // Excel only detects UTF-8 when the file starts with a byte order mark
+    let mut csv = if options.bom { "\u{feff}".to_string() } else { String::new() };
+    let header: Vec<String> = columns.iter()
+        .map(|column| options.field(column, &Value::String(column.to_string())))
+        .collect();
+    csv.push_str(&header.join(&delimiter));
+    csv.push('\n');
+    for record in records {
+        let row: Vec<String> = columns.iter()
+            .map(|column| options.field(column, record.get(column).unwrap_or(&Value::Null)))
+            .collect();
+        csv.push_str(&row.join(&delimiter));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn write_file(path: &Path, contents: &str) -> PyResult<()> {
+    fs::write(path, contents)
+        .map_err(|e| PyValueError::new_err(format!("Failed to write {}: {}", path.display(), e)))
+}
+
+/// Fetch channel stats and write them into a dated partition with a manifest
+///
+/// Output goes to `out_dir/YYYY-MM-DD/channels.<ext>` (UTC date of the run)
+/// next to a `manifest.json` describing the file, so a daily crawl lands in
+/// its own directory ready for batch ingestion. Re-running on the same day
+/// replaces that day's partition. NDJSON output can be fed to
+/// `crawl.diff_crawls`. Costs 1 quota unit per 50 channels.
+///
+/// # Arguments
+/// * `channel_ids` - Channel IDs (`UC...`)
+/// * `out_dir` - Root directory of the partitions (created if missing)
+/// * `format` - `"ndjson"` (default), `"json"` or `"csv"` (scalar fields only)
+/// * `partition_by` - Partitioning scheme; only `"date"` is supported
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `csv_delimiter` - Field separator for CSV output; use `";"` for Excel in
+///   locales with a decimal comma (default: `","`)
+/// * `csv_bom` - Start CSV output with a UTF-8 byte order mark so Excel
+///   reads accents and emoji correctly (default: False)
+/// * `csv_quoting` - `"minimal"` (default), `"all"` or `"nonnumeric"`
+/// * `csv_text_columns` - Columns whose integers are written as `="123"` so
+///   Excel shows every digit instead of rounding or scientific notation
+///
+/// # Returns
+/// * PyResult<PyObject> - The manifest: `partition`, `path`, `format`,
+///   `record_count`, `missing_channel_ids`, `failed_channels` (ID to error)
+///   and `created_at`
+#[pyfunction]
+#[pyo3(signature = (channel_ids, out_dir, format="ndjson", partition_by="date", api_key=None, csv_delimiter=",", csv_bom=false, csv_quoting="minimal", csv_text_columns=None))]
+#[allow(clippy::too_many_arguments)]
+pub fn run_partitioned(
+    py: Python,
+    channel_ids: Vec<String>,
+    out_dir: String,
+    format: &str,
+    partition_by: &str,
+    api_key: Option<Credentials>,
+    csv_delimiter: &str,
+    csv_bom: bool,
+    csv_quoting: &str,
+    csv_text_columns: Option<Vec<String>>,
+) -> PyResult<PyObject> {
+    if partition_by != "date" {
+        return Err(PyValueError::new_err(format!(
+            "Unsupported partition_by '{}': only \"date\" is supported", partition_by
+        )));
+    }
+    let extension = extension(format)?;
+    let csv_options = CsvOptions::new(csv_delimiter, csv_bom, csv_quoting, csv_text_columns.unwrap_or_default())?;
+
+    // One failing channel should not sink the whole day's export
+    let report = account::get_youtube_channels_stats(channel_ids, api_key, false, false, true)?;
+    let report = to_json(py, report.as_ref(py))?;
+    let statuses = &report["status"];
+
+    let mut records = Vec::new();
+    let mut missing_channel_ids = Vec::new();
+    let mut failed_channels = serde_json::Map::new();
+    for (channel_id, stats) in report["results"].as_object().into_iter().flatten() {
+        let status = &statuses[channel_id.as_str()];
+        match status["status"].as_str() {
+            Some("ok") => records.push(stats.clone()),
+            Some("error") => {
+                failed_channels.insert(channel_id.clone(), status["error"].clone());
+            }
+            _ => missing_channel_ids.push(channel_id.clone()),
+        }
+    }
+
+    let created_at = Utc::now();
+    let partition = created_at.format("%Y-%m-%d").to_string();
+    let partition_dir = Path::new(&out_dir).join(&partition);
+    fs::create_dir_all(&partition_dir)
+        .map_err(|e| PyValueError::new_err(format!("Failed to create {}: {}", partition_dir.display(), e)))?;
+
+    let contents = match extension {
+        "ndjson" => records.iter().map(|record| format!("{}\n", record)).collect(),
+        "json" => serde_json::to_string_pretty(&records)
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize results: {}", e)))?,
+        _ => to_csv(&records, &csv_options),
+    };
+    let data_path = partition_dir.join(format!("channels.{}", extension));
+    write_file(&data_path, &contents)?;
+
+    let manifest = json!({
+        "partition": partition,
+        "path": data_path.display().to_string(),
+        "format": extension,
+        "record_count": records.len(),
+        "missing_channel_ids": missing_channel_ids,
+        "failed_channels": failed_channels,
+        "created_at": created_at.to_rfc3339_opts(SecondsFormat::Secs, true),
+    });
+    let manifest_text = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| PyValueError::new_err(format!("Failed to serialize manifest: {}", e)))?;
+    write_file(&partition_dir.join(MANIFEST_FILE), &manifest_text)?;
+
+    client::json_to_py(py, &manifest)
+}
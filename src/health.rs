@@ -0,0 +1,165 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use std::net::ToSocketAddrs;
+use std::time::Instant;
+use crate::auth::Credentials;
+use crate::client;
+
+const API_HOST: &str = "www.googleapis.com";
+
+// Optional subsystems and whether this build includes them. Keep in step with
+// Cargo features and the README when a subsystem is added or made optional.
+const CAPABILITIES: [(&str, bool); 8] = [
+    ("keyring", cfg!(feature = "keyring")),
+    ("oauth", true),
+    ("service_accounts", true),
+    ("mock_server", true),
+    // youtube.com pages and RSS feeds used where the Data API has gaps
+    ("scraping_fallbacks", true),
+    ("csv_export", true),
+    ("parquet", false),
+    ("kafka", false),
+];
+
+// Outcome of one self-check step
+struct Check {
+    name: &'static str,
+    ok: Option<bool>,
+    detail: String,
+    duration_ms: u128,
+}
+
+impl Check {
+    fn run(name: &'static str, step: impl FnOnce() -> Result<String, String>) -> Check {
+        let started = Instant::now();
+        let (ok, detail) = match step() {
+            Ok(detail) => (Some(true), detail),
+            Err(detail) => (Some(false), detail),
+        };
+        Check { name, ok, detail, duration_ms: started.elapsed().as_millis() }
+    }
+
+    fn skipped(name: &'static str, reason: &str) -> Check {
+        Check { name, ok: None, detail: format!("skipped: {}", reason), duration_ms: 0 }
+    }
+
+    fn passed(&self) -> bool {
+        self.ok == Some(true)
+    }
+}
+
+fn credentials_kind(credentials: &Credentials) -> &'static str {
+    match credentials {
+        Credentials::ApiKey(_) => "api_key",
+        Credentials::Pool(_) => "key_pool",
+        Credentials::ServiceAccount(_) => "service_account",
+        Credentials::OAuth(_) => "oauth",
+        Credentials::Config(_) => "auth_config",
+    }
+}
+
+/// Verify the crate end-to-end: configuration, DNS, TLS, one API call and the cache
+///
+/// Costs 1 quota unit. Steps after a failed prerequisite are reported as
+/// skipped instead of failing with a confusing secondary error.
+///
+/// # Arguments
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with overall `ok` and a `checks` list of
+///   `name`, `ok` (None when skipped), `detail` and `duration_ms`
+#[pyfunction]
+#[pyo3(signature = (api_key=None))]
+pub fn self_check(py: Python, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    let mut checks = Vec::new();
+
+    let mut credentials = None;
+    checks.push(Check::run("config", || match Credentials::resolve(api_key) {
+        Ok(resolved) => {
+            let detail = format!("using {}", credentials_kind(&resolved));
+            credentials = Some(resolved);
+            Ok(detail)
+        }
+        Err(e) => Err(e.to_string()),
+    }));
+
+    let dns = Check::run("dns", || {
+        let addresses: Vec<_> = (API_HOST, 443).to_socket_addrs()
+            .map_err(|e| format!("cannot resolve {}: {}", API_HOST, e))?
+            .collect();
+        Ok(format!("{} resolved to {} address(es)", API_HOST, addresses.len()))
+    });
+    let dns_ok = dns.passed();
+    checks.push(dns);
+
+    let client = match &credentials {
+        Some(credentials) => client::build_client(credentials).ok(),
+        None => None,
+    };
+
+    match (&client, dns_ok) {
+        (Some(http), true) => checks.push(Check::run("tls", || {
+            http.head(format!("https://{}/", API_HOST))
+                .send()
+                .map(|response| format!("HTTPS handshake ok (status {})", response.status()))
+                .map_err(|e| format!("HTTPS request failed: {}", e))
+        })),
+        (None, _) => checks.push(Check::skipped("tls", "no usable configuration")),
+        (_, false) => checks.push(Check::skipped("tls", "DNS lookup failed")),
+    }
+    let tls_ok = checks.last().is_some_and(Check::passed);
+
+    match (&client, &credentials, tls_ok) {
+        (Some(http), Some(credentials), true) => checks.push(Check::run("api", || {
+            let url = format!("{}/i18nLanguages?part=snippet&hl=en", client::BASE_URL);
+            let response = client::send_get_uncached(http, credentials, &url).map_err(|e| e.to_string())?;
+            if response.is_success() {
+                Ok("i18nLanguages call succeeded (1 quota unit)".to_string())
+            } else {
+                let message = response.error()
+                    .map(|error| error.message)
+                    .unwrap_or_else(|| response.status.to_string());
+                Err(format!("{} - {}", response.status, message))
+            }
+        })),
+        _ => checks.push(Check::skipped("api", "TLS check did not pass")),
+    }
+
+    checks.push(Check::run("cache", client::check_cache));
+
+    let report = PyDict::new(py);
+    report.set_item("ok", checks.iter().all(Check::passed))?;
+    let py_checks = PyList::empty(py);
+    for check in &checks {
+        let entry = PyDict::new(py);
+        entry.set_item("name", check.name)?;
+        entry.set_item("ok", check.ok)?;
+        entry.set_item("detail", &check.detail)?;
+        entry.set_item("duration_ms", check.duration_ms)?;
+        py_checks.append(entry)?;
+    }
+    report.set_item("checks", py_checks)?;
+    Ok(report.into())
+}
+
+/// Report which optional subsystems were compiled into this build
+///
+/// Lets callers feature-detect at runtime instead of probing with
+/// try/except. Subsystems that do not exist yet are listed as False.
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with the package `version` and
+///   `features`, mapping each subsystem name to True or False
+#[pyfunction]
+pub fn capabilities(py: Python) -> PyResult<PyObject> {
+    let features = PyDict::new(py);
+    for (name, available) in CAPABILITIES {
+        features.set_item(name, available)?;
+    }
+    let result = PyDict::new(py);
+    result.set_item("version", env!("CARGO_PKG_VERSION"))?;
+    result.set_item("features", features)?;
+    Ok(result.into())
+}
@@ -2,23 +2,163 @@ use pyo3::prelude::*;
 
 pub mod auth;
 pub mod account;
+pub mod client;
+pub mod config;
+pub mod crawl;
+pub mod error;
+pub mod export;
+pub mod health;
+pub mod memberships;
+pub mod metadata;
+pub mod metrics;
+pub mod secrets;
+pub mod testing;
+pub mod text;
+pub mod playlist;
+pub mod trending;
+pub mod utils;
+pub mod video;
 
 #[pymodule]
 fn youtube_stats(py: Python, m: &PyModule) -> PyResult<()> {
     let auth_module = PyModule::new(py, "auth")?;
 
+    auth_module.add_function(wrap_pyfunction!(auth::load_env, auth_module)?)?;
     auth_module.add_function(wrap_pyfunction!(auth::get_youtube_api_key, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(auth::get_youtube_api_keys, auth_module)?)?;
     auth_module.add_function(wrap_pyfunction!(auth::call_youtube_client, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(auth::from_service_account, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(auth::validate_api_key, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(auth::device_login, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(auth::store_api_key, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(auth::delete_api_key, auth_module)?)?;
+    auth_module.add_class::<auth::ServiceAccountCredentials>()?;
+    auth_module.add_function(wrap_pyfunction!(config::set_default_config, auth_module)?)?;
+    auth_module.add_function(wrap_pyfunction!(config::get_default_config, auth_module)?)?;
+    auth_module.add_class::<auth::KeyPool>()?;
+    for (name, scope) in auth::SCOPES {
+        auth_module.add(name, scope)?;
+    }
+    auth_module.add_class::<auth::OAuthCredentials>()?;
+    auth_module.add_class::<config::AuthConfig>()?;
 
     let account_module = PyModule::new(py, "account")?;
     account_module.add_function(wrap_pyfunction!(account::get_youtube_channel_stats, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_youtube_channels_stats, account_module)?)?;
     account_module.add_function(wrap_pyfunction!(account::search_youtube_channels, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::validate_result, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::era_comparison, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_localizations, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::download_channel_images, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::resolution_report, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_by_custom_url, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_audit_details, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_subscriptions, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_activities, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_last_activity, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_live_streams, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_total_duration, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_upload_frequency, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_top_videos, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::compare_channels, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::resolve_channel_id, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::resolve_channel_candidates, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::channel_exists, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::get_channel_from_video, account_module)?)?;
+    account_module.add_function(wrap_pyfunction!(account::iter_channel_uploads, account_module)?)?;
+    let topics = pyo3::types::PyDict::new(py);
+    for (name, topic_id) in account::SEARCH_TOPICS {
+        topics.set_item(name, topic_id)?;
+    }
+    account_module.add("TOPICS", topics)?;
+
+    let crawl_module = PyModule::new(py, "crawl")?;
+    crawl_module.add_function(wrap_pyfunction!(crawl::diff_crawls, crawl_module)?)?;
+
+    let export_module = PyModule::new(py, "export")?;
+    export_module.add_function(wrap_pyfunction!(export::run_partitioned, export_module)?)?;
+
+    let memberships_module = PyModule::new(py, "memberships")?;
+    memberships_module.add_function(wrap_pyfunction!(memberships::get_membership_levels, memberships_module)?)?;
+    memberships_module.add_function(wrap_pyfunction!(memberships::iter_members, memberships_module)?)?;
+    memberships_module.add_class::<memberships::MemberStream>()?;
+
+    let metadata_module = PyModule::new(py, "metadata")?;
+    metadata_module.add_function(wrap_pyfunction!(metadata::set_channel_metadata, metadata_module)?)?;
+    metadata_module.add_function(wrap_pyfunction!(metadata::get_channel_metadata, metadata_module)?)?;
+    metadata_module.add_function(wrap_pyfunction!(metadata::clear_channel_metadata, metadata_module)?)?;
+
+    let metrics_module = PyModule::new(py, "metrics")?;
+    metrics_module.add_function(wrap_pyfunction!(metrics::engagement_score, metrics_module)?)?;
+    metrics_module.add_function(wrap_pyfunction!(metrics::channel_engagement_score, metrics_module)?)?;
+
+    let playlist_module = PyModule::new(py, "playlist")?;
+    playlist_module.add_function(wrap_pyfunction!(playlist::get_playlist_feed, playlist_module)?)?;
+    playlist_module.add_function(wrap_pyfunction!(playlist::playlist_channel_breakdown, playlist_module)?)?;
+    playlist_module.add_function(wrap_pyfunction!(playlist::stream_playlist_items, playlist_module)?)?;
+    playlist_module.add_function(wrap_pyfunction!(playlist::get_channel_playlists, playlist_module)?)?;
+    playlist_module.add_class::<playlist::PlaylistItemStream>()?;
+
+    let testing_module = PyModule::new(py, "testing")?;
+    testing_module.add_class::<testing::MockServer>()?;
+    testing_module.add_function(wrap_pyfunction!(testing::record_fixture, testing_module)?)?;
+
+    let trending_module = PyModule::new(py, "trending")?;
+    trending_module.add_function(wrap_pyfunction!(trending::compare_trending_regions, trending_module)?)?;
+
+    let utils_module = PyModule::new(py, "utils")?;
+    utils_module.add_function(wrap_pyfunction!(utils::build_share_url, utils_module)?)?;
+    utils_module.add_function(wrap_pyfunction!(utils::best_thumbnail_url, utils_module)?)?;
+    utils_module.add_function(wrap_pyfunction!(utils::redact_secrets, utils_module)?)?;
+    utils_module.add_function(wrap_pyfunction!(utils::clean_text, utils_module)?)?;
+    utils_module.add_function(wrap_pyfunction!(utils::extract_video_ids, utils_module)?)?;
+    utils_module.add_function(wrap_pyfunction!(utils::parse_iso_duration, utils_module)?)?;
+    utils_module.add_function(wrap_pyfunction!(utils::extract_chapters, utils_module)?)?;
+
+    let video_module = PyModule::new(py, "video")?;
+    video_module.add_function(wrap_pyfunction!(video::get_video_stats, video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(video::get_videos_stats, video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(video::download_video_thumbnail, video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(video::is_short, video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(video::get_video_captions, video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(video::get_video_transcript, video_module)?)?;
+    video_module.add_function(wrap_pyfunction!(video::get_video_oembed, video_module)?)?;
+
+    m.add("AmbiguousChannelError", py.get_type::<error::AmbiguousChannelError>())?;
+    m.add("ApiNotEnabledError", py.get_type::<error::ApiNotEnabledError>())?;
+    m.add("ChannelNotFoundError", py.get_type::<error::ChannelNotFoundError>())?;
+    m.add("ChannelUnavailableError", py.get_type::<error::ChannelUnavailableError>())?;
+    m.add("InvalidVideoReferenceError", py.get_type::<error::InvalidVideoReferenceError>())?;
+    m.add_function(wrap_pyfunction!(config::configure, m)?)?;
+    m.add_function(wrap_pyfunction!(client::clear_cache, m)?)?;
+    m.add_function(wrap_pyfunction!(health::self_check, m)?)?;
+    m.add_function(wrap_pyfunction!(health::capabilities, m)?)?;
 
     m.add_submodule(auth_module)?;
     m.add_submodule(account_module)?;
+    m.add_submodule(crawl_module)?;
+    m.add_submodule(export_module)?;
+    m.add_submodule(memberships_module)?;
+    m.add_submodule(metadata_module)?;
+    m.add_submodule(metrics_module)?;
+    m.add_submodule(playlist_module)?;
+    m.add_submodule(testing_module)?;
+    m.add_submodule(trending_module)?;
+    m.add_submodule(utils_module)?;
+    m.add_submodule(video_module)?;
 
     py.import("sys")?.getattr("modules")?.set_item("youtube_stats.auth", auth_module)?;
     py.import("sys")?.getattr("modules")?.set_item("youtube_stats.account", account_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.crawl", crawl_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.export", export_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.memberships", memberships_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.metadata", metadata_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.metrics", metrics_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.playlist", playlist_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.testing", testing_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.trending", trending_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.utils", utils_module)?;
+    py.import("sys")?.getattr("modules")?.set_item("youtube_stats.video", video_module)?;
     Ok(())
 
 }
@@ -0,0 +1,277 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::exceptions::PyValueError;
+use reqwest::blocking::Client;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::VecDeque;
+use crate::auth::Credentials;
+use crate::client;
+
+// Largest page the members endpoint accepts
+const MAX_MEMBERS_PER_PAGE: usize = 1000;
+
+// membershipsLevels response structures
+#[derive(Debug, Deserialize)]
+struct MembershipsLevelListResponse {
+    #[serde(default)]
+    items: Vec<MembershipsLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MembershipsLevel {
+    id: String,
+    snippet: MembershipsLevelSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+struct MembershipsLevelSnippet {
+    #[serde(rename = "creatorChannelId")]
+    creator_channel_id: Option<String>,
+    #[serde(rename = "levelDetails")]
+    level_details: Option<LevelDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LevelDetails {
+    #[serde(rename = "displayName")]
+    display_name: Option<String>,
+}
+
+// members response structures
+#[derive(Debug, Deserialize)]
+struct MemberListResponse {
+    #[serde(default)]
+    items: Vec<Member>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    snippet: MemberSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+struct MemberSnippet {
+    #[serde(rename = "memberDetails")]
+    member_details: Option<MemberDetails>,
+    #[serde(rename = "membershipsDetails")]
+    memberships_details: Option<MembershipsDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MemberDetails {
+    #[serde(rename = "channelId")]
+    channel_id: Option<String>,
+    #[serde(rename = "channelUrl")]
+    channel_url: Option<String>,
+    #[serde(rename = "displayName")]
+    display_name: Option<String>,
+    #[serde(rename = "profileImageUrl")]
+    profile_image_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MembershipsDetails {
+    #[serde(rename = "highestAccessibleLevel")]
+    highest_accessible_level: Option<String>,
+    #[serde(rename = "highestAccessibleLevelDisplayName")]
+    highest_accessible_level_display_name: Option<String>,
+    #[serde(rename = "accessibleLevels", default)]
+    accessible_levels: Vec<String>,
+    #[serde(rename = "membershipsDuration")]
+    memberships_duration: Option<MembershipsDuration>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MembershipsDuration {
+    #[serde(rename = "memberSince")]
+    member_since: Option<String>,
+    #[serde(rename = "memberTotalDurationMonths")]
+    member_total_duration_months: Option<u32>,
+}
+
+/// Only the channel owner can read membership data
+fn require_owner(credentials: &Credentials) -> PyResult<()> {
+    if credentials.is_user_oauth() {
+        return Ok(());
+    }
+    Err(PyValueError::new_err(
+        "Membership data is only available to the channel owner; pass OAuthCredentials with the youtube.channel-memberships.creator scope",
+    ))
+}
+
+/// GET a membership endpoint, turning 403s into a scope hint
+fn get_membership_page(client: &Client, credentials: &Credentials, url: &str, what: &str) -> PyResult<client::ApiResponse> {
+    let response = client::send_get(client, credentials, url)?;
+    if response.status.as_u16() == 403 {
+        let message = response.error().map(|error| error.message).unwrap_or_default();
+        return Err(PyValueError::new_err(format!(
+            "Access to {} denied; the token needs the youtube.channel-memberships.creator scope and the channel must have memberships enabled ({})",
+            what, message
+        )));
+    }
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Failed to fetch {}: {}", what, response.status)));
+    }
+    Ok(response)
+}
+
+/// List the membership levels (tiers) of the authorized user's channel
+///
+/// Requires OAuth credentials granted
+/// `auth.SCOPE_CHANNEL_MEMBERSHIPS_CREATOR`. Costs 1 quota unit.
+///
+/// # Arguments
+/// * `api_key` - OAuthCredentials or an AuthConfig holding an OAuth token
+///   (default: the configured default)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `level_id`, `display_name` and
+///   `creator_channel_id` dictionaries, lowest tier first as the API orders them
+#[pyfunction]
+#[pyo3(signature = (api_key=None))]
+pub fn get_membership_levels(api_key: Option<Credentials>) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    require_owner(&api_key)?;
+    let client = client::build_client(&api_key)?;
+
+    let url = format!("{}/membershipsLevels?part=id,snippet", client::BASE_URL);
+    let response = get_membership_page(&client, &api_key, &url, "membership levels")?;
+    let data: MembershipsLevelListResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse membership levels: {}", e)))?;
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for level in &data.items {
+            let entry = PyDict::new(py);
+            entry.set_item("level_id", &level.id)?;
+            entry.set_item("display_name", level.snippet.level_details.as_ref().and_then(|details| details.display_name.as_deref()))?;
+            entry.set_item("creator_channel_id", &level.snippet.creator_channel_id)?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+fn member_to_py<'py>(py: Python<'py>, member: &MemberSnippet) -> PyResult<&'py PyDict> {
+    let details = member.member_details.as_ref();
+    let memberships = member.memberships_details.as_ref();
+    let duration = memberships.and_then(|m| m.memberships_duration.as_ref());
+    let entry = PyDict::new(py);
+    entry.set_item("channel_id", details.and_then(|d| d.channel_id.as_deref()))?;
+    entry.set_item("display_name", details.and_then(|d| d.display_name.as_deref()))?;
+    entry.set_item("channel_url", details.and_then(|d| d.channel_url.as_deref()))?;
+    entry.set_item("profile_image_url", details.and_then(|d| d.profile_image_url.as_deref()))?;
+    entry.set_item("level_id", memberships.and_then(|m| m.highest_accessible_level.as_deref()))?;
+    entry.set_item("level_name", memberships.and_then(|m| m.highest_accessible_level_display_name.as_deref()))?;
+    entry.set_item("accessible_levels", memberships.map(|m| m.accessible_levels.clone()).unwrap_or_default())?;
+    entry.set_item("member_since", duration.and_then(|d| d.member_since.as_deref()))?;
+    entry.set_item("total_duration_months", duration.and_then(|d| d.member_total_duration_months))?;
+    Ok(entry)
+}
+
+/// Iterator over a channel's members that fetches pages only as they are consumed
+#[pyclass]
+pub struct MemberStream {
+    client: Client,
+    credentials: Credentials,
+    mode: String,
+    buffer: VecDeque<MemberSnippet>,
+    page_token: Option<String>,
+    exhausted: bool,
+    pages_fetched: u32,
+}
+
+impl MemberStream {
+    fn fetch_page(&mut self) -> PyResult<()> {
+        let page_size = MAX_MEMBERS_PER_PAGE.to_string();
+        let mut params = vec![
+            ("part", "snippet"),
+            ("mode", self.mode.as_str()),
+            ("maxResults", page_size.as_str()),
+        ];
+        if let Some(token) = &self.page_token {
+            params.push(("pageToken", token.as_str()));
+        }
+        let url = Url::parse_with_params(&format!("{}/members", client::BASE_URL), &params)
+            .map_err(|e| PyValueError::new_err(format!("Invalid members URL: {}", e)))?;
+        let response = get_membership_page(&self.client, &self.credentials, url.as_str(), "members")?;
+        let page: MemberListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse members: {}", e)))?;
+        self.pages_fetched += 1;
+        // In `updates` mode a token comes back even at the end, for polling later
+        self.exhausted = page.items.is_empty() || page.next_page_token.is_none();
+        self.page_token = page.next_page_token;
+        self.buffer.extend(page.items.into_iter().map(|member| member.snippet));
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl MemberStream {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        if self.buffer.is_empty() && !self.exhausted {
+            self.fetch_page()?;
+        }
+        match self.buffer.pop_front() {
+            Some(member) => Ok(Some(member_to_py(py, &member)?.into())),
+            None => Ok(None),
+        }
+    }
+
+    /// Token for the page after the last one fetched; pass it to a later
+    /// `iter_members(mode="updates", page_token=...)` to see only new members
+    #[getter]
+    fn page_token(&self) -> Option<String> {
+        self.page_token.clone()
+    }
+
+    /// API pages fetched so far, i.e. quota units spent
+    #[getter]
+    fn pages_fetched(&self) -> u32 {
+        self.pages_fetched
+    }
+}
+
+/// Lazily iterate over the members of the authorized user's channel
+///
+/// Requires OAuth credentials granted `auth.SCOPE_CHANNEL_MEMBERSHIPS_CREATOR`.
+/// Members are fetched up to 1000 per request as the iterator advances, 1
+/// quota unit each. The iterator yields `channel_id`, `display_name`,
+/// `channel_url`, `profile_image_url`, `level_id`, `level_name`,
+/// `accessible_levels`, `member_since` and `total_duration_months` dicts.
+///
+/// # Arguments
+/// * `api_key` - OAuthCredentials or an AuthConfig holding an OAuth token
+///   (default: the configured default)
+/// * `mode` - `"all_current"` for the full roster or `"updates"` for members
+///   who joined since `page_token` was issued (default: `"all_current"`)
+/// * `page_token` - Resume from a token returned by an earlier stream
+///
+/// # Returns
+/// * PyResult<MemberStream> - Iterator over member dicts
+#[pyfunction]
+#[pyo3(signature = (api_key=None, mode="all_current", page_token=None))]
+pub fn iter_members(api_key: Option<Credentials>, mode: &str, page_token: Option<String>) -> PyResult<MemberStream> {
+    if !matches!(mode, "all_current" | "updates") {
+        return Err(PyValueError::new_err(format!("Unknown members mode '{}'; use 'all_current' or 'updates'", mode)));
+    }
+    let api_key = Credentials::resolve(api_key)?;
+    require_owner(&api_key)?;
+    let client = client::build_client(&api_key)?;
+    Ok(MemberStream {
+        client,
+        credentials: api_key,
+        mode: mode.to_string(),
+        buffer: VecDeque::new(),
+        page_token,
+        exhausted: false,
+        pages_fetched: 0,
+    })
+}
@@ -0,0 +1,57 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+// Process-wide store of user metadata keyed by channel ID
+static CHANNEL_METADATA: OnceLock<Mutex<HashMap<String, Py<PyDict>>>> = OnceLock::new();
+
+fn store() -> &'static Mutex<HashMap<String, Py<PyDict>>> {
+    CHANNEL_METADATA.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Look up the metadata attached to a channel, if any
+pub(crate) fn channel_metadata<'py>(py: Python<'py>, channel_id: &str) -> PyResult<Option<&'py PyDict>> {
+    let metadata = store().lock().unwrap().get(channel_id).map(|metadata| metadata.clone_ref(py));
+    match metadata {
+        Some(metadata) => Ok(Some(metadata.into_ref(py).copy()?)),
+        None => Ok(None),
+    }
+}
+
+/// Attach custom metadata to a channel
+///
+/// # Arguments
+/// * `channel_id` - Canonical channel ID (`UC...`)
+/// * `metadata` - Arbitrary dictionary (internal artist ID, agent, genre tags, ...)
+///
+/// The metadata is returned under the `metadata` key of channel results.
+#[pyfunction]
+pub fn set_channel_metadata(channel_id: String, metadata: &PyDict) -> PyResult<()> {
+    let copy: Py<PyDict> = metadata.copy()?.into();
+    store().lock().unwrap().insert(channel_id, copy);
+    Ok(())
+}
+
+/// Get the metadata attached to a channel
+///
+/// # Returns
+/// * PyResult<Option<PyObject>> - Copy of the metadata dictionary, or None
+#[pyfunction]
+pub fn get_channel_metadata(py: Python, channel_id: String) -> PyResult<Option<PyObject>> {
+    Ok(channel_metadata(py, &channel_id)?.map(|metadata| metadata.into()))
+}
+
+/// Remove the metadata of one channel, or of every channel when no ID is given
+#[pyfunction]
+#[pyo3(signature = (channel_id=None))]
+pub fn clear_channel_metadata(channel_id: Option<String>) -> PyResult<()> {
+    let mut store = store().lock().unwrap();
+    match channel_id {
+        Some(channel_id) => {
+            store.remove(&channel_id);
+        }
+        None => store.clear(),
+    }
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyDict, PyList};
+
+// Comments take more effort than likes, so they count double by default
+pub const DEFAULT_LIKE_WEIGHT: f64 = 1.0;
+pub const DEFAULT_COMMENT_WEIGHT: f64 = 2.0;
+
+// The per-video `engagement_rate` is plain (likes + comments) / views unless
+// a call passes `engagement_rate_weights`
+pub(crate) const ENGAGEMENT_RATE_WEIGHTS: EngagementWeights = EngagementWeights { like: 1.0, comment: 1.0 };
+
+// Provenance labels of the entries in a result's `derived` sub-dict
+pub(crate) const SOURCE_API: &str = "api";
+pub(crate) const SOURCE_COMPUTED: &str = "computed";
+pub(crate) const SOURCE_THIRD_PARTY: &str = "third_party";
+
+/// Record `name` under `result["derived"]` as `{"value": ..., "source": ...}`
+///
+/// The sub-dict is created on first use. `source` is `SOURCE_COMPUTED` for the
+/// crate's own calculations, `SOURCE_API` for values taken as-is from the API
+/// and `SOURCE_THIRD_PARTY` for estimates from services other than YouTube.
+pub(crate) fn set_derived(result: &PyDict, name: &str, value: impl ToPyObject, source: &str) -> PyResult<()> {
+    let derived: &PyDict = match result.get_item("derived")? {
+        Some(existing) => existing.downcast()?,
+        None => {
+            let created = PyDict::new(result.py());
+            result.set_item("derived", created)?;
+            created
+        }
+    };
+    let entry = PyDict::new(result.py());
+    entry.set_item("value", value)?;
+    entry.set_item("source", source)?;
+    derived.set_item(name, entry)
+}
+
+/// Weights applied to likes and comments in the engagement score
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct EngagementWeights {
+    pub like: f64,
+    pub comment: f64,
+}
+
+impl Default for EngagementWeights {
+    fn default() -> Self {
+        EngagementWeights { like: DEFAULT_LIKE_WEIGHT, comment: DEFAULT_COMMENT_WEIGHT }
+    }
+}
+
+impl EngagementWeights {
+    /// Raises ValueError for negative or non-finite weights
+    pub fn new(like: f64, comment: f64) -> PyResult<Self> {
+        if !(like.is_finite() && comment.is_finite() && like >= 0.0 && comment >= 0.0) {
+            return Err(PyValueError::new_err(format!(
+                "Engagement weights must be finite and non-negative, got ({}, {})",
+                like, comment
+            )));
+        }
+        Ok(EngagementWeights { like, comment })
+    }
+
+    /// Validated `(like_weight, comment_weight)`, or `default` when None
+    pub fn from_tuple(weights: Option<(f64, f64)>, default: EngagementWeights) -> PyResult<Self> {
+        match weights {
+            Some((like, comment)) => Self::new(like, comment),
+            None => Ok(default),
+        }
+    }
+}
+
+/// Weighted likes + comments per view; None when there are no views
+pub(crate) fn engagement(views: u64, likes: u64, comments: u64, weights: EngagementWeights) -> Option<f64> {
+    if views == 0 {
+        return None;
+    }
+    Some((weights.like * likes as f64 + weights.comment * comments as f64) / views as f64)
+}
+
+/// Engagement rate of a single video
+///
+/// None when views are 0 or any count is hidden, since a hidden count would
+/// otherwise read as zero engagement.
+pub(crate) fn engagement_rate(views: Option<u64>, likes: Option<u64>, comments: Option<u64>, weights: EngagementWeights) -> Option<f64> {
+    engagement(views?, likes?, comments?, weights)
+}
+
+/// `(views, likes, comments)` of one video, None for a hidden like count or
+/// disabled comments
+pub(crate) type VideoCounts = (u64, Option<u64>, Option<u64>);
+
+/// Videos left out of `aggregate_engagement` for a hidden count
+pub(crate) fn engagement_excluded(videos: &[VideoCounts]) -> usize {
+    videos.iter().filter(|v| v.1.is_none() || v.2.is_none()).count()
+}
+
+/// Channel-level engagement: total weighted interactions over total views
+///
+/// Weighting by views keeps one small viral video from dominating the
+/// average. Only videos with both counts take part, since a hidden count
+/// would otherwise read as zero engagement; see `engagement_excluded`.
+pub(crate) fn aggregate_engagement(videos: &[VideoCounts], weights: EngagementWeights) -> Option<f64> {
+    let complete: Vec<(u64, u64, u64)> = videos.iter().filter_map(|&(views, likes, comments)| Some((views, likes?, comments?))).collect();
+    let views: u64 = complete.iter().map(|v| v.0).sum();
+    let likes: u64 = complete.iter().map(|v| v.1).sum();
+    let comments: u64 = complete.iter().map(|v| v.2).sum();
+    engagement(views, likes, comments, weights)
+}
+
+/// Count over views of the videos where that count is present
+fn present_ratio(videos: &[VideoCounts], count: impl Fn(&VideoCounts) -> Option<u64>) -> Option<f64> {
+    let (views, total) = videos.iter()
+        .filter_map(|video| count(video).map(|value| (video.0, value)))
+        .fold((0u64, 0u64), |(views, total), (v, c)| (views + v, total + c));
+    if views == 0 { None } else { Some(total as f64 / views as f64) }
+}
+
+/// Averages and ratios over `(views, likes, comments)` items
+///
+/// Ratios divide totals rather than averaging per-video ratios, like
+/// `aggregate_engagement`, and each only covers the videos where its count is
+/// present; `likes_hidden_count`, `comments_disabled_count` and
+/// `engagement_excluded_count` say how many were left out. `engagement_rate`
+/// uses the per-video rate weights (likes + comments per view by default).
+/// Every value is None without videos or without views.
+pub(crate) fn engagement_summary<'py>(py: Python<'py>, videos: &[VideoCounts], rate_weights: EngagementWeights) -> PyResult<&'py PyDict> {
+    let views: u64 = videos.iter().map(|v| v.0).sum();
+
+    let mut sorted: Vec<u64> = videos.iter().map(|v| v.0).collect();
+    sorted.sort_unstable();
+    let median = match sorted.len() {
+        0 => None,
+        n if n % 2 == 1 => Some(sorted[n / 2] as f64),
+        n => Some((sorted[n / 2 - 1] + sorted[n / 2]) as f64 / 2.0),
+    };
+
+    let summary = PyDict::new(py);
+    summary.set_item("video_count", videos.len())?;
+    summary.set_item("average_views", if videos.is_empty() { None } else { Some(views as f64 / videos.len() as f64) })?;
+    summary.set_item("median_views", median)?;
+    summary.set_item("like_view_ratio", present_ratio(videos, |v| v.1))?;
+    summary.set_item("comment_view_ratio", present_ratio(videos, |v| v.2))?;
+    summary.set_item("engagement_rate", aggregate_engagement(videos, rate_weights))?;
+    summary.set_item("likes_hidden_count", videos.iter().filter(|v| v.1.is_none()).count())?;
+    summary.set_item("comments_disabled_count", videos.iter().filter(|v| v.2.is_none()).count())?;
+    summary.set_item("engagement_excluded_count", engagement_excluded(videos))?;
+    Ok(summary)
+}
+
+fn count(video: &PyDict, key: &str) -> PyResult<Option<u64>> {
+    match video.get_item(key)? {
+        Some(value) if !value.is_none() => Ok(Some(value.extract().unwrap_or(0))),
+        _ => Ok(None),
+    }
+}
+
+/// Engagement score of a single video
+///
+/// # Arguments
+/// * `view_count`, `like_count`, `comment_count` - Video statistics
+/// * `like_weight` - Weight of a like (default: 1.0)
+/// * `comment_weight` - Weight of a comment (default: 2.0)
+///
+/// # Returns
+/// * PyResult<Option<f64>> - `(like_weight * likes + comment_weight * comments) / views`,
+///   None without views; ValueError for negative or non-finite weights
+#[pyfunction]
+#[pyo3(signature = (view_count, like_count, comment_count, like_weight=DEFAULT_LIKE_WEIGHT, comment_weight=DEFAULT_COMMENT_WEIGHT))]
+pub fn engagement_score(
+    view_count: u64,
+    like_count: u64,
+    comment_count: u64,
+    like_weight: f64,
+    comment_weight: f64,
+) -> PyResult<Option<f64>> {
+    let weights = EngagementWeights::new(like_weight, comment_weight)?;
+    Ok(engagement(view_count, like_count, comment_count, weights))
+}
+
+/// Aggregate engagement score over a list of video dicts
+///
+/// # Arguments
+/// * `videos` - Video dicts with `view_count`, `like_count` and `comment_count`
+/// * `like_weight` - Weight of a like (default: 1.0)
+/// * `comment_weight` - Weight of a comment (default: 2.0)
+///
+/// # Returns
+/// * PyResult<Option<f64>> - View-weighted engagement score over the videos
+///   with both counts present, None without views; ValueError for negative or
+///   non-finite weights
+#[pyfunction]
+#[pyo3(signature = (videos, like_weight=DEFAULT_LIKE_WEIGHT, comment_weight=DEFAULT_COMMENT_WEIGHT))]
+pub fn channel_engagement_score(videos: &PyList, like_weight: f64, comment_weight: f64) -> PyResult<Option<f64>> {
+    let weights = EngagementWeights::new(like_weight, comment_weight)?;
+    let mut counts = Vec::with_capacity(videos.len());
+    for video in videos.iter() {
+        let video: &PyDict = video.downcast()?;
+        counts.push((count(video, "view_count")?.unwrap_or(0), count(video, "like_count")?, count(video, "comment_count")?));
+    }
+    Ok(aggregate_engagement(&counts, weights))
+}
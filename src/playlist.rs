@@ -0,0 +1,693 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::exceptions::PyValueError;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use reqwest::blocking::Client;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::account::{self, Thumbnails, MAX_RESULTS_PER_PAGE};
+use crate::auth::Credentials;
+use crate::client;
+
+// playlistItems response structures
+#[derive(Debug, Deserialize)]
+struct PlaylistItemListResponse {
+    #[serde(default)]
+    items: Vec<PlaylistItem>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct PlaylistItem {
+    // Unique per playlist entry, unlike the video ID
+    pub id: Option<String>,
+    pub snippet: Option<PlaylistItemSnippet>,
+    #[serde(rename = "contentDetails")]
+    pub content_details: PlaylistItemContentDetails,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct PlaylistItemSnippet {
+    pub title: Option<String>,
+    // Owner of the video, which differs from the playlist owner in compilations
+    #[serde(rename = "videoOwnerChannelId")]
+    pub video_owner_channel_id: Option<String>,
+    #[serde(rename = "videoOwnerChannelTitle")]
+    pub video_owner_channel_title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct PlaylistItemContentDetails {
+    #[serde(rename = "videoId")]
+    pub video_id: String,
+    // Missing for private and deleted videos
+    #[serde(rename = "videoPublishedAt")]
+    pub video_published_at: Option<String>,
+}
+
+// playlists response structures
+#[derive(Debug, Deserialize)]
+struct PlaylistListResponse {
+    #[serde(default)]
+    items: Vec<ChannelPlaylist>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelPlaylist {
+    id: String,
+    snippet: ChannelPlaylistSnippet,
+    #[serde(rename = "contentDetails")]
+    content_details: Option<ChannelPlaylistContentDetails>,
+    status: Option<ChannelPlaylistStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelPlaylistSnippet {
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(rename = "publishedAt")]
+    published_at: Option<String>,
+    thumbnails: Option<Thumbnails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelPlaylistContentDetails {
+    #[serde(rename = "itemCount")]
+    item_count: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChannelPlaylistStatus {
+    #[serde(rename = "privacyStatus")]
+    privacy_status: Option<String>,
+}
+
+// Atom feed structures (youtube.com/feeds/videos.xml)
+#[derive(Debug, Default)]
+struct Feed {
+    title: Option<String>,
+    author: Option<String>,
+    channel_id: Option<String>,
+    entries: Vec<FeedEntry>,
+}
+
+#[derive(Debug, Default)]
+struct FeedEntry {
+    video_id: String,
+    channel_id: Option<String>,
+    title: String,
+    author: Option<String>,
+    published_at: Option<String>,
+    updated_at: Option<String>,
+    description: Option<String>,
+    thumbnail: Option<String>,
+    view_count: Option<u64>,
+    rating_count: Option<u64>,
+}
+
+fn attribute(element: &BytesStart, name: &str) -> Option<String> {
+    element.try_get_attribute(name).ok().flatten()
+        .and_then(|attr| attr.unescape_value().ok().map(|value| value.into_owned()))
+}
+
+fn parse_feed(xml: &str) -> Result<Feed, quick_xml::Error> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut feed = Feed::default();
+    let mut entry: Option<FeedEntry> = None;
+    let mut path: Vec<String> = Vec::new();
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).into_owned();
+                if name == "entry" {
+                    entry = Some(FeedEntry::default());
+                }
+                path.push(name);
+            }
+            Event::End(_) => {
+                let closed = path.pop();
+                if closed.as_deref() == Some("entry") {
+                    feed.entries.extend(entry.take());
+                }
+            }
+            Event::Empty(e) => {
+                if let Some(current) = entry.as_mut() {
+                    match e.name().as_ref() {
+                        b"media:thumbnail" => current.thumbnail = attribute(&e, "url"),
+                        b"media:statistics" => {
+                            current.view_count = attribute(&e, "views").and_then(|v| v.parse().ok())
+                        }
+                        b"media:starRating" => {
+                            current.rating_count = attribute(&e, "count").and_then(|v| v.parse().ok())
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Event::Text(e) => {
+                let text = e.unescape()?.into_owned();
+                let element = path.last().map(String::as_str).unwrap_or("");
+                let parent = if path.len() >= 2 { path[path.len() - 2].as_str() } else { "" };
+                match entry.as_mut() {
+                    Some(current) => match (parent, element) {
+                        (_, "yt:videoId") => current.video_id = text,
+                        (_, "yt:channelId") => current.channel_id = Some(text),
+                        ("entry", "title") => current.title = text,
+                        ("author", "name") => current.author = Some(text),
+                        ("entry", "published") => current.published_at = Some(text),
+                        ("entry", "updated") => current.updated_at = Some(text),
+                        (_, "media:description") => current.description = Some(text),
+                        _ => {}
+                    },
+                    None => match (parent, element) {
+                        ("feed", "title") => feed.title = Some(text),
+                        ("feed", "yt:channelId") => feed.channel_id = Some(text),
+                        ("author", "name") => feed.author = Some(text),
+                        _ => {}
+                    },
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(feed)
+}
+
+/// One playlistItems page of up to `max_results` items (1 quota unit)
+///
+/// Returns None when the API rejects `page_token` (`invalidPageToken`).
+fn fetch_playlist_page(
+    client: &Client,
+    credentials: &Credentials,
+    playlist_id: &str,
+    max_results: usize,
+    page_token: Option<&str>,
+) -> PyResult<Option<PlaylistItemListResponse>> {
+    let mut params = vec![
+        ("part", "snippet,contentDetails".to_string()),
+        ("playlistId", playlist_id.to_string()),
+        ("maxResults", max_results.min(MAX_RESULTS_PER_PAGE).to_string()),
+    ];
+    if let Some(token) = page_token {
+        params.push(("pageToken", token.to_string()));
+    }
+    let url = Url::parse_with_params(&format!("{}/playlistItems", client::BASE_URL), &params)
+        .map_err(|e| PyValueError::new_err(format!("Invalid playlist URL: {}", e)))?;
+
+    let response = client::send_get(client, credentials, url.as_str())?;
+    if page_token.is_some() && response.is_invalid_page_token() {
+        return Ok(None);
+    }
+    if response.status.as_u16() == 404 {
+        return Err(PyValueError::new_err(format!("Playlist not found: {}", playlist_id)));
+    }
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Failed to fetch playlist items: {}", response.status)));
+    }
+    response.json()
+        .map(Some)
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse playlist items: {}", e)))
+}
+
+// Restarts after rejected page tokens before a listing is given up
+const MAX_PAGE_TOKEN_RESTARTS: u32 = 3;
+
+/// Page-by-page walk over a playlist that survives expired page tokens
+///
+/// Page tokens can expire while a long crawl is in progress. When one is
+/// rejected, the listing restarts from the first page, entries already
+/// returned are skipped and a warning is recorded instead of failing.
+pub(crate) struct PlaylistPager {
+    playlist_id: String,
+    page_token: Option<String>,
+    seen: HashSet<String>,
+    restarts: u32,
+    exhausted: bool,
+    pages_fetched: u32,
+    warnings: Vec<String>,
+}
+
+impl PlaylistPager {
+    pub(crate) fn new(playlist_id: String) -> Self {
+        PlaylistPager {
+            playlist_id,
+            page_token: None,
+            seen: HashSet::new(),
+            restarts: 0,
+            exhausted: false,
+            pages_fetched: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Entries of the next page not returned before; empty once exhausted
+    pub(crate) fn next_page(&mut self, client: &Client, credentials: &Credentials, max_results: usize) -> PyResult<Vec<PlaylistItem>> {
+        if self.exhausted {
+            return Ok(Vec::new());
+        }
+        let page = loop {
+            let page = fetch_playlist_page(client, credentials, &self.playlist_id, max_results, self.page_token.as_deref())?;
+            self.pages_fetched += 1;
+            match page {
+                Some(page) => break page,
+                None if self.restarts < MAX_PAGE_TOKEN_RESTARTS => {
+                    self.restarts += 1;
+                    self.page_token = None;
+                    self.warnings.push(format!(
+                        "Page token for playlist {} was rejected; restarted the listing from the beginning \
+                         and skipped {} entries already collected",
+                        self.playlist_id, self.seen.len()
+                    ));
+                }
+                None => return Err(PyValueError::new_err(format!(
+                    "Page tokens for playlist {} were rejected {} times in a row", self.playlist_id, self.restarts + 1
+                ))),
+            }
+        };
+        self.page_token = page.next_page_token;
+        self.exhausted = self.page_token.is_none();
+        Ok(page.items.into_iter()
+            .filter(|item| {
+                let key = item.id.clone().unwrap_or_else(|| item.content_details.video_id.clone());
+                self.seen.insert(key)
+            })
+            .collect())
+    }
+
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+/// Walk a playlist page by page (1 quota unit per 50 items)
+pub(crate) fn fetch_playlist_items(
+    client: &Client,
+    credentials: &Credentials,
+    playlist_id: &str,
+    limit: Option<usize>,
+) -> PyResult<Vec<PlaylistItem>> {
+    fetch_playlist_items_with_warnings(client, credentials, playlist_id, limit).map(|(items, _)| items)
+}
+
+/// `fetch_playlist_items`, plus warnings about restarts after rejected page tokens
+pub(crate) fn fetch_playlist_items_with_warnings(
+    client: &Client,
+    credentials: &Credentials,
+    playlist_id: &str,
+    limit: Option<usize>,
+) -> PyResult<(Vec<PlaylistItem>, Vec<String>)> {
+    let mut items = Vec::new();
+    let mut pager = PlaylistPager::new(playlist_id.to_string());
+
+    while !pager.is_exhausted() {
+        let remaining = limit.map_or(MAX_RESULTS_PER_PAGE, |limit| limit.saturating_sub(items.len()));
+        if remaining == 0 {
+            break;
+        }
+        let mut page = pager.next_page(client, credentials, remaining)?;
+        // A restart can return more entries than the limit still allows
+        page.truncate(remaining);
+        items.extend(page);
+    }
+    Ok((items, pager.warnings))
+}
+
+/// Python dict for one playlist entry
+fn playlist_item_to_py<'py>(py: Python<'py>, item: &PlaylistItem) -> PyResult<&'py PyDict> {
+    let snippet = item.snippet.as_ref();
+    let entry = PyDict::new(py);
+    entry.set_item("video_id", &item.content_details.video_id)?;
+    entry.set_item("title", snippet.and_then(|snippet| snippet.title.as_deref()))?;
+    entry.set_item("published_at", &item.content_details.video_published_at)?;
+    entry.set_item("channel_id", snippet.and_then(|snippet| snippet.video_owner_channel_id.as_deref()))?;
+    entry.set_item("channel_title", snippet.and_then(|snippet| snippet.video_owner_channel_title.as_deref()))?;
+    entry.set_item("video_url", format!("https://www.youtube.com/watch?v={}", item.content_details.video_id))?;
+    Ok(entry)
+}
+
+/// Iterator over a playlist that fetches pages only as they are consumed
+///
+/// Yields lists of up to `batch_size` item dicts, or single dicts when built
+/// without a batch size. At most one page is held in memory at a time.
+#[pyclass]
+pub struct PlaylistItemStream {
+    client: Client,
+    credentials: Credentials,
+    batch_size: Option<usize>,
+    buffer: VecDeque<PlaylistItem>,
+    pager: PlaylistPager,
+}
+
+impl PlaylistItemStream {
+    pub(crate) fn new(credentials: Credentials, playlist_id: String, batch_size: Option<usize>) -> PyResult<Self> {
+        let client = client::build_client(&credentials)?;
+        Ok(PlaylistItemStream {
+            client,
+            credentials,
+            batch_size,
+            buffer: VecDeque::new(),
+            pager: PlaylistPager::new(playlist_id),
+        })
+    }
+
+    /// Fetch pages until `wanted` items are buffered or the playlist ends
+    fn fill(&mut self, wanted: usize) -> PyResult<()> {
+        while self.buffer.len() < wanted && !self.pager.is_exhausted() {
+            let page = self.pager.next_page(&self.client, &self.credentials, MAX_RESULTS_PER_PAGE)?;
+            self.buffer.extend(page);
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PlaylistItemStream {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        self.fill(self.batch_size.unwrap_or(1))?;
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+        match self.batch_size {
+            Some(batch_size) => {
+                let batch = PyList::empty(py);
+                let count = batch_size.min(self.buffer.len());
+                for item in self.buffer.drain(..count) {
+                    batch.append(playlist_item_to_py(py, &item)?)?;
+                }
+                Ok(Some(batch.into()))
+            }
+            None => {
+                let item = self.buffer.pop_front().expect("buffer checked above");
+                Ok(Some(playlist_item_to_py(py, &item)?.into()))
+            }
+        }
+    }
+
+    /// Playlist being walked
+    #[getter]
+    fn playlist_id(&self) -> &str {
+        &self.pager.playlist_id
+    }
+
+    /// API pages fetched so far, i.e. quota units spent
+    #[getter]
+    fn pages_fetched(&self) -> u32 {
+        self.pager.pages_fetched
+    }
+
+    /// Notes about listings restarted after a page token expired mid-stream
+    #[getter]
+    fn warnings(&self) -> Vec<String> {
+        self.pager.warnings.clone()
+    }
+}
+
+/// Stream a playlist of any size to Python in batches as pages arrive
+///
+/// Returns an iterator yielding lists of up to `batch_size` item dicts
+/// (`video_id`, `title`, `published_at`, `channel_id`, `channel_title`,
+/// `video_url`). Pages are requested only when the consumer needs them, so
+/// memory stays flat for 10k+ item playlists and processing can start after
+/// the first page. Costs 1 quota unit per 50 items consumed.
+///
+/// # Arguments
+/// * `playlist_id` - Playlist ID
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `batch_size` - Items per yielded list (default: 50)
+///
+/// # Returns
+/// * PyResult<PlaylistItemStream> - Iterator over item batches
+#[pyfunction]
+#[pyo3(signature = (playlist_id, api_key=None, batch_size=50))]
+pub fn stream_playlist_items(
+    playlist_id: String,
+    api_key: Option<Credentials>,
+    batch_size: usize,
+) -> PyResult<PlaylistItemStream> {
+    if batch_size == 0 {
+        return Err(PyValueError::new_err("batch_size must be at least 1"));
+    }
+    PlaylistItemStream::new(Credentials::resolve(api_key)?, playlist_id, Some(batch_size))
+}
+
+/// List the playlists a channel has published
+///
+/// Costs 1 quota unit to resolve non-ID identifiers plus 1 per 50 playlists.
+/// Only public playlists are returned unless the credentials belong to the
+/// channel owner.
+///
+/// # Arguments
+/// * `channel_id` - Channel ID, @handle, legacy username or channel URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `max_results` - Stop after this many playlists (default: all)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `playlist_id`, `title`, `description`,
+///   `published_at`, `item_count`, `privacy_status`, `thumbnails` and
+///   `playlist_url` dictionaries
+#[pyfunction]
+#[pyo3(signature = (channel_id, api_key=None, max_results=None))]
+pub fn get_channel_playlists(
+    channel_id: String,
+    api_key: Option<Credentials>,
+    max_results: Option<usize>,
+) -> PyResult<PyObject> {
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let channel_id = account::channel_id_for(&client, &api_key, &channel_id)?;
+
+    let mut playlists: Vec<ChannelPlaylist> = Vec::new();
+    let mut page_token: Option<String> = None;
+    loop {
+        let remaining = max_results.map_or(MAX_RESULTS_PER_PAGE, |limit| limit.saturating_sub(playlists.len()));
+        if remaining == 0 {
+            break;
+        }
+        let mut params = vec![
+            ("part", "snippet,contentDetails,status".to_string()),
+            ("channelId", channel_id.clone()),
+            ("maxResults", remaining.min(MAX_RESULTS_PER_PAGE).to_string()),
+        ];
+        if let Some(token) = &page_token {
+            params.push(("pageToken", token.clone()));
+        }
+        let url = Url::parse_with_params(&format!("{}/playlists", client::BASE_URL), &params)
+            .map_err(|e| PyValueError::new_err(format!("Invalid playlists URL: {}", e)))?;
+
+        let response = client::send_get(&client, &api_key, url.as_str())?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch playlists: {}", response.status)));
+        }
+        let page: PlaylistListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse playlists: {}", e)))?;
+
+        playlists.extend(page.items);
+        page_token = page.next_page_token;
+        if page_token.is_none() {
+            break;
+        }
+    }
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for playlist in &playlists {
+            let entry = PyDict::new(py);
+            entry.set_item("playlist_id", &playlist.id)?;
+            entry.set_item("title", &playlist.snippet.title)?;
+            entry.set_item("description", &playlist.snippet.description)?;
+            entry.set_item("published_at", &playlist.snippet.published_at)?;
+            entry.set_item("item_count", playlist.content_details.as_ref().and_then(|details| details.item_count))?;
+            entry.set_item("privacy_status", playlist.status.as_ref().and_then(|status| status.privacy_status.as_deref()))?;
+            match &playlist.snippet.thumbnails {
+                Some(thumbnails) => entry.set_item("thumbnails", thumbnails.to_py(py)?)?,
+                None => entry.set_item("thumbnails", PyDict::new(py))?,
+            }
+            entry.set_item("playlist_url", format!("https://www.youtube.com/playlist?list={}", playlist.id))?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+/// Get the latest items of a playlist from its public Atom feed
+///
+/// Costs no API quota. The feed only carries the most recent 15 items, which
+/// is enough to detect additions between polls.
+///
+/// # Arguments
+/// * `playlist_id` - Playlist ID
+/// * `known_video_ids` - Video IDs seen on a previous poll; items not in this
+///   list are reported under `new_video_ids`
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with playlist info and feed `items`
+#[pyfunction]
+#[pyo3(signature = (playlist_id, known_video_ids=None))]
+pub fn get_playlist_feed(
+    playlist_id: String,
+    known_video_ids: Option<Vec<String>>,
+) -> PyResult<PyObject> {
+    let client = Client::new();
+    let feed_url = Url::parse_with_params(&format!("{}/feeds/videos.xml", client::WEB_URL), &[("playlist_id", &playlist_id)])
+        .map_err(|e| PyValueError::new_err(format!("Invalid playlist feed URL: {}", e)))?;
+
+    let response = client.get(client::apply_base_url_override(feed_url.as_str()))
+        .send()
+        .map_err(|e| PyValueError::new_err(format!("Request failed: {}", e)))?;
+
+    if response.status().as_u16() == 404 {
+        return Err(PyValueError::new_err(format!("Playlist feed not found: {}", playlist_id)));
+    }
+    if !response.status().is_success() {
+        return Err(PyValueError::new_err(format!("Failed to fetch playlist feed: {}", response.status())));
+    }
+
+    let feed_text = response.text()
+        .map_err(|e| PyValueError::new_err(format!("Failed to read response text: {}", e)))?;
+    let feed = parse_feed(&feed_text)
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse playlist feed: {}", e)))?;
+
+    let known: HashSet<String> = known_video_ids.unwrap_or_default().into_iter().collect();
+
+    Python::with_gil(|py| {
+        let py_dict = PyDict::new(py);
+        py_dict.set_item("playlist_id", &playlist_id)?;
+        py_dict.set_item("title", &feed.title)?;
+        py_dict.set_item("author", &feed.author)?;
+        py_dict.set_item("channel_id", &feed.channel_id)?;
+
+        let items = PyList::empty(py);
+        let mut new_video_ids = Vec::new();
+        for entry in &feed.entries {
+            let item = PyDict::new(py);
+            item.set_item("video_id", &entry.video_id)?;
+            item.set_item("channel_id", &entry.channel_id)?;
+            item.set_item("title", &entry.title)?;
+            item.set_item("author", &entry.author)?;
+            item.set_item("published_at", &entry.published_at)?;
+            item.set_item("updated_at", &entry.updated_at)?;
+            item.set_item("description", &entry.description)?;
+            item.set_item("thumbnail", &entry.thumbnail)?;
+            item.set_item("view_count", entry.view_count)?;
+            item.set_item("rating_count", entry.rating_count)?;
+            item.set_item("video_url", format!("https://www.youtube.com/watch?v={}", entry.video_id))?;
+            items.append(item)?;
+
+            if !known.contains(&entry.video_id) {
+                new_video_ids.push(entry.video_id.clone());
+            }
+        }
+        py_dict.set_item("items", items)?;
+        py_dict.set_item("new_video_ids", new_video_ids)?;
+
+        Ok(py_dict.into())
+    })
+}
+
+#[derive(Default)]
+struct ChannelContribution {
+    title: Option<String>,
+    item_count: u64,
+    total_views: u64,
+}
+
+/// Break a playlist down by the channels that own its videos
+///
+/// Costs about 2 quota units per 50 playlist items.
+///
+/// # Arguments
+/// * `playlist_id` - Playlist ID
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `item_count`, `unavailable_count`
+///   (private or deleted items), `channels`: one entry per contributing
+///   channel with `item_count`, `total_views` and `view_share`, largest first,
+///   and `warnings` noting listings restarted after an expired page token
+#[pyfunction]
+#[pyo3(signature = (playlist_id, api_key=None))]
+pub fn playlist_channel_breakdown(playlist_id: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+
+        let (items, warnings) = fetch_playlist_items_with_warnings(&client, &api_key, &playlist_id, None)?;
+        let video_ids: Vec<String> = items.iter().map(|item| item.content_details.video_id.clone()).collect();
+        let views: HashMap<String, u64> = account::fetch_video_statistics(&client, &api_key, &video_ids)?
+            .into_iter()
+            .map(|video| {
+                let views = video.statistics.map(|stats| stats.counts().0).unwrap_or(0);
+                (video.id, views)
+            })
+            .collect();
+
+        let mut unavailable_count = 0;
+        let mut order: Vec<String> = Vec::new();
+        let mut contributions: HashMap<String, ChannelContribution> = HashMap::new();
+        for item in &items {
+            let owner = item.snippet.as_ref().and_then(|snippet| {
+                snippet.video_owner_channel_id.clone().map(|id| (id, snippet.video_owner_channel_title.clone()))
+            });
+            let (channel_id, title) = match owner {
+                Some(owner) => owner,
+                None => {
+                    unavailable_count += 1;
+                    continue;
+                }
+            };
+            let contribution = contributions.entry(channel_id.clone()).or_insert_with(|| {
+                order.push(channel_id.clone());
+                ChannelContribution { title, ..Default::default() }
+            });
+            contribution.item_count += 1;
+            contribution.total_views += views.get(&item.content_details.video_id).copied().unwrap_or(0);
+        }
+
+        // Largest contributors first, ties in playlist order
+        order.sort_by_key(|channel_id| std::cmp::Reverse(contributions[channel_id].item_count));
+        let playlist_views: u64 = contributions.values().map(|contribution| contribution.total_views).sum();
+
+        Python::with_gil(|py| {
+            let result = PyDict::new(py);
+            result.set_item("playlist_id", &playlist_id)?;
+            result.set_item("item_count", items.len())?;
+            result.set_item("unavailable_count", unavailable_count)?;
+            result.set_item("total_views", playlist_views)?;
+            result.set_item("warnings", &warnings)?;
+
+            let channels = PyList::empty(py);
+            for channel_id in &order {
+                let contribution = &contributions[channel_id];
+                let channel = PyDict::new(py);
+                channel.set_item("channel_id", channel_id)?;
+                channel.set_item("channel_title", &contribution.title)?;
+                channel.set_item("item_count", contribution.item_count)?;
+                channel.set_item("total_views", contribution.total_views)?;
+                let share = if playlist_views == 0 { 0.0 } else { contribution.total_views as f64 / playlist_views as f64 };
+                channel.set_item("view_share", share)?;
+                channels.append(channel)?;
+            }
+            result.set_item("channels", channels)?;
+
+            Ok(result.into())
+        })
+    })
+}
@@ -0,0 +1,58 @@
+use pyo3::prelude::*;
+#[cfg(not(feature = "keyring"))]
+use pyo3::exceptions::PyRuntimeError;
+#[cfg(feature = "keyring")]
+use pyo3::exceptions::PyValueError;
+
+// Service name under which every secret is stored in the OS keyring
+#[cfg(feature = "keyring")]
+const KEYRING_SERVICE: &str = "youtube_stats";
+
+pub(crate) const API_KEY_ENTRY: &str = "api_key";
+
+/// Keyring entry name for an OAuth refresh token
+pub(crate) fn refresh_token_entry(account: &str) -> String {
+    format!("refresh_token:{}", account)
+}
+
+/// Read a secret from the OS keyring; None when absent or keyring support is not compiled in
+#[cfg(feature = "keyring")]
+pub(crate) fn get_secret(name: &str) -> Option<String> {
+    keyring::Entry::new(KEYRING_SERVICE, name)
+        .and_then(|entry| entry.get_password())
+        .ok()
+}
+
+#[cfg(not(feature = "keyring"))]
+pub(crate) fn get_secret(_name: &str) -> Option<String> {
+    None
+}
+
+/// Store a secret in the OS keyring
+#[cfg(feature = "keyring")]
+pub(crate) fn set_secret(name: &str, value: &str) -> PyResult<()> {
+    keyring::Entry::new(KEYRING_SERVICE, name)
+        .and_then(|entry| entry.set_password(value))
+        .map_err(|e| PyValueError::new_err(format!("Failed to store {} in keyring: {}", name, e)))
+}
+
+#[cfg(not(feature = "keyring"))]
+pub(crate) fn set_secret(_name: &str, _value: &str) -> PyResult<()> {
+    Err(PyRuntimeError::new_err("youtube_stats was built without the 'keyring' feature"))
+}
+
+/// Remove a secret from the OS keyring (missing entries are not an error)
+#[cfg(feature = "keyring")]
+pub(crate) fn delete_secret(name: &str) -> PyResult<()> {
+    let entry = keyring::Entry::new(KEYRING_SERVICE, name)
+        .map_err(|e| PyValueError::new_err(format!("Failed to open keyring entry {}: {}", name, e)))?;
+    match entry.delete_password() {
+        Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+        Err(e) => Err(PyValueError::new_err(format!("Failed to delete {} from keyring: {}", name, e))),
+    }
+}
+
+#[cfg(not(feature = "keyring"))]
+pub(crate) fn delete_secret(_name: &str) -> PyResult<()> {
+    Err(PyRuntimeError::new_err("youtube_stats was built without the 'keyring' feature"))
+}
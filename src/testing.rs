@@ -0,0 +1,417 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::types::{PyDict, PyList, PyTuple};
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use crate::client;
+use crate::utils::redact;
+
+// A canned response for one endpoint, optionally limited to matching params
+struct MockRule {
+    endpoint: String,
+    params: Vec<(String, String)>,
+    status: u16,
+    body: String,
+    latency: Duration,
+    remaining: Option<u32>,
+    // Recorded fixtures must match the full query (minus the key), not a subset
+    exact: bool,
+    content_type: String,
+}
+
+const JSON_CONTENT_TYPE: &str = "application/json; charset=UTF-8";
+
+impl MockRule {
+    fn matches(&self, endpoint: &str, query: &[(String, String)]) -> bool {
+        self.endpoint == endpoint
+            && self.remaining != Some(0)
+            && self.params.iter().all(|param| query.contains(param))
+            && (!self.exact || query.iter().all(|param| param.0 == "key" || self.params.contains(param)))
+    }
+}
+
+/// Endpoint name (e.g. `"channels"`) and query pairs of an API URL
+fn endpoint_and_query(url: &Url) -> (String, Vec<(String, String)>) {
+    let endpoint = url.path().trim_start_matches("/youtube/v3").trim_matches('/').to_string();
+    let query = url.query_pairs()
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    (endpoint, query)
+}
+
+#[derive(Default)]
+struct MockState {
+    rules: Vec<MockRule>,
+    requests: Vec<(String, Vec<(String, String)>)>,
+}
+
+fn body_to_string(py: Python, body: &PyAny) -> PyResult<String> {
+    match body.extract::<String>() {
+        Ok(text) => Ok(text),
+        Err(_) => py.import("json")?.call_method1("dumps", (body,))?.extract(),
+    }
+}
+
+fn google_error(status: u16, reason: &str, message: &str) -> String {
+    serde_json::json!({
+        "error": {
+            "code": status,
+            "message": message,
+            "errors": [{"message": message, "domain": "youtube.mock", "reason": reason}],
+        }
+    })
+    .to_string()
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Status",
+    }
+}
+
+fn handle_connection(stream: TcpStream, state: &Mutex<MockState>) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers and any form body, so closing never resets the connection
+    let mut header = String::new();
+    let mut content_length = 0;
+    while reader.read_line(&mut header)? > 2 {
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+        header.clear();
+    }
+    io::copy(&mut reader.by_ref().take(content_length), &mut io::sink())?;
+
+    let target = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let url = Url::parse(&format!("http://mock{}", target))
+        .unwrap_or_else(|_| Url::parse("http://mock/").unwrap());
+    let (endpoint, query) = endpoint_and_query(&url);
+
+    let (status, body, latency, content_type) = {
+        let mut state = state.lock().unwrap();
+        state.requests.push((endpoint.clone(), query.clone()));
+        match state.rules.iter_mut().find(|rule| rule.matches(&endpoint, &query)) {
+            Some(rule) => {
+                if let Some(remaining) = rule.remaining.as_mut() {
+                    *remaining -= 1;
+                }
+                (rule.status, rule.body.clone(), rule.latency, rule.content_type.clone())
+            }
+            None => (
+                404,
+                google_error(404, "notFound", &format!("No mock response for {}", endpoint)),
+                Duration::ZERO,
+                JSON_CONTENT_TYPE.to_string(),
+            ),
+        }
+    };
+
+    thread::sleep(latency);
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status, status_text(status), content_type, body.len(), body
+    )?;
+    stream.flush()
+}
+
+/// Embedded HTTP server answering Data API requests with canned responses
+///
+/// While installed (`install()` or `with MockServer() as server:`), every API
+/// call goes to this server instead of googleapis.com, so retries, pagination
+/// and error paths can be exercised without network access or quota.
+#[pyclass]
+pub struct MockServer {
+    state: Arc<Mutex<MockState>>,
+    address: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+#[pymethods]
+impl MockServer {
+    #[new]
+    #[pyo3(signature = (port=0))]
+    fn new(port: u16) -> PyResult<Self> {
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to start mock server: {}", e)))?;
+        let address = listener.local_addr()
+            .map_err(|e| PyRuntimeError::new_err(format!("Failed to start mock server: {}", e)))?;
+
+        let state = Arc::new(Mutex::new(MockState::default()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let state = Arc::clone(&state);
+            let shutdown = Arc::clone(&shutdown);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let state = Arc::clone(&state);
+                        thread::spawn(move || {
+                            let _ = handle_connection(stream, &state);
+                        });
+                    }
+                }
+            })
+        };
+
+        Ok(MockServer { state, address, shutdown, handle: Some(handle) })
+    }
+
+    /// Base URL to use in place of https://www.googleapis.com/youtube/v3
+    #[getter]
+    fn url(&self) -> String {
+        format!("http://{}/youtube/v3", self.address)
+    }
+
+    /// Serve `body` (dict, list or JSON string) for an endpoint such as `"channels"`
+    ///
+    /// Rules are matched in the order they were added; `params` restricts a rule
+    /// to requests carrying those query parameters (e.g. `{"pageToken": "p2"}`)
+    /// and `times` limits how often it is served. `content_type` overrides the
+    /// JSON default, e.g. to serve an image.
+    #[pyo3(signature = (endpoint, body, status=200, params=None, latency_ms=0, times=None, content_type=JSON_CONTENT_TYPE))]
+    #[allow(clippy::too_many_arguments)]
+    fn add_response(
+        &self,
+        py: Python,
+        endpoint: String,
+        body: &PyAny,
+        status: u16,
+        params: Option<&PyDict>,
+        latency_ms: u64,
+        times: Option<u32>,
+        content_type: &str,
+    ) -> PyResult<()> {
+        let rule = MockRule {
+            endpoint: endpoint.trim_matches('/').to_string(),
+            params: match params {
+                Some(params) => params.iter()
+                    .map(|(name, value)| Ok((name.str()?.to_string(), value.str()?.to_string())))
+                    .collect::<PyResult<_>>()?,
+                None => Vec::new(),
+            },
+            status,
+            body: body_to_string(py, body)?,
+            latency: Duration::from_millis(latency_ms),
+            remaining: times,
+            exact: false,
+            content_type: content_type.to_string(),
+        };
+        self.state.lock().unwrap().rules.push(rule);
+        Ok(())
+    }
+
+    /// Fail the next `times` requests to an endpoint with a Google error payload
+    ///
+    /// Injected errors take priority over regular responses, e.g.
+    /// `inject_error("channels", 500)` or `inject_error("search", 403, "quotaExceeded")`.
+    #[pyo3(signature = (endpoint, status=500, reason="backendError", times=1, latency_ms=0))]
+    fn inject_error(&self, endpoint: String, status: u16, reason: &str, times: u32, latency_ms: u64) {
+        let rule = MockRule {
+            endpoint: endpoint.trim_matches('/').to_string(),
+            params: Vec::new(),
+            status,
+            body: google_error(status, reason, &format!("Injected {} error", reason)),
+            latency: Duration::from_millis(latency_ms),
+            remaining: Some(times),
+            exact: false,
+            content_type: JSON_CONTENT_TYPE.to_string(),
+        };
+        self.state.lock().unwrap().rules.insert(0, rule);
+    }
+
+    /// Serve every fixture in a directory written by `record_fixture`
+    ///
+    /// Each fixture answers only requests with exactly the recorded parameters,
+    /// so paginated recordings replay page by page. Returns the number loaded.
+    fn load_fixtures(&self, fixture_dir: &str) -> PyResult<usize> {
+        let mut paths: Vec<_> = fs::read_dir(fixture_dir)
+            .map_err(|e| PyValueError::new_err(format!("Cannot read fixture directory {}: {}", fixture_dir, e)))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .collect();
+        paths.sort();
+
+        let mut rules = Vec::with_capacity(paths.len());
+        for path in &paths {
+            let fixture: Fixture = fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|text| serde_json::from_str(&text).map_err(|e| e.to_string()))
+                .map_err(|e| PyValueError::new_err(format!("Invalid fixture {}: {}", path.display(), e)))?;
+            rules.push(MockRule {
+                endpoint: fixture.endpoint,
+                params: fixture.params.into_iter().collect(),
+                status: fixture.status,
+                body: fixture.body.to_string(),
+                latency: Duration::ZERO,
+                remaining: None,
+                exact: true,
+                content_type: JSON_CONTENT_TYPE.to_string(),
+            });
+        }
+        let loaded = rules.len();
+        self.state.lock().unwrap().rules.extend(rules);
+        Ok(loaded)
+    }
+
+    /// Requests received so far as `{"endpoint": ..., "params": {...}}`, keys redacted
+    fn requests(&self, py: Python) -> PyResult<PyObject> {
+        let state = self.state.lock().unwrap();
+        let requests = PyList::empty(py);
+        for (endpoint, query) in &state.requests {
+            let params = PyDict::new(py);
+            for (name, value) in query {
+                let value = if name == "key" { "***".to_string() } else { redact(value) };
+                params.set_item(name, value)?;
+            }
+            let request = PyDict::new(py);
+            request.set_item("endpoint", endpoint)?;
+            request.set_item("params", params)?;
+            requests.append(request)?;
+        }
+        Ok(requests.into())
+    }
+
+    /// Forget all responses and recorded requests
+    fn reset(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.rules.clear();
+        state.requests.clear();
+    }
+
+    /// Route all API calls to this server
+    ///
+    /// Only one server can be installed per process; installing a second one
+    /// raises RuntimeError instead of silently redirecting the first one's calls.
+    fn install(&self) -> PyResult<()> {
+        client::install_base_url_override(self.url()).map_err(|installed| {
+            PyRuntimeError::new_err(format!(
+                "Another MockServer is already installed at {}; uninstall it first or add the responses to that server",
+                installed
+            ))
+        })
+    }
+
+    /// Route API calls back to googleapis.com (no-op unless this server is installed)
+    fn uninstall(&self) {
+        client::uninstall_base_url_override(&self.url());
+    }
+
+    /// Stop accepting connections
+    fn stop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            self.shutdown.store(true, Ordering::SeqCst);
+            // Wake the accept loop so it sees the shutdown flag
+            let _ = TcpStream::connect(self.address);
+            let _ = handle.join();
+        }
+    }
+
+    fn __enter__(slf: PyRef<Self>) -> PyResult<PyRef<Self>> {
+        slf.install()?;
+        Ok(slf)
+    }
+
+    fn __exit__(&mut self, _exc_type: &PyAny, _exc_value: &PyAny, _traceback: &PyAny) {
+        self.uninstall();
+        self.stop();
+    }
+}
+
+// One recorded request/response pair as stored on disk
+#[derive(Serialize, Deserialize)]
+struct Fixture {
+    endpoint: String,
+    params: BTreeMap<String, String>,
+    status: u16,
+    body: serde_json::Value,
+}
+
+/// Run a real API call and save its responses as replayable fixtures
+///
+/// `function` is called with `args` (a tuple/list of positional arguments or a
+/// dict of keyword arguments) while every Data API response is captured. Each
+/// response is written to `out_dir` as `NNN_<endpoint>.json` with API keys and
+/// tokens redacted, ready for `MockServer.load_fixtures(out_dir)`.
+/// Calls made from other threads in the meantime are captured too.
+///
+/// # Arguments
+/// * `function` - Any youtube_stats function, e.g. `account.get_youtube_channel_stats`
+/// * `args` - Arguments for `function`
+/// * `out_dir` - Directory for the fixture files (created if missing)
+///
+/// # Returns
+/// * PyResult<Vec<String>> - Paths of the written fixture files
+#[pyfunction]
+pub fn record_fixture(py: Python, function: &PyAny, args: &PyAny, out_dir: &str) -> PyResult<Vec<String>> {
+    let kwargs = args.downcast::<PyDict>().ok();
+    let positional = match kwargs {
+        Some(_) => PyTuple::empty(py),
+        None => PyTuple::new(py, args.iter()?.collect::<PyResult<Vec<_>>>()?),
+    };
+    if !client::start_recording() {
+        return Err(PyRuntimeError::new_err(
+            "record_fixture is already running; fixtures are recorded process-wide, so record one call at a time",
+        ));
+    }
+    let result = function.call(positional, kwargs);
+    let recorded = client::stop_recording();
+    result?;
+
+    fs::create_dir_all(out_dir)
+        .map_err(|e| PyValueError::new_err(format!("Cannot create fixture directory {}: {}", out_dir, e)))?;
+    let mut written = Vec::with_capacity(recorded.len());
+    for (index, (url, response)) in recorded.iter().enumerate() {
+        let url = Url::parse(url)
+            .map_err(|e| PyValueError::new_err(format!("Invalid recorded URL: {}", e)))?;
+        let (endpoint, query) = endpoint_and_query(&url);
+        let body = redact(&response.body);
+        let fixture = Fixture {
+            params: query.into_iter()
+                .filter(|(name, _)| name != "key")
+                .map(|(name, value)| (name, redact(&value)))
+                .collect(),
+            status: response.status.as_u16(),
+            body: serde_json::from_str(&body).unwrap_or(serde_json::Value::String(body)),
+            endpoint,
+        };
+        let path = Path::new(out_dir).join(format!("{:03}_{}.json", index, fixture.endpoint.replace('/', "_")));
+        let text = serde_json::to_string_pretty(&fixture)
+            .map_err(|e| PyValueError::new_err(format!("Failed to serialize fixture: {}", e)))?;
+        fs::write(&path, text)
+            .map_err(|e| PyValueError::new_err(format!("Cannot write fixture {}: {}", path.display(), e)))?;
+        written.push(path.display().to_string());
+    }
+    Ok(written)
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
@@ -0,0 +1,59 @@
+use unicode_normalization::UnicodeNormalization;
+
+// Characters that only modify a preceding emoji (variation selectors, ZWJ,
+// keycap, tag sequences); dropped together with the emoji they belong to
+fn is_emoji_modifier(c: char) -> bool {
+    matches!(c as u32, 0xFE0E | 0xFE0F | 0x200D | 0x20E3 | 0xE0020..=0xE007F)
+}
+
+/// True for pictographic characters: emoji, flags, skin tones and dingbats
+pub(crate) fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F000..=0x1FAFF
+            | 0x2600..=0x27BF
+            | 0x2B50..=0x2B55
+            | 0x231A..=0x231B
+            | 0x23E9..=0x23FA
+            | 0x3030
+            | 0x303D
+            | 0x3297
+            | 0x3299
+    )
+}
+
+/// Remove emoji sequences, keeping ZWJ and selectors used by other scripts
+pub(crate) fn strip_emoji(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_emoji = false;
+    for c in text.chars() {
+        if is_emoji(c) {
+            in_emoji = true;
+        } else if in_emoji && is_emoji_modifier(c) {
+            // still part of the previous emoji sequence
+        } else {
+            in_emoji = false;
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+/// How titles and descriptions are post-processed before reaching Python
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct TextOptions {
+    pub normalize: bool,
+    pub strip_emoji: bool,
+}
+
+impl TextOptions {
+    pub fn new(normalize: bool, strip_emoji: bool) -> Self {
+        TextOptions { normalize, strip_emoji }
+    }
+
+    /// Apply NFC normalization and/or emoji stripping
+    pub fn apply(&self, text: &str) -> String {
+        let text = if self.strip_emoji { strip_emoji(text) } else { text.to_string() };
+        if self.normalize { text.nfc().collect() } else { text }
+    }
+}
@@ -0,0 +1,220 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::exceptions::PyValueError;
+use reqwest::blocking::Client;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::thread;
+use crate::account::{VideoStatus, MAX_RESULTS_PER_PAGE};
+use crate::auth::Credentials;
+use crate::client;
+use crate::metrics::{self, EngagementWeights};
+use crate::utils::redact;
+
+// videos?chart=mostPopular response structures
+#[derive(Debug, Deserialize)]
+struct TrendingListResponse {
+    #[serde(default)]
+    items: Vec<TrendingVideo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TrendingVideo {
+    id: String,
+    snippet: TrendingSnippet,
+    statistics: Option<TrendingStatistics>,
+    status: Option<VideoStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TrendingSnippet {
+    title: String,
+    #[serde(rename = "channelId")]
+    channel_id: String,
+    #[serde(rename = "channelTitle")]
+    channel_title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TrendingStatistics {
+    #[serde(rename = "viewCount")]
+    view_count: Option<String>,
+    #[serde(rename = "likeCount")]
+    like_count: Option<String>,
+    #[serde(rename = "commentCount")]
+    comment_count: Option<String>,
+}
+
+/// Most popular videos of one region (1 quota unit)
+fn fetch_trending(
+    client: &Client,
+    credentials: &Credentials,
+    region_code: &str,
+    category_id: Option<&str>,
+) -> Result<Vec<TrendingVideo>, String> {
+    let mut params = vec![
+        ("part", "snippet,statistics,status".to_string()),
+        ("chart", "mostPopular".to_string()),
+        ("regionCode", region_code.to_string()),
+        ("maxResults", MAX_RESULTS_PER_PAGE.to_string()),
+    ];
+    if let Some(category_id) = category_id {
+        params.push(("videoCategoryId", category_id.to_string()));
+    }
+    let url = Url::parse_with_params(&format!("{}/videos", client::BASE_URL), &params)
+        .map_err(|e| format!("Invalid trending URL: {}", e))?;
+    let response = client::send_get(client, credentials, url.as_str()).map_err(|e| e.to_string())?;
+    if !response.is_success() {
+        let reason = response.error().map(|error| error.message).unwrap_or_default();
+        return Err(format!("{} {}", response.status, reason).trim_end().to_string());
+    }
+    let data: TrendingListResponse = response.json()
+        .map_err(|e| format!("Failed to parse trending videos: {}", e))?;
+    Ok(data.items)
+}
+
+/// Compare the trending charts of several regions
+///
+/// All regions are fetched concurrently, 1 quota unit each. A region whose
+/// chart cannot be fetched (e.g. an unsupported region code) is reported
+/// under `errors` and left out of the comparison.
+///
+/// # Arguments
+/// * `region_codes` - ISO 3166-1 alpha-2 codes such as `["US", "GB", "DE"]`
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `category_id` - Restrict the charts to one video category (e.g. `"10"` for Music)
+/// * `engagement_rate_weights` - `(like_weight, comment_weight)` for
+///   `engagement_rate` (default: `(1.0, 1.0)`)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `regions` (code to ranked videos with
+///   `rank`, `video_id`, `title`, `channel_id`, `channel_title`, `view_count`,
+///   `engagement_rate` and the status fields of `video.get_video_stats`),
+///   `overlap` (video IDs trending in every region), `shared` (video ID to the
+///   regions it trends in, for videos in two or more), `exclusive` (code to the
+///   video IDs trending only there) and `errors` (code to error message)
+#[pyfunction]
+#[pyo3(signature = (region_codes, api_key=None, category_id=None, engagement_rate_weights=None))]
+pub fn compare_trending_regions(
+    py: Python,
+    region_codes: Vec<String>,
+    api_key: Option<Credentials>,
+    category_id: Option<String>,
+    engagement_rate_weights: Option<(f64, f64)>,
+) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let rate_weights = EngagementWeights::from_tuple(engagement_rate_weights, metrics::ENGAGEMENT_RATE_WEIGHTS)?;
+        let mut regions: Vec<String> = Vec::with_capacity(region_codes.len());
+        for code in region_codes {
+            let code = code.trim().to_ascii_uppercase();
+            if code.len() != 2 || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(PyValueError::new_err(format!("Invalid region code: {}", code)));
+            }
+            if !regions.contains(&code) {
+                regions.push(code);
+            }
+        }
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+
+        // Release the GIL so the worker threads can report errors through Python
+        let charts: Vec<Result<Vec<TrendingVideo>, String>> = py.allow_threads(|| {
+            thread::scope(|scope| {
+                let handles: Vec<_> = regions.iter()
+                    .map(|region| {
+                        let (client, api_key, category_id) = (&client, &api_key, category_id.as_deref());
+                        scope.spawn(move || client::track_requests(|| fetch_trending(client, api_key, region, category_id)))
+                    })
+                    .collect();
+                handles.into_iter()
+                    .map(|handle| match handle.join() {
+                        Ok((chart, requests)) => {
+                            // Requests ran on the worker; credit them to this call's _meta
+                            client::extend_tracked_requests(requests);
+                            chart
+                        }
+                        Err(_) => Err("worker thread panicked".to_string()),
+                    })
+                    .collect()
+            })
+        });
+
+        let mut errors: BTreeMap<String, String> = BTreeMap::new();
+        let mut fetched: Vec<(String, Vec<TrendingVideo>)> = Vec::new();
+        for (region, chart) in regions.iter().zip(charts) {
+            match chart {
+                Ok(videos) => fetched.push((region.clone(), videos)),
+                Err(error) => {
+                    errors.insert(region.clone(), redact(&error));
+                }
+            }
+        }
+
+        // Regions each video trends in, in the order the regions were requested
+        let mut video_regions: HashMap<&str, Vec<&str>> = HashMap::new();
+        for (region, videos) in &fetched {
+            for video in videos {
+                let entry = video_regions.entry(video.id.as_str()).or_default();
+                if !entry.contains(&region.as_str()) {
+                    entry.push(region);
+                }
+            }
+        }
+
+        Python::with_gil(|py| {
+            let py_regions = PyDict::new(py);
+            let shared = PyDict::new(py);
+            let exclusive = PyDict::new(py);
+            let mut overlap: Vec<&str> = Vec::new();
+            for (region, videos) in &fetched {
+                let chart = PyList::empty(py);
+                let mut region_exclusive = Vec::new();
+                for (rank, video) in videos.iter().enumerate() {
+                    let entry = PyDict::new(py);
+                    entry.set_item("rank", rank + 1)?;
+                    entry.set_item("video_id", &video.id)?;
+                    entry.set_item("title", &video.snippet.title)?;
+                    entry.set_item("channel_id", &video.snippet.channel_id)?;
+                    entry.set_item("channel_title", &video.snippet.channel_title)?;
+                    let views = video.statistics.as_ref()
+                        .and_then(|stats| stats.view_count.as_ref())
+                        .and_then(|views| views.parse::<u64>().ok());
+                    entry.set_item("view_count", views)?;
+                    let count = |value: Option<&String>| value.and_then(|v| v.parse::<u64>().ok());
+                    let stats = video.statistics.as_ref();
+                    let engagement_rate = metrics::engagement_rate(
+                        views,
+                        count(stats.and_then(|s| s.like_count.as_ref())),
+                        count(stats.and_then(|s| s.comment_count.as_ref())),
+                        rate_weights,
+                    );
+                    entry.set_item("engagement_rate", engagement_rate)?;
+                    VideoStatus::set_items(video.status.as_ref(), entry)?;
+                    chart.append(entry)?;
+
+                    let trending_in = &video_regions[video.id.as_str()];
+                    if trending_in.len() == 1 {
+                        region_exclusive.push(video.id.as_str());
+                    } else if !shared.contains(&video.id)? {
+                        shared.set_item(&video.id, trending_in)?;
+                        if trending_in.len() == fetched.len() {
+                            overlap.push(&video.id);
+                        }
+                    }
+                }
+                py_regions.set_item(region, chart)?;
+                exclusive.set_item(region, region_exclusive)?;
+            }
+
+            let result = PyDict::new(py);
+            result.set_item("regions", py_regions)?;
+            result.set_item("overlap", overlap)?;
+            result.set_item("shared", shared)?;
+            result.set_item("exclusive", exclusive)?;
+            result.set_item("errors", errors)?;
+            Ok(result.into())
+        })
+    })
+}
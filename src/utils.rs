@@ -0,0 +1,437 @@
+use pyo3::prelude::*;
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyDict, PyList};
+use reqwest::Url;
+use std::collections::HashMap;
+use crate::text::TextOptions;
+
+// Thumbnail variants from largest to smallest
+const THUMBNAIL_PREFERENCE: [&str; 5] = ["maxres", "standard", "high", "medium", "default"];
+
+// YouTube only shows chapters for descriptions listing at least this many
+const MIN_CHAPTERS: usize = 3;
+
+// UTM parameters in the order they are appended to share URLs
+const UTM_KEYS: [&str; 5] = ["utm_source", "utm_medium", "utm_campaign", "utm_term", "utm_content"];
+
+// Query/form parameters whose values must never reach error messages or logs
+const SECRET_PARAMS: [&str; 4] = ["key", "access_token", "refresh_token", "client_secret"];
+
+/// Replace the values of secret parameters (`key=`, `access_token=`, ...) with `***`
+pub(crate) fn redact(text: &str) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    let mut at_boundary = true;
+    while let Some(c) = rest.chars().next() {
+        if at_boundary {
+            let secret = SECRET_PARAMS.iter().copied()
+                .find(|name| rest.starts_with(name) && rest[name.len()..].starts_with('='));
+            if let Some(name) = secret {
+                redacted.push_str(name);
+                redacted.push_str("=***");
+                rest = &rest[name.len() + 1..];
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || matches!(c, '&' | '#' | '"' | '\'' | ')' | ','))
+                    .unwrap_or(rest.len());
+                rest = &rest[end..];
+                at_boundary = false;
+                continue;
+            }
+        }
+        redacted.push(c);
+        rest = &rest[c.len_utf8()..];
+        at_boundary = matches!(c, '?' | '&' | ' ' | '\n' | '"' | '\'' | '(');
+    }
+    redacted
+}
+
+/// Decode `%XX` escapes in a URL path segment
+pub(crate) fn percent_decode(segment: &str) -> String {
+    Url::parse(&format!("https://decode.invalid/?t={}", segment.replace('+', "%2B")))
+        .ok()
+        .and_then(|url| url.query_pairs().next().map(|(_, value)| value.into_owned()))
+        .unwrap_or_else(|| segment.to_string())
+}
+
+/// Readable name of a topicDetails category URL
+///
+/// `https://en.wikipedia.org/wiki/Hip_hop_music` becomes `Hip hop music`.
+pub(crate) fn topic_name(topic_url: &str) -> String {
+    let article = topic_url.rsplit('/').next().unwrap_or(topic_url);
+    percent_decode(article).replace('_', " ")
+}
+
+/// Split a channel's keywords string into individual keywords
+///
+/// YouTube stores keywords space-separated, with multi-word keywords in
+/// double quotes: `music "hip hop" rap` becomes `["music", "hip hop", "rap"]`.
+/// An unterminated quote runs to the end; empty and repeated keywords are dropped.
+pub(crate) fn parse_keywords(raw: &str) -> Vec<String> {
+    fn flush(current: &mut String, keywords: &mut Vec<String>) {
+        let keyword = current.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !keyword.is_empty() && !keywords.contains(&keyword) {
+            keywords.push(keyword);
+        }
+        current.clear();
+    }
+
+    let mut keywords = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in raw.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => flush(&mut current, &mut keywords),
+            c => current.push(c),
+        }
+    }
+    flush(&mut current, &mut keywords);
+    keywords
+}
+
+/// Seconds in an ISO 8601 duration as used by the API (`PT1H2M3S`, `P1DT2H`)
+///
+/// Year and month designators have no fixed length and are rejected.
+pub(crate) fn parse_duration(duration: &str) -> Option<u64> {
+    let rest = duration.strip_prefix('P')?;
+    let (date, time) = rest.split_once('T').unwrap_or((rest, ""));
+    let mut seconds = 0u64;
+    for (part, units) in [(date, &[('W', 604_800), ('D', 86_400)][..]), (time, &[('H', 3600), ('M', 60), ('S', 1)][..])] {
+        let mut number = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() || c == '.' {
+                number.push(c);
+                continue;
+            }
+            let (_, unit) = units.iter().find(|(designator, _)| *designator == c)?;
+            let value: f64 = number.parse().ok()?;
+            seconds += (value * *unit as f64) as u64;
+            number.clear();
+        }
+        if !number.is_empty() {
+            return None;
+        }
+    }
+    Some(seconds)
+}
+
+/// Convert an ISO 8601 duration as the API returns it into seconds
+///
+/// # Arguments
+/// * `duration` - Duration such as `PT1H2M3S`, `PT45S` or `P1DT2H`
+///
+/// # Returns
+/// * PyResult<u64> - Whole seconds; raises ValueError for malformed strings
+///   and for year or month designators, which have no fixed length
+#[pyfunction]
+#[pyo3(name = "parse_duration")]
+pub fn parse_iso_duration(duration: &str) -> PyResult<u64> {
+    parse_duration(duration.trim())
+        .ok_or_else(|| PyValueError::new_err(format!("Invalid ISO 8601 duration: {}", duration)))
+}
+
+/// True for strings shaped like a YouTube video ID (11 URL-safe base64 characters)
+pub(crate) fn is_valid_video_id(video_id: &str) -> bool {
+    video_id.len() == 11
+        && video_id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+// Path prefixes followed directly by a video ID
+const VIDEO_PATH_PREFIXES: [&str; 5] = ["embed", "shorts", "live", "v", "e"];
+
+/// Video ID referenced by a YouTube URL in any of its known forms
+///
+/// Handles `watch?v=`, `youtu.be/`, `/embed/`, `/shorts/`, `/live/`, `/v/`,
+/// `youtube-nocookie.com` and `attribution_link` URLs on every subdomain.
+pub(crate) fn video_id_from_url(url: &Url) -> Option<String> {
+    let host = url.host_str()?.to_ascii_lowercase();
+    let mut segments = url.path_segments()?.filter(|segment| !segment.is_empty());
+
+    let candidate = if host == "youtu.be" || host == "www.youtu.be" {
+        segments.next().map(str::to_string)
+    } else if host == "youtube.com" || host.ends_with(".youtube.com")
+        || host == "youtube-nocookie.com" || host.ends_with(".youtube-nocookie.com")
+    {
+        let query_id = url.query_pairs()
+            .find(|(name, _)| name == "v")
+            .map(|(_, value)| value.into_owned());
+        match segments.next() {
+            None => query_id,
+            Some("watch") => query_id.or_else(|| segments.next().map(str::to_string)),
+            Some("attribution_link") => url.query_pairs()
+                .find(|(name, _)| name == "u")
+                .and_then(|(_, target)| Url::parse("https://www.youtube.com").ok()?.join(&target).ok())
+                .and_then(|target| video_id_from_url(&target)),
+            Some(prefix) if VIDEO_PATH_PREFIXES.contains(&prefix) => segments.next().map(str::to_string),
+            Some(_) => None,
+        }
+    } else {
+        None
+    };
+    candidate.filter(|id| is_valid_video_id(id))
+}
+
+/// Video ID from a bare ID or any URL form `video_id_from_url` understands
+/// (`watch?v=`, `youtu.be/`, `shorts/`, `embed/`, ... with any extra parameters)
+pub(crate) fn parse_video_reference(input: &str) -> Option<String> {
+    let input = input.trim();
+    if is_valid_video_id(input) {
+        return Some(input.to_string());
+    }
+    let with_scheme = if input.contains("://") { input.to_string() } else { format!("https://{}", input) };
+    Url::parse(&with_scheme).ok().and_then(|url| video_id_from_url(&url))
+}
+
+/// Find every YouTube video referenced in a block of free text
+///
+/// # Arguments
+/// * `text` - Arbitrary text such as an email, spreadsheet export or chat log
+/// * `include_bare_ids` - Also accept standalone 11-character IDs; off by default
+///   because ordinary words of that length would match too
+///
+/// # Returns
+/// * Vec<String> - Video IDs in order of first appearance, without duplicates
+#[pyfunction]
+#[pyo3(signature = (text, include_bare_ids=false))]
+pub fn extract_video_ids(text: &str, include_bare_ids: bool) -> Vec<String> {
+    let mut video_ids: Vec<String> = Vec::new();
+    let tokens = text.split(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'' | '(' | ')' | '[' | ']' | '{' | '}' | '|' | ','));
+
+    for token in tokens {
+        let token = token.trim_end_matches(['.', ';', ':', '!', '?']);
+        if token.is_empty() {
+            continue;
+        }
+
+        let lower = token.to_ascii_lowercase();
+        let video_id = if lower.contains("youtu") {
+            let with_scheme = if lower.starts_with("http://") || lower.starts_with("https://") {
+                token.to_string()
+            } else {
+                format!("https://{}", token.trim_start_matches("//"))
+            };
+            Url::parse(&with_scheme).ok().and_then(|url| video_id_from_url(&url))
+        } else if include_bare_ids && is_valid_video_id(token) {
+            Some(token.to_string())
+        } else {
+            None
+        };
+
+        if let Some(video_id) = video_id {
+            if !video_ids.contains(&video_id) {
+                video_ids.push(video_id);
+            }
+        }
+    }
+    video_ids
+}
+
+/// Seconds in a description timestamp such as `4:05`, `04:05` or `1:02:03`
+fn timestamp_seconds(token: &str) -> Option<u64> {
+    let parts: Vec<&str> = token.split(':').collect();
+    if !(2..=3).contains(&parts.len()) || parts.iter().any(|part| part.is_empty() || !part.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    let mut seconds = 0u64;
+    for (index, part) in parts.iter().enumerate() {
+        let value: u64 = part.parse().ok()?;
+        // Every field after the first is zero-padded and below 60
+        if index > 0 && (part.len() != 2 || value >= 60) {
+            return None;
+        }
+        seconds = seconds * 60 + value;
+    }
+    Some(seconds)
+}
+
+/// Timestamp and title of a description line such as `00:00 Intro`,
+/// `[1:30] - Verse` or `Outro 3:12`
+fn chapter_line(line: &str) -> Option<(u64, String)> {
+    let line = line.trim().trim_start_matches(|c: char| matches!(c, '-' | '*' | '•' | '▶') || c.is_whitespace());
+    let strip = |token: &str| token.trim_matches(|c| matches!(c, '[' | ']' | '(' | ')')).to_string();
+    let (first, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let (seconds, title) = match timestamp_seconds(&strip(first)) {
+        Some(seconds) => (seconds, rest),
+        None => {
+            let (rest, last) = line.rsplit_once(char::is_whitespace)?;
+            (timestamp_seconds(&strip(last))?, rest)
+        }
+    };
+    let title = title.trim_matches(|c: char| matches!(c, '-' | '–' | '—' | ':' | '|') || c.is_whitespace());
+    if title.is_empty() {
+        return None;
+    }
+    Some((seconds, title.to_string()))
+}
+
+/// Chapters listed in a video description, following YouTube's own rules
+///
+/// The list must start at 0:00, run in ascending order and hold at least
+/// three entries; anything else yields no chapters. Timestamped lines after
+/// the list ends (comments like "best part at 2:10") are ignored.
+pub(crate) fn parse_chapters(description: &str) -> Vec<(u64, String)> {
+    let mut chapters: Vec<(u64, String)> = Vec::new();
+    for (seconds, title) in description.lines().filter_map(chapter_line) {
+        match chapters.last().map(|(start, _)| *start) {
+            None if seconds != 0 => continue,
+            Some(previous) if seconds <= previous => break,
+            _ => chapters.push((seconds, title)),
+        }
+    }
+    if chapters.len() < MIN_CHAPTERS {
+        chapters.clear();
+    }
+    chapters
+}
+
+/// Python list of `start_seconds` and `title` dicts
+pub(crate) fn chapters_to_py<'py>(py: Python<'py>, chapters: &[(u64, String)]) -> PyResult<&'py PyList> {
+    let results = PyList::empty(py);
+    for (start_seconds, title) in chapters {
+        let entry = PyDict::new(py);
+        entry.set_item("start_seconds", start_seconds)?;
+        entry.set_item("title", title)?;
+        results.append(entry)?;
+    }
+    Ok(results)
+}
+
+/// Extract chapters from a video description's timestamp list
+///
+/// # Arguments
+/// * `description` - Video description, e.g. from `video.get_video_stats`
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `start_seconds` and `title` dictionaries in
+///   order; empty unless the list starts at 0:00, ascends and has at least
+///   three entries, the same rules YouTube applies
+#[pyfunction]
+pub fn extract_chapters(py: Python, description: &str) -> PyResult<PyObject> {
+    Ok(chapters_to_py(py, &parse_chapters(description))?.into())
+}
+
+/// Build a share URL for a video with optional timestamp and UTM parameters
+///
+/// # Arguments
+/// * `video_id` - 11-character video ID
+/// * `utm` - UTM parameters, keyed with or without the `utm_` prefix
+///   (`source`, `medium`, `campaign`, `term`, `content`)
+/// * `t` - Start offset in seconds
+/// * `short` - Use the `youtu.be` short form instead of the watch URL
+///
+/// # Returns
+/// * PyResult<String> - Fully encoded share URL
+#[pyfunction]
+#[pyo3(signature = (video_id, utm=None, t=None, short=false))]
+pub fn build_share_url(
+    video_id: String,
+    utm: Option<HashMap<String, String>>,
+    t: Option<u32>,
+    short: bool,
+) -> PyResult<String> {
+    if !is_valid_video_id(&video_id) {
+        return Err(PyValueError::new_err(format!("Invalid video ID: {}", video_id)));
+    }
+
+    let mut utm_params: HashMap<String, String> = HashMap::new();
+    for (key, value) in utm.unwrap_or_default() {
+        let key = if key.starts_with("utm_") { key } else { format!("utm_{}", key) };
+        if !UTM_KEYS.contains(&key.as_str()) {
+            return Err(PyValueError::new_err(format!(
+                "Unknown UTM parameter '{}', expected one of: {}", key, UTM_KEYS.join(", ")
+            )));
+        }
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(PyValueError::new_err(format!("UTM parameter '{}' must not be empty", key)));
+        }
+        utm_params.insert(key, value);
+    }
+
+    let mut url = if short {
+        Url::parse(&format!("https://youtu.be/{}", video_id))
+    } else {
+        Url::parse("https://www.youtube.com/watch")
+    }
+    .map_err(|e| PyValueError::new_err(format!("Failed to build URL: {}", e)))?;
+
+    {
+        let mut query = url.query_pairs_mut();
+        if !short {
+            query.append_pair("v", &video_id);
+        }
+        if let Some(seconds) = t {
+            query.append_pair("t", &seconds.to_string());
+        }
+        for key in UTM_KEYS {
+            if let Some(value) = utm_params.get(key) {
+                query.append_pair(key, value);
+            }
+        }
+    }
+
+    let mut share_url = url.to_string();
+    // Url keeps a dangling '?' when nothing was appended
+    if share_url.ends_with('?') {
+        share_url.pop();
+    }
+    Ok(share_url)
+}
+
+/// Pick the highest-resolution thumbnail URL from a result
+///
+/// # Arguments
+/// * `result` - A channel or video result dict (or its `thumbnails` dict);
+///   thumbnail entries may be URL strings or dicts with a `url` key
+///
+/// # Returns
+/// * PyResult<Option<String>> - URL of the largest available variant, or None
+#[pyfunction]
+pub fn best_thumbnail_url(result: &PyDict) -> PyResult<Option<String>> {
+    let thumbnails: &PyDict = match result.get_item("thumbnails")? {
+        Some(nested) => nested.downcast()?,
+        None => result,
+    };
+
+    for name in THUMBNAIL_PREFERENCE {
+        if let Some(entry) = thumbnails.get_item(name)? {
+            if let Ok(url) = entry.extract::<String>() {
+                return Ok(Some(url));
+            }
+            if let Ok(entry) = entry.downcast::<PyDict>() {
+                if let Some(url) = entry.get_item("url")? {
+                    return Ok(Some(url.extract()?));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Redact API keys and tokens from a URL, error message or log line
+///
+/// # Arguments
+/// * `text` - Any string that may contain `key=...` style parameters
+///
+/// # Returns
+/// * String - The text with secret values replaced by `***`
+#[pyfunction]
+pub fn redact_secrets(text: &str) -> String {
+    redact(text)
+}
+
+/// Normalize a title or description for storage or export
+///
+/// # Arguments
+/// * `text` - Any text returned by the API
+/// * `normalize_unicode` - Apply NFC normalization (default: True)
+/// * `strip_emoji` - Remove emoji, including ZWJ sequences, flags and skin
+///   tones, for targets that cannot store them (default: False)
+///
+/// # Returns
+/// * String - The cleaned text
+#[pyfunction]
+#[pyo3(signature = (text, normalize_unicode=true, strip_emoji=false))]
+pub fn clean_text(text: &str, normalize_unicode: bool, strip_emoji: bool) -> String {
+    TextOptions::new(normalize_unicode, strip_emoji).apply(text)
+}
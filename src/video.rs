@@ -0,0 +1,726 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use pyo3::exceptions::PyValueError;
+use reqwest::blocking::Client;
+use reqwest::Url;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+use crate::account::{self, LiveStreamingDetails, PaidProductPlacementDetails, Thumbnails, TopicDetails, VideoContentDetails, VideoStatus, MAX_RESULTS_PER_PAGE};
+use crate::auth::Credentials;
+use crate::client;
+use crate::error;
+use crate::metrics::{self, EngagementWeights};
+use crate::utils;
+
+// Thumbnail qualities on i.ytimg.com, largest first; only the last three always exist
+const THUMBNAIL_QUALITIES: [&str; 5] = ["maxresdefault", "sddefault", "hqdefault", "mqdefault", "default"];
+
+// Parts requested for every video result
+const VIDEO_PARTS: &str = "snippet,statistics,contentDetails,status,liveStreamingDetails,topicDetails,paidProductPlacementDetails";
+
+// videos response structures
+#[derive(Debug, Deserialize)]
+struct VideoListResponse {
+    #[serde(default)]
+    items: Vec<Video>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Video {
+    id: String,
+    snippet: Option<VideoSnippet>,
+    statistics: Option<VideoStatistics>,
+    #[serde(rename = "contentDetails")]
+    content_details: Option<VideoContentDetails>,
+    status: Option<VideoStatus>,
+    #[serde(rename = "liveStreamingDetails")]
+    live_streaming_details: Option<LiveStreamingDetails>,
+    #[serde(rename = "topicDetails")]
+    topic_details: Option<TopicDetails>,
+    #[serde(rename = "paidProductPlacementDetails")]
+    paid_product_placement_details: Option<PaidProductPlacementDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoSnippet {
+    title: String,
+    description: Option<String>,
+    #[serde(rename = "channelId")]
+    channel_id: String,
+    #[serde(rename = "channelTitle")]
+    channel_title: Option<String>,
+    #[serde(rename = "publishedAt")]
+    published_at: String,
+    thumbnails: Option<Thumbnails>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(rename = "categoryId")]
+    category_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoStatistics {
+    #[serde(rename = "viewCount")]
+    view_count: Option<String>,
+    #[serde(rename = "likeCount")]
+    like_count: Option<String>,
+    #[serde(rename = "commentCount")]
+    comment_count: Option<String>,
+}
+
+// captions response structures
+#[derive(Debug, Deserialize)]
+struct CaptionListResponse {
+    #[serde(default)]
+    items: Vec<Caption>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Caption {
+    id: String,
+    snippet: CaptionSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+struct CaptionSnippet {
+    language: Option<String>,
+    name: Option<String>,
+    // "standard", "asr" (automatic speech recognition) or "forced"
+    #[serde(rename = "trackKind")]
+    track_kind: Option<String>,
+    #[serde(rename = "lastUpdated")]
+    last_updated: Option<String>,
+    #[serde(rename = "isCC")]
+    is_cc: Option<bool>,
+    #[serde(rename = "isDraft")]
+    is_draft: Option<bool>,
+    status: Option<String>,
+}
+
+// youtube.com/api/timedtext?fmt=json3 response structures
+#[derive(Debug, Deserialize)]
+struct TimedText {
+    #[serde(default)]
+    events: Vec<TimedTextEvent>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimedTextEvent {
+    #[serde(rename = "tStartMs", default)]
+    start_ms: u64,
+    #[serde(rename = "dDurationMs", default)]
+    duration_ms: u64,
+    // Absent on styling/window events that carry no text
+    #[serde(default)]
+    segs: Vec<TimedTextSegment>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TimedTextSegment {
+    #[serde(default)]
+    utf8: String,
+}
+
+// youtube.com/oembed response
+#[derive(Debug, Deserialize)]
+struct OEmbed {
+    title: Option<String>,
+    author_name: Option<String>,
+    author_url: Option<String>,
+    thumbnail_url: Option<String>,
+    thumbnail_width: Option<u32>,
+    thumbnail_height: Option<u32>,
+    html: Option<String>,
+    width: Option<u32>,
+    height: Option<u32>,
+}
+
+// Return YouTube Dislike votes response
+#[derive(Debug, Deserialize)]
+struct DislikeVotes {
+    dislikes: Option<u64>,
+}
+
+// videoCategories response structures
+#[derive(Debug, Deserialize)]
+struct VideoCategoryListResponse {
+    #[serde(default)]
+    items: Vec<VideoCategory>,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoCategory {
+    id: String,
+    snippet: VideoCategorySnippet,
+}
+
+#[derive(Debug, Deserialize)]
+struct VideoCategorySnippet {
+    title: String,
+}
+
+// Category names by ID; the list is fixed, so entries never expire
+static CATEGORY_NAMES: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+
+/// Names of the categories the videos belong to, looking up only unseen IDs (1 unit)
+///
+/// Names are a convenience on top of the IDs, so a failed lookup leaves them
+/// out instead of failing the whole call.
+fn category_names(client: &Client, credentials: &Credentials, videos: &[Video]) -> HashMap<String, String> {
+    let cache = CATEGORY_NAMES.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut unseen: Vec<&str> = Vec::new();
+    {
+        let cache = cache.lock().unwrap();
+        for category_id in videos.iter().filter_map(|video| video.snippet.as_ref()?.category_id.as_deref()) {
+            if !cache.contains_key(category_id) && !unseen.contains(&category_id) {
+                unseen.push(category_id);
+            }
+        }
+    }
+    if !unseen.is_empty() {
+        let url = format!("{}/videoCategories?part=snippet&id={}", client::BASE_URL, unseen.join(","));
+        let fetched = client::send_get(client, credentials, &url).ok()
+            .filter(|response| response.is_success())
+            .and_then(|response| response.json::<VideoCategoryListResponse>().ok());
+        if let Some(fetched) = fetched {
+            let mut cache = cache.lock().unwrap();
+            for category in fetched.items {
+                cache.insert(category.id, category.snippet.title);
+            }
+        }
+    }
+    cache.lock().unwrap().clone()
+}
+
+/// Videos the API returned for any number of IDs, 50 per request
+fn fetch_videos(client: &Client, credentials: &Credentials, video_ids: &[String]) -> PyResult<Vec<Video>> {
+    let mut videos = Vec::with_capacity(video_ids.len());
+    for batch in video_ids.chunks(MAX_RESULTS_PER_PAGE) {
+        let url = format!("{}/videos?part={}&id={}", client::BASE_URL, VIDEO_PARTS, batch.join(","));
+        let response = client::send_get(client, credentials, &url)?;
+        if !response.is_success() {
+            return Err(PyValueError::new_err(format!("Failed to fetch videos: {}", response.status)));
+        }
+        let page: VideoListResponse = response.json()
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse videos: {}", e)))?;
+        videos.extend(page.items);
+    }
+    Ok(videos)
+}
+
+/// Estimated dislikes per video ID from Return YouTube Dislike
+///
+/// The service extrapolates from its browser extension's users, so results are
+/// estimates; a video it cannot answer for maps to None.
+fn estimated_dislikes(videos: &[Video]) -> PyResult<HashMap<String, Option<u64>>> {
+    let client = client::build_public_client()?;
+    Ok(videos.iter()
+        .map(|video| {
+            let url = format!("{}/votes?videoId={}", client::DISLIKE_API_URL, video.id);
+            let dislikes = client::get_page(&client, &url)
+                .filter(|(status, _)| status.is_success())
+                .and_then(|(_, body)| serde_json::from_str::<DislikeVotes>(&body).ok())
+                .and_then(|votes| votes.dislikes);
+            (video.id.clone(), dislikes)
+        })
+        .collect())
+}
+
+/// Merge a third-party dislike estimate into a video dict
+fn set_estimated_dislikes(result: &PyDict, dislikes: Option<u64>) -> PyResult<()> {
+    result.set_item("estimated_dislikes", dislikes)?;
+    metrics::set_derived(result, "estimated_dislikes", dislikes, metrics::SOURCE_THIRD_PARTY)
+}
+
+/// Video ID from a bare ID or a watch, youtu.be, shorts or embed URL
+fn video_id_for(input: &str) -> PyResult<String> {
+    utils::parse_video_reference(input).ok_or_else(|| error::invalid_video_reference(input))
+}
+
+/// Short or regular, with how it was decided, per video ID
+///
+/// Only videos of three minutes or less cost a HEAD request to youtube.com.
+fn short_classes(credentials: &Credentials, videos: &[Video]) -> PyResult<HashMap<String, (Option<bool>, &'static str)>> {
+    let web_client = client::build_web_client(credentials)?;
+    Ok(videos.iter()
+        .map(|video| {
+            let duration = video.content_details.as_ref().and_then(VideoContentDetails::duration_seconds);
+            (video.id.clone(), account::classify_short(&web_client, &video.id, duration))
+        })
+        .collect())
+}
+
+fn video_to_py<'py>(
+    py: Python<'py>,
+    video: &Video,
+    categories: &HashMap<String, String>,
+    short: Option<(Option<bool>, &'static str)>,
+    rate_weights: EngagementWeights,
+) -> PyResult<&'py PyDict> {
+    let result = PyDict::new(py);
+    let snippet = video.snippet.as_ref();
+    result.set_item("video_id", &video.id)?;
+    result.set_item("title", snippet.map(|s| s.title.as_str()))?;
+    result.set_item("description", snippet.and_then(|s| s.description.as_deref()))?;
+    let chapters = snippet.and_then(|s| s.description.as_deref()).map(utils::parse_chapters).unwrap_or_default();
+    result.set_item("chapters", utils::chapters_to_py(py, &chapters)?)?;
+    result.set_item("channel_id", snippet.map(|s| s.channel_id.as_str()))?;
+    result.set_item("channel_title", snippet.and_then(|s| s.channel_title.as_deref()))?;
+    result.set_item("published_at", snippet.map(|s| s.published_at.as_str()))?;
+    result.set_item("tags", snippet.map(|s| s.tags.clone()).unwrap_or_default())?;
+    let category_id = snippet.and_then(|s| s.category_id.as_deref());
+    result.set_item("category_id", category_id)?;
+    result.set_item("category_name", category_id.and_then(|id| categories.get(id)))?;
+
+    // Counts are None when the owner hides them, rather than 0
+    let count = |value: Option<&String>| value.and_then(|v| v.parse::<u64>().ok());
+    let stats = video.statistics.as_ref();
+    result.set_item("view_count", count(stats.and_then(|s| s.view_count.as_ref())))?;
+    result.set_item("like_count", count(stats.and_then(|s| s.like_count.as_ref())))?;
+    result.set_item("comment_count", count(stats.and_then(|s| s.comment_count.as_ref())))?;
+    // The API leaves a count out when the uploader hides likes or turns comments off
+    result.set_item("likes_hidden", stats.map(|s| s.like_count.is_none()))?;
+    result.set_item("comments_disabled", stats.map(|s| s.comment_count.is_none()))?;
+    let engagement_rate = metrics::engagement_rate(
+        count(stats.and_then(|s| s.view_count.as_ref())),
+        count(stats.and_then(|s| s.like_count.as_ref())),
+        count(stats.and_then(|s| s.comment_count.as_ref())),
+        rate_weights,
+    );
+    result.set_item("engagement_rate", engagement_rate)?;
+    metrics::set_derived(result, "engagement_rate", engagement_rate, metrics::SOURCE_COMPUTED)?;
+
+    let content_details = video.content_details.as_ref();
+    VideoContentDetails::set_items(content_details, result)?;
+    metrics::set_derived(result, "duration_seconds", content_details.and_then(VideoContentDetails::duration_seconds), metrics::SOURCE_COMPUTED)?;
+    if let Some((is_short, short_detection)) = short {
+        result.set_item("is_short", is_short)?;
+        result.set_item("short_detection", short_detection)?;
+        metrics::set_derived(result, "is_short", is_short, metrics::SOURCE_COMPUTED)?;
+    }
+    VideoStatus::set_items(video.status.as_ref(), result)?;
+    result.set_item("availability", account::availability(video.status.as_ref()))?;
+    LiveStreamingDetails::set_items(video.live_streaming_details.as_ref(), result)?;
+    let topic_names = TopicDetails::set_items(video.topic_details.as_ref(), result)?;
+    metrics::set_derived(result, "topic_categories", topic_names, metrics::SOURCE_COMPUTED)?;
+    result.set_item("has_paid_product_placement", video.paid_product_placement_details.as_ref().and_then(|p| p.has_paid_product_placement))?;
+
+    match snippet.and_then(|s| s.thumbnails.as_ref()) {
+        Some(thumbnails) => {
+            result.set_item("thumbnails", thumbnails.to_py(py)?)?;
+            result.set_item("thumbnail_details", thumbnails.details_to_py(py)?)?;
+        }
+        None => {
+            result.set_item("thumbnails", PyDict::new(py))?;
+            result.set_item("thumbnail_details", PyDict::new(py))?;
+        }
+    }
+    result.set_item("video_url", format!("https://www.youtube.com/watch?v={}", video.id))?;
+    Ok(result)
+}
+
+/// Get statistics and metadata for a single video
+///
+/// Costs 1 quota unit, plus 1 the first time the process sees the video's
+/// category.
+///
+/// # Arguments
+/// * `video_id` - Video ID or watch, youtu.be, shorts or embed URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `include_dislikes` - Add `estimated_dislikes` from the third-party
+///   Return YouTube Dislike API, marked `third_party` under `derived`; None
+///   when the service has no estimate (default: False)
+/// * `engagement_rate_weights` - `(like_weight, comment_weight)` for
+///   `engagement_rate` (default: `(1.0, 1.0)`); ValueError when negative or
+///   not finite
+/// * `detect_shorts` - Add `is_short` and `short_detection` as `is_short`
+///   below decides them; videos of three minutes or less cost a HEAD request
+///   to youtube.com each (default: False)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `video_id`, `title`, `description`,
+///   `chapters` (`start_seconds` and `title` dicts from the description's
+///   timestamp list, as `utils.extract_chapters` returns), `channel_id`,
+///   `channel_title`, `published_at`, `tags`, `category_id`,
+///   `category_name` (e.g. `"Music"`), `view_count`,
+///   `like_count`, `comment_count` (None when hidden), `likes_hidden`,
+///   `comments_disabled` (True when the uploader hid likes or turned
+///   comments off, so a None count is not zero engagement), `engagement_rate`
+///   (`(likes + comments) / views` unless reweighted with
+///   `engagement_rate_weights`; None without views or when a count is
+///   hidden), `duration` (ISO 8601),
+///   `duration_seconds`, `definition` (`hd`/`sd`), `dimension` (`2d`/`3d`),
+///   `licensed_content`, `caption` (True when captions are available),
+///   `is_short` and `short_detection` (only with `detect_shorts`),
+///   `region_restriction` (`{"allowed": [...], "blocked": [...]}` with
+///   ISO 3166 country codes, either list None; None when unrestricted),
+///   `privacy_status`, `upload_status`, `license`, `embeddable`,
+///   `public_stats_viewable`, `made_for_kids`, `availability` (`public`,
+///   `unlisted`, `private` or `deleted`), `concurrent_viewers`,
+///   `scheduled_start_time`, `actual_start_time` and `active_live_chat_id`
+///   (all None unless the video is or was a live stream or premiere),
+///   `topic_categories` (readable names such as `"Hip hop music"`),
+///   `topic_category_urls` (the Wikipedia URLs they come from),
+///   `has_paid_product_placement` (True when the creator declared a
+///   sponsorship or paid promotion),
+///   `thumbnails`, `thumbnail_details` and `video_url`;
+///   raises ValueError when the video does not exist or is private and
+///   InvalidVideoReferenceError when the input is not a video ID or URL
+#[pyfunction]
+#[pyo3(signature = (video_id, api_key=None, include_dislikes=false, engagement_rate_weights=None, detect_shorts=false))]
+pub fn get_video_stats(
+    video_id: String,
+    api_key: Option<Credentials>,
+    include_dislikes: bool,
+    engagement_rate_weights: Option<(f64, f64)>,
+    detect_shorts: bool,
+) -> PyResult<PyObject> {
+    let video_id = video_id_for(&video_id)?;
+    let rate_weights = EngagementWeights::from_tuple(engagement_rate_weights, metrics::ENGAGEMENT_RATE_WEIGHTS)?;
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let video = fetch_videos(&client, &api_key, std::slice::from_ref(&video_id))?
+            .into_iter()
+            .next()
+            .ok_or_else(|| PyValueError::new_err(format!("Video not found: {}", video_id)))?;
+        let categories = category_names(&client, &api_key, std::slice::from_ref(&video));
+        let shorts = if detect_shorts { Some(short_classes(&api_key, std::slice::from_ref(&video))?) } else { None };
+        let dislikes = if include_dislikes { Some(estimated_dislikes(std::slice::from_ref(&video))?) } else { None };
+        Python::with_gil(|py| {
+            let result = video_to_py(py, &video, &categories, shorts.as_ref().map(|shorts| shorts[&video.id]), rate_weights)?;
+            if let Some(dislikes) = &dislikes {
+                set_estimated_dislikes(result, dislikes[&video.id])?;
+            }
+            Ok(result.into())
+        })
+    })
+}
+
+/// Get statistics for many videos, 50 IDs per request
+///
+/// Costs 1 quota unit per 50 distinct IDs, plus 1 for category names not
+/// looked up before; duplicates are fetched once.
+///
+/// # Arguments
+/// * `video_ids` - Video IDs or URLs, keyed in the result by ID
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+/// * `include_dislikes` - Add `estimated_dislikes` as `get_video_stats` does,
+///   one third-party request per video (default: False)
+/// * `engagement_rate_weights` - `(like_weight, comment_weight)` for
+///   `engagement_rate`, as in `get_video_stats` (default: `(1.0, 1.0)`)
+/// * `detect_shorts` - Add `is_short` and `short_detection` as
+///   `get_video_stats` does, one HEAD request per video of three minutes or
+///   less (default: False)
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `videos` (video ID to the same
+///   dictionary `get_video_stats` returns, in request order) and `not_found`
+///   (requested IDs the API returned nothing for: deleted, private or invalid)
+#[pyfunction]
+#[pyo3(signature = (video_ids, api_key=None, include_dislikes=false, engagement_rate_weights=None, detect_shorts=false))]
+pub fn get_videos_stats(
+    video_ids: Vec<String>,
+    api_key: Option<Credentials>,
+    include_dislikes: bool,
+    engagement_rate_weights: Option<(f64, f64)>,
+    detect_shorts: bool,
+) -> PyResult<PyObject> {
+    let video_ids = video_ids.iter().map(|video_id| video_id_for(video_id)).collect::<PyResult<Vec<String>>>()?;
+    let rate_weights = EngagementWeights::from_tuple(engagement_rate_weights, metrics::ENGAGEMENT_RATE_WEIGHTS)?;
+    client::with_meta(|| {
+        let api_key = Credentials::resolve(api_key)?;
+        let client = client::build_client(&api_key)?;
+        let mut unique_ids: Vec<String> = Vec::with_capacity(video_ids.len());
+        for video_id in video_ids {
+            if !unique_ids.contains(&video_id) {
+                unique_ids.push(video_id);
+            }
+        }
+        let fetched = fetch_videos(&client, &api_key, &unique_ids)?;
+        let categories = category_names(&client, &api_key, &fetched);
+        let shorts = if detect_shorts { Some(short_classes(&api_key, &fetched)?) } else { None };
+        let dislikes = if include_dislikes { Some(estimated_dislikes(&fetched)?) } else { None };
+
+        Python::with_gil(|py| {
+            let videos = PyDict::new(py);
+            let mut not_found: Vec<&str> = Vec::new();
+            for video_id in &unique_ids {
+                match fetched.iter().find(|video| &video.id == video_id) {
+                    Some(video) => {
+                        let entry = video_to_py(py, video, &categories, shorts.as_ref().map(|shorts| shorts[video_id]), rate_weights)?;
+                        if let Some(dislikes) = &dislikes {
+                            set_estimated_dislikes(entry, dislikes[video_id])?;
+                        }
+                        videos.set_item(video_id, entry)?;
+                    }
+                    None => not_found.push(video_id),
+                }
+            }
+            let result = PyDict::new(py);
+            result.set_item("videos", videos)?;
+            result.set_item("not_found", not_found)?;
+            Ok(result.into())
+        })
+    })
+}
+
+/// Download a video's thumbnail straight from i.ytimg.com
+///
+/// Costs no quota. When the requested quality does not exist (maxresdefault
+/// and sddefault are missing for many older or low-resolution uploads), the
+/// next smaller one is tried until one is found.
+///
+/// # Arguments
+/// * `video_id` - Video ID or watch, youtu.be, shorts or embed URL
+/// * `quality` - `"maxresdefault"`, `"sddefault"`, `"hqdefault"`,
+///   `"mqdefault"` or `"default"` (default: `"maxresdefault"`)
+/// * `dest` - File to save to, or an existing directory to save in as
+///   `<video_id>_<quality>.<ext>`; without it the bytes are returned
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `video_id`, `quality` (the one
+///   actually downloaded), `url`, `content_type`, `byte_count`, either
+///   `path` or `data`, and `_meta` (with no Data API requests, so a
+///   `quota_cost` of 0); raises ValueError when no quality exists
+#[pyfunction]
+#[pyo3(signature = (video_id, quality="maxresdefault", dest=None))]
+pub fn download_video_thumbnail(py: Python, video_id: String, quality: &str, dest: Option<PathBuf>) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let video_id = video_id_for(&video_id)?;
+        let start = THUMBNAIL_QUALITIES.iter().position(|q| *q == quality).ok_or_else(|| {
+            PyValueError::new_err(format!("Unknown thumbnail quality '{}'; use one of: {}", quality, THUMBNAIL_QUALITIES.join(", ")))
+        })?;
+        let client = client::build_public_client()?;
+
+        let mut found = None;
+        for quality in &THUMBNAIL_QUALITIES[start..] {
+            let url = format!("{}/vi/{}/{}.jpg", client::THUMBNAIL_URL, video_id, quality);
+            if let Some((content_type, bytes)) = client::download_if_exists(&client, &url)? {
+                found = Some((*quality, url, content_type, bytes));
+                break;
+            }
+        }
+        let (quality, url, content_type, bytes) = found
+            .ok_or_else(|| PyValueError::new_err(format!("No thumbnail found for video {}", video_id)))?;
+        let extension = account::image_extension(&content_type)
+            .ok_or_else(|| PyValueError::new_err(format!("Expected an image from {}, got '{}'", url, content_type)))?;
+
+        let result = PyDict::new(py);
+        result.set_item("video_id", &video_id)?;
+        result.set_item("quality", quality)?;
+        result.set_item("url", &url)?;
+        result.set_item("content_type", &content_type)?;
+        result.set_item("byte_count", bytes.len())?;
+        match dest {
+            Some(dest) => {
+                let path = if dest.is_dir() { dest.join(format!("{}_{}.{}", video_id, quality, extension)) } else { dest };
+                fs::write(&path, &bytes)
+                    .map_err(|e| PyValueError::new_err(format!("Cannot write {}: {}", path.display(), e)))?;
+                result.set_item("path", path.to_string_lossy())?;
+            }
+            None => result.set_item("data", PyBytes::new(py, &bytes))?,
+        }
+        Ok(result.into())
+    })
+}
+
+/// Whether a video is a YouTube Short
+///
+/// Videos longer than three minutes are never Shorts. Shorter ones are checked
+/// with a HEAD request to `youtube.com/shorts/<id>`, which serves Shorts and
+/// redirects regular videos; if that probe fails, videos up to one minute
+/// count as Shorts. Costs 1 quota unit for the duration lookup.
+///
+/// # Arguments
+/// * `video_id` - Video ID or watch, youtu.be, shorts or embed URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<Option<bool>> - None when neither the probe nor the duration
+///   settles it; raises ValueError when the video does not exist
+#[pyfunction]
+#[pyo3(signature = (video_id, api_key=None))]
+pub fn is_short(video_id: String, api_key: Option<Credentials>) -> PyResult<Option<bool>> {
+    let video_id = video_id_for(&video_id)?;
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+    let video = fetch_videos(&client, &api_key, std::slice::from_ref(&video_id))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| PyValueError::new_err(format!("Video not found: {}", video_id)))?;
+    let duration = video.content_details.as_ref().and_then(VideoContentDetails::duration_seconds);
+    Ok(account::classify_short(&client::build_web_client(&api_key)?, &video.id, duration).0)
+}
+
+/// List the caption tracks of a video
+///
+/// Costs 50 quota units. Draft tracks are only listed for the video's owner.
+///
+/// # Arguments
+/// * `video_id` - Video ID or watch, youtu.be, shorts or embed URL
+/// * `api_key` - YouTube Data API v3 key, credentials object or AuthConfig
+///   (default: the configured default, then YOUTUBE_API_KEY)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `caption_id`, `language` (BCP-47 code),
+///   `name`, `kind` (`standard`, `asr` or `forced`), `is_auto_generated`,
+///   `is_cc`, `is_draft`, `status` and `last_updated` dictionaries
+#[pyfunction]
+#[pyo3(signature = (video_id, api_key=None))]
+pub fn get_video_captions(video_id: String, api_key: Option<Credentials>) -> PyResult<PyObject> {
+    let video_id = video_id_for(&video_id)?;
+    let api_key = Credentials::resolve(api_key)?;
+    let client = client::build_client(&api_key)?;
+
+    let url = format!("{}/captions?part=snippet&videoId={}", client::BASE_URL, video_id);
+    let response = client::send_get(&client, &api_key, &url)?;
+    if response.status.as_u16() == 404 {
+        return Err(PyValueError::new_err(format!("Video not found: {}", video_id)));
+    }
+    if !response.is_success() {
+        return Err(PyValueError::new_err(format!("Failed to fetch captions: {}", response.status)));
+    }
+    let data: CaptionListResponse = response.json()
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse captions: {}", e)))?;
+
+    Python::with_gil(|py| {
+        let results = PyList::empty(py);
+        for caption in &data.items {
+            let snippet = &caption.snippet;
+            let kind = snippet.track_kind.as_deref().map(str::to_ascii_lowercase);
+            let entry = PyDict::new(py);
+            entry.set_item("caption_id", &caption.id)?;
+            entry.set_item("language", snippet.language.as_deref())?;
+            entry.set_item("name", snippet.name.as_deref())?;
+            entry.set_item("is_auto_generated", kind.as_deref().map(|kind| kind == "asr"))?;
+            entry.set_item("kind", kind)?;
+            entry.set_item("is_cc", snippet.is_cc)?;
+            entry.set_item("is_draft", snippet.is_draft)?;
+            entry.set_item("status", snippet.status.as_deref())?;
+            entry.set_item("last_updated", snippet.last_updated.as_deref())?;
+            results.append(entry)?;
+        }
+        Ok(results.into())
+    })
+}
+
+/// Transcript segments as `(start_seconds, duration_seconds, text)`; empty
+/// when the track does not exist
+fn fetch_transcript(client: &Client, video_id: &str, lang: &str, auto_generated: bool) -> Vec<(f64, f64, String)> {
+    let mut url = Url::parse(&format!("{}/api/timedtext", client::WEB_URL)).expect("WEB_URL is a valid URL");
+    {
+        let mut query = url.query_pairs_mut();
+        query.append_pair("v", video_id).append_pair("lang", lang);
+        if auto_generated {
+            query.append_pair("kind", "asr");
+        }
+        query.append_pair("fmt", "json3");
+    }
+    let timed_text = client::get_page(client, url.as_str())
+        .filter(|(status, body)| status.is_success() && !body.trim().is_empty())
+        .and_then(|(_, body)| serde_json::from_str::<TimedText>(&body).ok());
+    let mut segments = Vec::new();
+    for event in timed_text.map(|t| t.events).unwrap_or_default() {
+        let text: String = event.segs.iter().map(|seg| seg.utf8.as_str()).collect();
+        let text = text.replace('\n', " ");
+        let text = text.trim();
+        if !text.is_empty() {
+            segments.push((event.start_ms as f64 / 1000.0, event.duration_ms as f64 / 1000.0, text.to_string()));
+        }
+    }
+    segments
+}
+
+/// Fetch a video's transcript from youtube.com
+///
+/// Costs no quota. Uploaded captions in `lang` are preferred; without them the
+/// automatic (speech recognition) track is used.
+///
+/// # Arguments
+/// * `video_id` - Video ID or watch, youtu.be, shorts or embed URL
+/// * `lang` - Caption language code (default: `"en"`)
+///
+/// # Returns
+/// * PyResult<PyObject> - List of `start` and `duration` (seconds) and `text`
+///   dictionaries in playback order; raises ValueError when the video has no
+///   transcript in that language
+#[pyfunction]
+#[pyo3(signature = (video_id, lang="en"))]
+pub fn get_video_transcript(py: Python, video_id: String, lang: &str) -> PyResult<PyObject> {
+    let video_id = video_id_for(&video_id)?;
+    let client = client::build_public_client()?;
+    let mut segments = fetch_transcript(&client, &video_id, lang, false);
+    if segments.is_empty() {
+        segments = fetch_transcript(&client, &video_id, lang, true);
+    }
+    if segments.is_empty() {
+        return Err(PyValueError::new_err(format!("No transcript available for video {} in '{}'", video_id, lang)));
+    }
+
+    let results = PyList::empty(py);
+    for (start, duration, text) in segments {
+        let entry = PyDict::new(py);
+        entry.set_item("start", start)?;
+        entry.set_item("duration", duration)?;
+        entry.set_item("text", text)?;
+        results.append(entry)?;
+    }
+    Ok(results.into())
+}
+
+/// Look up basic video metadata through YouTube's oEmbed endpoint
+///
+/// Needs no API key and costs no quota, which makes it a cheap fallback for
+/// titles and thumbnails; statistics are not available this way.
+///
+/// # Arguments
+/// * `url` - Video ID or watch, youtu.be, shorts or embed URL
+///
+/// # Returns
+/// * PyResult<PyObject> - Dictionary with `video_id`, `title`, `author_name`,
+///   `author_url`, `thumbnail_url`, `thumbnail_width`, `thumbnail_height`,
+///   `html` (the embed iframe), `width`, `height` and `_meta` (with no Data
+///   API requests, so a `quota_cost` of 0); raises ValueError when the video
+///   does not exist, is private or cannot be embedded
+#[pyfunction]
+pub fn get_video_oembed(py: Python, url: String) -> PyResult<PyObject> {
+    client::with_meta(|| {
+        let video_id = video_id_for(&url)?;
+        let watch_url = format!("https://www.youtube.com/watch?v={}", video_id);
+        let oembed_url = Url::parse_with_params(&format!("{}/oembed", client::WEB_URL), &[("url", watch_url.as_str()), ("format", "json")])
+            .map_err(|e| PyValueError::new_err(format!("Invalid oEmbed URL: {}", e)))?;
+
+        let client = client::build_public_client()?;
+        let (status, body) = client::get_page(&client, oembed_url.as_str())
+            .ok_or_else(|| PyValueError::new_err(format!("Failed to reach the oEmbed endpoint for {}", video_id)))?;
+        match status.as_u16() {
+            // Private and embedding-disabled videos answer 401/403, missing ones 400/404
+            401 | 403 => return Err(PyValueError::new_err(format!("Video {} is private or cannot be embedded", video_id))),
+            400 | 404 => return Err(PyValueError::new_err(format!("Video not found: {}", video_id))),
+            _ if !status.is_success() => return Err(PyValueError::new_err(format!("Failed to fetch oEmbed data: {}", status))),
+            _ => {}
+        }
+        let oembed: OEmbed = serde_json::from_str(&body)
+            .map_err(|e| PyValueError::new_err(format!("Failed to parse oEmbed data: {}", e)))?;
+
+        let result = PyDict::new(py);
+        result.set_item("video_id", &video_id)?;
+        result.set_item("title", oembed.title)?;
+        result.set_item("author_name", oembed.author_name)?;
+        result.set_item("author_url", oembed.author_url)?;
+        result.set_item("thumbnail_url", oembed.thumbnail_url)?;
+        result.set_item("thumbnail_width", oembed.thumbnail_width)?;
+        result.set_item("thumbnail_height", oembed.thumbnail_height)?;
+        result.set_item("html", oembed.html)?;
+        result.set_item("width", oembed.width)?;
+        result.set_item("height", oembed.height)?;
+        Ok(result.into())
+    })
+}
@@ -1,7 +1,24 @@
+import contextlib
+import copy
+import io
+import json
 import os
 import pytest
+import threading
+import time
+import youtube_stats
 from youtube_stats import account
+from youtube_stats import export
 from youtube_stats import auth
+from youtube_stats import crawl
+from youtube_stats import memberships
+from youtube_stats import metadata
+from youtube_stats import metrics
+from youtube_stats import playlist
+from youtube_stats import testing
+from youtube_stats import trending
+from youtube_stats import utils
+from youtube_stats import video
 from dotenv import load_dotenv
 
 load_dotenv()
@@ -212,3 +229,2217 @@ def test_channel_without_recent_videos():
     print("Channel info retrieved successfully")
     print(f"Videos found: {len(stats['recent_videos'])}")
 
+
+
+def test_channel_metadata_roundtrip():
+    """Test attaching and clearing custom channel metadata."""
+    channel_id = "UCBJycsmduvYEL83R_U4JriQ"
+
+    metadata.set_channel_metadata(channel_id, {"artist_id": 42, "genre_tags": ["tech"]})
+    stored = metadata.get_channel_metadata(channel_id)
+
+    assert stored == {"artist_id": 42, "genre_tags": ["tech"]}
+
+    metadata.clear_channel_metadata(channel_id)
+    assert metadata.get_channel_metadata(channel_id) is None
+
+
+def test_channel_metadata_in_stats():
+    """Test that attached metadata is returned as a copy and merged into channel stats."""
+    channel_id = "UC_mock_channel_000000000"
+    metadata.set_channel_metadata(channel_id, {"artist_id": 7, "agent": "Sam"})
+    try:
+        stored = metadata.get_channel_metadata(channel_id)
+        stored["agent"] = "changed"
+        assert metadata.get_channel_metadata(channel_id) == {"artist_id": 7, "agent": "Sam"}
+
+        with testing.MockServer() as server:
+            server.add_response("channels", CHANNEL_FIXTURE)
+            server.add_response("playlistItems", {"items": []})
+
+            stats = account.get_youtube_channel_stats(channel_id, "mock_key", video_count=1)
+
+        assert stats["metadata"] == {"artist_id": 7, "agent": "Sam"}
+    finally:
+        metadata.clear_channel_metadata(channel_id)
+
+
+def test_key_pool_usage_report():
+    """Test that a key pool reports masked per-key usage."""
+    pool = auth.KeyPool(["first_key_123456", "second_key_123456"])
+
+    assert len(pool) == 2
+
+    usage = pool.usage()
+    assert [entry["key"] for entry in usage] == ["first_...", "second..."]
+    assert all(entry["requests"] == 0 for entry in usage)
+    assert not any(entry["exhausted"] for entry in usage)
+
+    with pytest.raises(ValueError):
+        auth.KeyPool([])
+
+
+def test_build_share_url():
+    """Test share URL construction with timestamp and UTM parameters."""
+    url = utils.build_share_url(
+        "dQw4w9WgXcQ",
+        {"source": "newsletter", "utm_campaign": "spring sale"},
+        t=42,
+    )
+    assert url == "https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42&utm_source=newsletter&utm_campaign=spring+sale"
+
+    assert utils.build_share_url("dQw4w9WgXcQ", short=True) == "https://youtu.be/dQw4w9WgXcQ"
+
+    with pytest.raises(ValueError):
+        utils.build_share_url("not-a-video-id")
+    with pytest.raises(ValueError):
+        utils.build_share_url("dQw4w9WgXcQ", {"utm_bogus": "x"})
+
+
+def test_validate_api_key():
+    """Test structured API key validation results."""
+    def error(code, reason, message):
+        return {"error": {"code": code, "message": message,
+                          "errors": [{"message": message, "domain": "global", "reason": reason}]}}
+
+    with testing.MockServer() as server:
+        server.add_response("i18nLanguages", {"items": []}, params={"key": "good_key"})
+        server.add_response("i18nLanguages", error(403, "quotaExceeded", "You have exceeded your quota."),
+                            status=403, params={"key": "spent_key"})
+        server.add_response("i18nLanguages", error(403, "forbidden", "Requests from referer <empty> are blocked."),
+                            status=403, params={"key": "restricted_key"})
+        server.add_response("i18nLanguages", error(400, "badRequest", "API key not valid. Please pass a valid API key."),
+                            status=400)
+
+        results = {key: auth.validate_api_key(key) for key in ["good_key", "spent_key", "restricted_key", "invalid_api_key_12345"]}
+
+    assert results["good_key"] == {"valid": True, "status": "valid", "reason": None, "message": None}
+    assert results["spent_key"]["status"] == "quota_exceeded"
+    assert results["spent_key"]["reason"] == "quotaExceeded"
+    assert results["restricted_key"]["status"] == "restricted"
+    assert results["restricted_key"]["message"] == "Requests from referer <empty> are blocked."
+    invalid = results["invalid_api_key_12345"]
+    assert (invalid["valid"], invalid["status"], invalid["reason"]) == (False, "invalid", "badRequest")
+    assert invalid["message"].startswith("API key not valid")
+
+
+def test_auth_config_from_dict():
+    """Test assembling an AuthConfig from a dictionary."""
+    config = auth.AuthConfig.from_dict({"api_key": "key_123", "region": "US", "timeout": 5.0})
+
+    assert config.has_credentials
+    assert config.region == "US"
+    assert config.timeout == 5.0
+
+    auth.set_default_config(config)
+    assert auth.get_default_config().region == "US"
+    auth.set_default_config(None)
+    assert auth.get_default_config() is None
+
+    with pytest.raises(ValueError):
+        auth.AuthConfig.from_dict({"timeout": "soon"})
+
+
+DEVICE_CODE_FIXTURE = {
+    "device_code": "mock_device_code", "user_code": "ABCD-EFGH",
+    "verification_url": "https://www.google.com/device", "expires_in": 30, "interval": 0,
+}
+
+
+def test_device_login_polling():
+    """Test that device login keeps polling while pending and backs off on slow_down."""
+    with testing.MockServer() as server:
+        server.add_response("device/code", DEVICE_CODE_FIXTURE)
+        server.add_response("token", {"error": "authorization_pending"}, status=428, times=2)
+        server.add_response("token", {"error": "slow_down"}, status=403, times=1)
+        server.add_response("token", {"access_token": "mock_access", "refresh_token": "mock_refresh", "expires_in": 3600})
+
+        # The wait must not hold the GIL: a Python thread keeps ticking meanwhile
+        ticks, done = [], threading.Event()
+
+        def tick():
+            while not done.is_set():
+                ticks.append(time.monotonic())
+                time.sleep(0.05)
+
+        ticker = threading.Thread(target=tick)
+        ticker.start()
+        stdout = io.StringIO()
+        start = time.monotonic()
+        try:
+            with contextlib.redirect_stdout(stdout):
+                credentials = auth.device_login("mock_client_id", "mock_client_secret")
+        finally:
+            elapsed = time.monotonic() - start
+            done.set()
+            ticker.join()
+
+        assert DEVICE_CODE_FIXTURE["user_code"] in stdout.getvalue()
+        assert len(ticks) > 20
+        assert credentials.token() == "mock_access"
+        assert credentials.refresh_token == "mock_refresh"
+        assert [r["endpoint"] for r in server.requests()] == ["device/code"] + ["token"] * 4
+    # slow_down adds 5 seconds to the polling interval
+    assert elapsed >= 5
+
+
+def test_device_login_expiry_and_denial():
+    """Test that device login gives up when the code expires or is denied."""
+    with testing.MockServer() as server:
+        server.add_response("device/code", dict(DEVICE_CODE_FIXTURE, expires_in=1, interval=1))
+        server.add_response("token", {"error": "authorization_pending"}, status=428)
+        with pytest.raises(ValueError, match="expired"):
+            auth.device_login("mock_client_id")
+
+        server.reset()
+        server.add_response("device/code", DEVICE_CODE_FIXTURE)
+        server.add_response("token", {"error": "access_denied", "error_description": "User declined"}, status=403)
+        with pytest.raises(ValueError, match="access_denied"):
+            auth.device_login("mock_client_id")
+
+
+def test_best_thumbnail_url():
+    """Test picking the largest available thumbnail."""
+    result = {
+        "thumbnails": {
+            "default": "https://i.ytimg.com/default.jpg",
+            "high": "https://i.ytimg.com/high.jpg",
+            "standard": {"url": "https://i.ytimg.com/standard.jpg", "width": 640, "height": 480},
+        }
+    }
+    assert utils.best_thumbnail_url(result) == "https://i.ytimg.com/standard.jpg"
+    assert utils.best_thumbnail_url({"medium": "https://i.ytimg.com/medium.jpg"}) == "https://i.ytimg.com/medium.jpg"
+    assert utils.best_thumbnail_url({}) is None
+
+
+def test_diff_crawls(tmp_path):
+    """Test per-channel deltas between two NDJSON crawl runs."""
+    run_a = tmp_path / "run_a.ndjson"
+    run_b = tmp_path / "run_b.ndjson"
+    run_a.write_text("\n".join(json.dumps(row) for row in [
+        {"channel_id": "UC_a", "subscriber_count": 100, "total_view_count": 1000, "video_count": 10},
+        {"channel_id": "UC_b", "subscriber_count": 50, "total_view_count": 500, "video_count": 5},
+        {"channel_id": "UC_gone", "subscriber_count": 1, "total_view_count": 1, "video_count": 1},
+    ]))
+    run_b.write_text("\n".join(json.dumps(row) for row in [
+        {"channel_id": "UC_a", "subscriber_count": 120, "total_view_count": 1500, "video_count": 11},
+        {"channel_id": "UC_b", "subscriber_count": 50, "total_view_count": 500, "video_count": 5},
+        {"channel_id": "UC_new", "subscriber_count": 7, "total_view_count": 70, "video_count": 1},
+    ]))
+
+    diff = crawl.diff_crawls(str(run_a), str(run_b))
+
+    assert len(diff["changed"]) == 1
+    assert diff["changed"][0]["channel_id"] == "UC_a"
+    assert diff["changed"][0]["subscriber_count"]["delta"] == 20
+    assert diff["new_channels"] == ["UC_new"]
+    assert diff["disappeared_channels"] == ["UC_gone"]
+    assert diff["unchanged_count"] == 1
+
+
+def test_get_youtube_api_keys(monkeypatch):
+    """Test parsing the plural YOUTUBE_API_KEYS variable."""
+    monkeypatch.setenv("YOUTUBE_API_KEYS", " key_one, key_two,,key_one ")
+    assert auth.get_youtube_api_keys() == ["key_one", "key_two"]
+
+    monkeypatch.delenv("YOUTUBE_API_KEYS")
+    monkeypatch.setenv("YOUTUBE_API_KEY", "single_key")
+    assert auth.get_youtube_api_keys() == ["single_key"]
+
+
+def test_load_env_custom_path(tmp_path, monkeypatch):
+    """Test loading configuration from a non-standard dotenv file."""
+    env_file = tmp_path / ".env.production"
+    env_file.write_text("YOUTUBE_STATS_TEST_VALUE=from_file\n")
+
+    # os.environ is snapshotted at startup, so only check that loading succeeds
+    assert auth.load_env(str(env_file)) is None
+
+    monkeypatch.setenv("YOUTUBE_STATS_ENV_FILE", str(env_file))
+    assert auth.load_env() is None
+
+    with pytest.raises(ValueError):
+        auth.load_env(str(tmp_path / "missing.env"))
+
+
+def test_call_youtube_client():
+    """Test the generic endpoint caller."""
+    youtube_api_key = os.environ.get("YOUTUBE_API_KEY")
+    if not youtube_api_key:
+        pytest.skip("YOUTUBE_API_KEY environment variable not set")
+
+    response = auth.call_youtube_client(
+        "channels",
+        {"part": ["snippet", "statistics"], "id": "UCBJycsmduvYEL83R_U4JriQ"},
+        api_key=youtube_api_key,
+    )
+
+    assert response["items"][0]["id"] == "UCBJycsmduvYEL83R_U4JriQ"
+    assert "statistics" in response["items"][0]
+
+
+def test_validate_result():
+    """Test consistency warnings on a channel stats result."""
+    result = {
+        "total_view_count": 100,
+        "total_recent_views": 150,
+        "video_count": 1,
+        "published_at": "2999-01-01T00:00:00Z",
+        "recent_videos": [
+            {"video_id": "a", "view_count": 150, "published_at": "2020-01-01T00:00:00Z"},
+            {"video_id": "b", "published_at": "not a date"},
+        ],
+    }
+
+    checks = {warning["check"] for warning in account.validate_result(result)}
+
+    assert checks == {
+        "recent_views_exceed_total",
+        "video_count_too_low",
+        "future_timestamp",
+        "video_views_exceed_total",
+        "invalid_timestamp",
+    }
+    assert account.validate_result({"total_view_count": 10, "recent_videos": []}) == []
+
+
+def test_engagement_score():
+    """Test the standard weighted engagement score."""
+    assert metrics.engagement_score(1000, 50, 10) == pytest.approx(0.07)
+    assert metrics.engagement_score(1000, 50, 10, like_weight=1.0, comment_weight=1.0) == pytest.approx(0.06)
+    assert metrics.engagement_score(0, 5, 5) is None
+
+    videos = [
+        {"view_count": 1000, "like_count": 50, "comment_count": 10},
+        {"view_count": 9000, "like_count": 100},
+    ]
+    # Disabled comments leave the second video out instead of counting as zero
+    assert metrics.channel_engagement_score(videos) == pytest.approx(0.07)
+
+    for weights in ({"like_weight": -1.0}, {"comment_weight": float("nan")}, {"like_weight": float("inf")}):
+        with pytest.raises(ValueError, match="finite and non-negative"):
+            metrics.engagement_score(1000, 50, 10, **weights)
+        with pytest.raises(ValueError, match="finite and non-negative"):
+            metrics.channel_engagement_score(videos, **weights)
+    with pytest.raises(ValueError, match="finite and non-negative"):
+        account.compare_channels(["UC_mock_channel_000000000"], "mock_key", engagement_weights=(1.0, -2.0))
+
+
+def test_redact_secrets():
+    """Test that key and token values are masked."""
+    url = "https://www.googleapis.com/youtube/v3/channels?part=snippet&key=AIzaSECRET123&id=UC_x"
+    assert utils.redact_secrets(url) == "https://www.googleapis.com/youtube/v3/channels?part=snippet&key=***&id=UC_x"
+    assert utils.redact_secrets("refresh_token=1//abc&client_secret=shh") == "refresh_token=***&client_secret=***"
+    assert utils.redact_secrets("monkey=banana") == "monkey=banana"
+
+
+def test_api_key_not_in_error_messages():
+    """Test that a failing request never echoes the API key."""
+    secret = "AIzaLEAKCHECK0000000000000000000000000"
+    config = auth.AuthConfig(api_key=secret, proxy="http://127.0.0.1:9", timeout=2.0)
+
+    with pytest.raises(ValueError) as excinfo:
+        auth.call_youtube_client("channels", {"part": "id", "id": "UC_x"}, api_key=config)
+    assert secret not in str(excinfo.value)
+
+    with pytest.raises(ValueError) as excinfo:
+        account.search_youtube_channels("music", api_key=config, max_results=1)
+    assert secret not in str(excinfo.value)
+
+
+def test_clean_text():
+    """Test NFC normalization, emoji stripping and astral-plane round-tripping."""
+    decomposed = "Café"
+    assert utils.clean_text(decomposed) == "Café"
+    assert utils.clean_text(decomposed, normalize_unicode=False) == decomposed
+
+    title = "New single \U0001F3B5\U0001F525 out now \U0001F468‍\U0001F469‍\U0001F467 \U0001F1FA\U0001F1F8"
+    assert utils.clean_text(title, strip_emoji=True) == "New single  out now  "
+
+    # RTL text, ZWJ in Indic scripts and non-emoji astral characters are kept intact
+    for text in ["שלום مرحبا", "क्‍ष", "\U0001D11E \U00020BB7"]:
+        assert utils.clean_text(text, normalize_unicode=False, strip_emoji=True) == text
+
+
+def test_configure_defaults():
+    """Test that configure() merges settings into the default config."""
+    try:
+        config = youtube_stats.configure(api_key="key_123", timeout=5.0, retries=2)
+        assert config.has_credentials
+        assert config.retries == 2
+
+        config = youtube_stats.configure(cache_ttl=60.0)
+        assert config.has_credentials
+        assert config.timeout == 5.0
+        assert config.retries == 2
+        assert config.cache_ttl == 60.0
+        assert auth.get_default_config().cache_ttl == 60.0
+    finally:
+        auth.set_default_config(None)
+        youtube_stats.clear_cache()
+
+
+def test_validate_api_key_bypasses_cache():
+    """Test that key validation reaches the API even with the response cache on."""
+    invalid = {"error": {"code": 400, "message": "API key not valid. Please pass a valid API key.",
+                         "errors": [{"message": "API key not valid.", "domain": "global", "reason": "badRequest"}]}}
+    try:
+        youtube_stats.configure(cache_ttl=60.0)
+        with testing.MockServer() as server:
+            server.add_response("i18nLanguages", {"items": []}, params={"key": "good_key"})
+            server.add_response("i18nLanguages", invalid, status=400)
+
+            good = auth.validate_api_key("good_key")
+            bad = auth.validate_api_key("bad_key")
+            assert len(server.requests()) == 2
+    finally:
+        auth.set_default_config(None)
+        youtube_stats.clear_cache()
+
+    assert (good["valid"], good["status"]) == (True, "valid")
+    assert (bad["valid"], bad["status"]) == (False, "invalid")
+
+
+def test_config_rejects_invalid_durations():
+    """Test that bad timeout and cache_ttl values raise ValueError instead of panicking."""
+    for bad in [-1.0, 0.0, float("nan"), float("inf")]:
+        with pytest.raises(ValueError, match="timeout"):
+            auth.AuthConfig(api_key="key_123", timeout=bad)
+        with pytest.raises(ValueError):
+            auth.AuthConfig.from_dict({"api_key": "key_123", "timeout": bad})
+    for bad in [-1.0, float("nan"), float("inf")]:
+        with pytest.raises(ValueError, match="cache_ttl"):
+            auth.AuthConfig(api_key="key_123", cache_ttl=bad)
+    try:
+        with pytest.raises(ValueError, match="cache_ttl"):
+            youtube_stats.configure(api_key="key_123", cache_ttl=float("inf"))
+        assert auth.get_default_config() is None
+    finally:
+        auth.set_default_config(None)
+
+    assert auth.AuthConfig(api_key="key_123", cache_ttl=0.0).cache_ttl == 0.0
+
+
+def test_extract_video_ids():
+    """Test pulling video IDs out of free text."""
+    text = """
+    New drop: https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42s, also youtu.be/9bZkp7q19f0.
+    Short <https://youtube.com/shorts/kJQP7kiw5Fk?feature=share> and embed
+    "https://www.youtube-nocookie.com/embed/JGwWNGJdvx8" plus m.youtube.com/watch?v=dQw4w9WgXcQ again.
+    Not a video: https://www.youtube.com/@SomeChannel or https://example.com/watch?v=aaaaaaaaaaa
+    """
+    assert utils.extract_video_ids(text) == ["dQw4w9WgXcQ", "9bZkp7q19f0", "kJQP7kiw5Fk", "JGwWNGJdvx8"]
+
+    assert utils.extract_video_ids("ids: dQw4w9WgXcQ, 9bZkp7q19f0") == []
+    assert utils.extract_video_ids("ids: dQw4w9WgXcQ, 9bZkp7q19f0", include_bare_ids=True) == ["dQw4w9WgXcQ", "9bZkp7q19f0"]
+
+
+def test_extract_chapters():
+    """Test chapter detection in description timestamp lists."""
+    description = """New single out now!
+
+Tracklist:
+00:00 Intro
+[0:45] - Verse one
+1:30 | Chorus
+Outro 03:12
+1:02:03 Bonus live set
+
+Best part is at 1:31 honestly
+"""
+    assert utils.extract_chapters(description) == [
+        {"start_seconds": 0, "title": "Intro"},
+        {"start_seconds": 45, "title": "Verse one"},
+        {"start_seconds": 90, "title": "Chorus"},
+        {"start_seconds": 192, "title": "Outro"},
+        {"start_seconds": 3723, "title": "Bonus live set"},
+    ]
+
+    # YouTube requires the list to start at 0:00 and hold three entries
+    assert utils.extract_chapters("0:10 Intro\n0:45 Verse\n1:30 Chorus") == []
+    assert utils.extract_chapters("0:00 Intro\n0:45 Verse") == []
+    assert utils.extract_chapters("") == []
+
+
+def test_parse_duration():
+    """Test ISO 8601 duration parsing."""
+    assert utils.parse_duration("PT1H2M3S") == 3723
+    assert utils.parse_duration("PT45S") == 45
+    assert utils.parse_duration("P1DT2H") == 93600
+    assert utils.parse_duration("P0D") == 0
+    for invalid in ["", "1H2M", "PT1X", "P1Y"]:
+        with pytest.raises(ValueError):
+            utils.parse_duration(invalid)
+
+
+def test_era_comparison():
+    """Test per-year upload buckets for a channel."""
+    uploads = [("aaaaaaaaaaa", "2021-03-01T00:00:00Z", 900), ("bbbbbbbbbbb", "2019-06-01T00:00:00Z", 100),
+               ("ccccccccccc", "2019-11-01T00:00:00Z", 300), ("ddddddddddd", None, 5000)]
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [
+            {"contentDetails": {"videoId": video_id, **({"videoPublishedAt": published} if published else {})}}
+            for video_id, published, _ in uploads
+        ]})
+        server.add_response("videos", {"items": [{"id": video_id, "statistics": {"viewCount": str(views)}}
+                                                 for video_id, _, views in uploads]})
+
+        result = account.era_comparison("UC_mock_channel_000000000", "mock_key")
+
+    # The upload without a publish date (private or deleted) is left out
+    assert [bucket["year"] for bucket in result["years"]] == [2019, 2021]
+    assert result["total_uploads"] == 3
+    assert result["first_year"] == {"year": 2019, "upload_count": 2, "total_views": 400, "average_views": 200.0}
+    assert result["latest_year"] == {"year": 2021, "upload_count": 1, "total_views": 900, "average_views": 900.0}
+    assert result["average_views_ratio"] == pytest.approx(4.5)
+
+
+def test_get_youtube_channels_stats():
+    """Test batched channel stats keyed by channel ID."""
+    youtube_api_key = os.environ.get("YOUTUBE_API_KEY")
+    if not youtube_api_key:
+        pytest.skip("YOUTUBE_API_KEY environment variable not set")
+
+    channel_ids = ["UCBJycsmduvYEL83R_U4JriQ", "UCX6OQ3DkcsbYNE6H8uQQuVA", "UCBJycsmduvYEL83R_U4JriQ"]
+    results = account.get_youtube_channels_stats(channel_ids, youtube_api_key)
+
+    assert list(results) == ["UCBJycsmduvYEL83R_U4JriQ", "UCX6OQ3DkcsbYNE6H8uQQuVA"]
+    assert results["UCBJycsmduvYEL83R_U4JriQ"]["channel_id"] == "UCBJycsmduvYEL83R_U4JriQ"
+    assert "recent_videos" not in results["UCBJycsmduvYEL83R_U4JriQ"]
+
+    by_handle = account.get_youtube_channels_stats(["@mkbhd"], youtube_api_key)
+    assert by_handle["@mkbhd"]["channel_id"] == "UCBJycsmduvYEL83R_U4JriQ"
+    assert by_handle["@mkbhd"]["handle"] == "@mkbhd"
+
+    with pytest.raises(ValueError):
+        account.get_youtube_channels_stats(["mkbhd"], youtube_api_key)
+
+
+def test_playlist_channel_breakdown():
+    """Test per-channel contribution counts for a playlist."""
+    def item(video_id, owner=None, title=None):
+        snippet = {"title": video_id}
+        if owner:
+            snippet.update(videoOwnerChannelId=owner, videoOwnerChannelTitle=title)
+        return {"snippet": snippet, "contentDetails": {"videoId": video_id}}
+
+    with testing.MockServer() as server:
+        # A compilation: the guest appears first but the host owns more items; one item is private
+        server.add_response("playlistItems", {"items": [
+            item("aaaaaaaaaaa", "UC_guest", "Guest"),
+            item("bbbbbbbbbbb", "UC_mock_channel_000000000", "Mock Artist"),
+            item("ccccccccccc", "UC_mock_channel_000000000", "Mock Artist"),
+            item("ddddddddddd"),
+        ]}, params={"playlistId": "PLmock"})
+        server.add_response("videos", {"items": [
+            {"id": "aaaaaaaaaaa", "statistics": {"viewCount": "600"}},
+            {"id": "bbbbbbbbbbb", "statistics": {"viewCount": "300"}},
+            {"id": "ccccccccccc", "statistics": {"viewCount": "100"}},
+        ]})
+
+        result = playlist.playlist_channel_breakdown("PLmock", "mock_key")
+
+    assert result["item_count"] == 4
+    assert result["unavailable_count"] == 1
+    assert result["total_views"] == 1000
+    assert [channel["channel_id"] for channel in result["channels"]] == ["UC_mock_channel_000000000", "UC_guest"]
+    host, guest = result["channels"]
+    assert (host["channel_title"], host["item_count"], host["total_views"]) == ("Mock Artist", 2, 400)
+    assert host["view_share"] == pytest.approx(0.4)
+    assert guest["view_share"] == pytest.approx(0.6)
+    assert result["warnings"] == []
+
+
+PLAYLIST_FEED_FIXTURE = """<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns:yt="http://www.youtube.com/xml/schemas/2015" xmlns:media="http://search.yahoo.com/mrss/" xmlns="http://www.w3.org/2005/Atom">
+ <yt:playlistId>PLmock</yt:playlistId>
+ <yt:channelId>UC_mock_channel_000000000</yt:channelId>
+ <title>Mock Hits &amp; Remixes</title>
+ <author><name>Mock Artist</name></author>
+ <entry>
+  <yt:videoId>aaaaaaaaaaa</yt:videoId>
+  <yt:channelId>UC_mock_channel_000000000</yt:channelId>
+  <title>First Song</title>
+  <author><name>Mock Artist</name></author>
+  <published>2024-05-01T00:00:00+00:00</published>
+  <updated>2024-05-02T00:00:00+00:00</updated>
+  <media:group>
+   <media:title>First Song</media:title>
+   <media:thumbnail url="https://i.ytimg.com/vi/aaaaaaaaaaa/hqdefault.jpg" width="480" height="360"/>
+   <media:description>Out now</media:description>
+   <media:community>
+    <media:starRating count="42" average="5.00" min="1" max="5"/>
+    <media:statistics views="1234"/>
+   </media:community>
+  </media:group>
+ </entry>
+ <entry>
+  <yt:videoId>bbbbbbbbbbb</yt:videoId>
+  <title>Second Song</title>
+  <published>2024-04-01T00:00:00+00:00</published>
+ </entry>
+</feed>"""
+
+
+def test_get_playlist_feed():
+    """Test parsing a playlist's Atom feed and spotting new items."""
+    with testing.MockServer() as server:
+        server.add_response("feeds/videos.xml", PLAYLIST_FEED_FIXTURE, content_type="application/atom+xml")
+        result = playlist.get_playlist_feed("PL&mock=1", known_video_ids=["aaaaaaaaaaa"])
+
+        # The ID is sent as one encoded query value, not spliced into the URL
+        assert server.requests()[0]["params"] == {"playlist_id": "PL&mock=1"}
+
+    assert (result["title"], result["author"], result["channel_id"]) == ("Mock Hits & Remixes", "Mock Artist", "UC_mock_channel_000000000")
+    first, second = result["items"]
+    assert first["video_id"] == "aaaaaaaaaaa"
+    assert (first["title"], first["author"], first["description"]) == ("First Song", "Mock Artist", "Out now")
+    assert (first["published_at"], first["updated_at"]) == ("2024-05-01T00:00:00+00:00", "2024-05-02T00:00:00+00:00")
+    assert first["thumbnail"] == "https://i.ytimg.com/vi/aaaaaaaaaaa/hqdefault.jpg"
+    assert (first["view_count"], first["rating_count"]) == (1234, 42)
+    assert (second["title"], second["author"], second["view_count"]) == ("Second Song", None, None)
+    assert result["new_video_ids"] == ["bbbbbbbbbbb"]
+
+
+def test_self_check_report_shape():
+    """Test that self_check reports every step even when the network is unusable."""
+    config = auth.AuthConfig(api_key="key_123", proxy="http://127.0.0.1:9", timeout=2.0)
+    report = youtube_stats.self_check(config)
+
+    assert [check["name"] for check in report["checks"]] == ["config", "dns", "tls", "api", "cache"]
+    assert report["checks"][0]["ok"] is True
+    assert report["checks"][-1]["ok"] is True
+    assert report["ok"] is False
+
+
+def test_capabilities():
+    """Test the feature map reports every subsystem as a bool."""
+    report = youtube_stats.capabilities()
+
+    assert report["version"]
+    assert report["features"]["oauth"] is True
+    assert report["features"]["parquet"] is False
+    assert all(isinstance(available, bool) for available in report["features"].values())
+
+
+def test_get_channel_localizations():
+    """Test listing a channel's localized titles and descriptions, and the typed not-found errors."""
+    terminated = "UC_terminated_channel_000"
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [{"localizations": {
+            "de": {"title": "Mock Künstler", "description": "Beschreibung"},
+            "fr": {"title": "Artiste", "description": ""},
+        }}]}, params={"id": "UC_mock_channel_000000000"})
+        server.add_response("channels", {"items": []})
+        server.add_response(f"channel/{terminated}",
+                            '<html><body>This account has been terminated for a violation.</body></html>')
+
+        localizations = account.get_channel_localizations("UC_mock_channel_000000000", "mock_key")
+        with pytest.raises(youtube_stats.ChannelNotFoundError) as missing:
+            account.get_channel_localizations("UC_mistyped_channel_00000", "mock_key")
+        with pytest.raises(youtube_stats.ChannelUnavailableError) as unavailable:
+            account.get_channel_localizations(terminated, "mock_key")
+
+    assert localizations == {"de": {"title": "Mock Künstler", "description": "Beschreibung"},
+                             "fr": {"title": "Artiste", "description": ""}}
+    assert missing.value.identifier == "UC_mistyped_channel_00000"
+    assert not isinstance(missing.value, youtube_stats.ChannelUnavailableError)
+    assert unavailable.value.reason == "terminated"
+
+
+def test_resolution_report():
+    """Test comparing search, forHandle and forUsername resolution."""
+    def search_result(channel_id):
+        return {"items": [{"id": {"kind": "youtube#channel", "channelId": channel_id},
+                           "snippet": {"title": "", "description": "", "channelTitle": "", "publishedAt": "2015-01-01T00:00:00Z"}}]}
+
+    bad_request = {"error": {"code": 400, "message": "Bad request", "errors": [
+        {"message": "Bad request", "domain": "youtube.search", "reason": "badRequest"}]}}
+    with testing.MockServer() as server:
+        server.add_response("search", search_result("UC_mock_channel_000000000"), params={"q": "mockartist"})
+        server.add_response("search", bad_request, status=400, params={"q": "onlyhandle"})
+        server.add_response("search", search_result("UC_other_channel_00000000"), params={"q": "split"})
+        server.add_response("channels", {"items": [{"id": "UC_mock_channel_000000000"}]}, params={"forHandle": "@mockartist"})
+        server.add_response("channels", {"items": [{"id": "UC_mock_channel_000000000"}]}, params={"forHandle": "@onlyhandle"})
+        server.add_response("channels", {"items": [{"id": "UC_mock_channel_000000000"}]}, params={"forHandle": "@split"})
+        server.add_response("channels", {"items": []})
+
+        both, single, split = account.resolution_report(["@mockartist", "onlyhandle", "split"], "mock_key")
+
+    assert both["search"] == both["for_handle"] == "UC_mock_channel_000000000"
+    assert both["for_username"] is None
+    assert both["resolved_ids"] == ["UC_mock_channel_000000000"]
+    assert both["agree"] is True
+
+    # Only forHandle succeeded: the failed and empty strategies do not count as disagreement
+    assert single["search"] is None
+    assert "400" in single["errors"]["search"]
+    assert single["resolved_ids"] == ["UC_mock_channel_000000000"]
+    assert single["agree"] is True
+
+    assert split["resolved_ids"] == ["UC_other_channel_00000000", "UC_mock_channel_000000000"]
+    assert split["agree"] is False
+    assert split["errors"] == {}
+
+
+def test_get_channel_by_custom_url():
+    """Test resolving a legacy /c/ custom URL by scraping the page, then by search."""
+    snippet = dict(CHANNEL_FIXTURE["items"][0]["snippet"], customUrl="mockartist")
+    with testing.MockServer() as server:
+        page = '<link rel="canonical" href="https://www.youtube.com/channel/UC_mock_custom_url_00000">'
+        server.add_response("c/K%C3%BCnstler", page, content_type="text/html")
+        server.add_response("channels", {"items": [{"id": "UC_mock_custom_url_00000", "snippet": snippet}]})
+
+        scraped = account.get_channel_by_custom_url("https://www.youtube.com/c/Künstler", "mock_key")
+
+        server.reset()
+        server.add_response("search", {"items": [
+            {"id": {"kind": "youtube#channel", "channelId": channel_id},
+             "snippet": {"title": "", "description": "", "channelTitle": "", "publishedAt": "2015-01-01T00:00:00Z"}}
+            for channel_id in ("UC_other_channel_00000000", "UC_mock_custom_url_00000")
+        ]}, params={"q": "MockArtist", "type": "channel"})
+        server.add_response("channels", {"items": [
+            {"id": "UC_other_channel_00000000", "snippet": dict(snippet, title="Other", customUrl="@someoneelse")},
+            {"id": "UC_mock_custom_url_00000", "snippet": snippet},
+        ]})
+
+        searched = account.get_channel_by_custom_url("/c/MockArtist", "mock_key")
+        endpoints = [request["endpoint"] for request in server.requests()]
+
+    assert scraped["channel_id"] == "UC_mock_custom_url_00000"
+    assert scraped["method"] == "scrape"
+    assert searched["channel_id"] == "UC_mock_custom_url_00000"
+    assert searched["method"] == "search"
+    assert endpoints == ["c/MockArtist", "search", "channels"]
+
+
+def test_channel_audit_details_requires_oauth():
+    """Test that auditDetails refuses API-key credentials."""
+    with pytest.raises(ValueError, match="channel owner"):
+        account.get_channel_audit_details("key_123")
+    assert auth.SCOPE_YOUTUBEPARTNER_CHANNEL_AUDIT.endswith("youtubepartner-channel-audit")
+
+
+CHANNEL_FIXTURE = {
+    "items": [{
+        "id": "UC_mock_channel_000000000",
+        "snippet": {
+            "title": "Mock Artist",
+            "description": "",
+            "publishedAt": "2015-01-01T00:00:00Z",
+            "thumbnails": {"default": {"url": "https://i.ytimg.com/default.jpg"}},
+        },
+        "statistics": {"viewCount": "1000", "subscriberCount": "10", "hiddenSubscriberCount": False, "videoCount": "3"},
+        "contentDetails": {"relatedPlaylists": {"uploads": "UU_mock_channel_000000000"}},
+    }]
+}
+
+
+def test_api_not_enabled_error():
+    """Test that a disabled Data API raises ApiNotEnabledError with the console link."""
+    enable_url = "https://console.developers.google.com/apis/api/youtube.googleapis.com/overview?project=123"
+    message = "YouTube Data API v3 has not been used in project 123 before or it is disabled. Enable it by visiting {}.".format(enable_url)
+    error = {"code": 403, "message": message, "errors": [{"message": message, "domain": "usageLimits", "reason": "accessNotConfigured"}]}
+    with testing.MockServer() as server:
+        server.add_response("channels", {"error": dict(error, details=[{
+            "@type": "type.googleapis.com/google.rpc.ErrorInfo",
+            "reason": "SERVICE_DISABLED",
+            "metadata": {"activationUrl": enable_url},
+        }])}, status=403)
+        with pytest.raises(youtube_stats.ApiNotEnabledError) as excinfo:
+            account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key")
+        assert excinfo.value.enable_url == enable_url
+        assert enable_url in str(excinfo.value)
+
+        # Without structured details the link is taken from the message, minus the full stop
+        server.reset()
+        server.add_response("channels", {"error": error}, status=403)
+        with pytest.raises(youtube_stats.ApiNotEnabledError) as excinfo:
+            account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key")
+        assert excinfo.value.enable_url == enable_url
+
+
+def test_mock_server_retries_and_pagination():
+    """Test retries, pagination and error paths against the bundled mock server."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {
+            "items": [{"contentDetails": {"videoId": "aaaaaaaaaaa", "videoPublishedAt": "2020-05-01T00:00:00Z"}}],
+            "nextPageToken": "p2",
+        }, times=1)
+        server.add_response("playlistItems", {
+            "items": [{"contentDetails": {"videoId": "bbbbbbbbbbb", "videoPublishedAt": "2024-05-01T00:00:00Z"}}],
+        }, params={"pageToken": "p2"})
+        server.add_response("videos", {"items": [
+            {"id": "aaaaaaaaaaa", "statistics": {"viewCount": "100"}},
+            {"id": "bbbbbbbbbbb", "statistics": {"viewCount": "300"}},
+        ]})
+        server.inject_error("channels", 503)
+
+        try:
+            youtube_stats.configure(api_key="mock_key", retries=1)
+            result = account.era_comparison("UC_mock_channel_000000000")
+        finally:
+            auth.set_default_config(None)
+
+        assert [bucket["year"] for bucket in result["years"]] == [2020, 2024]
+        assert result["average_views_ratio"] == pytest.approx(3.0)
+
+        endpoints = [request["endpoint"] for request in server.requests()]
+        assert endpoints == ["channels", "channels", "playlistItems", "playlistItems", "videos"]
+        assert all(request["params"].get("key") == "***" for request in server.requests())
+
+        server.inject_error("channels", 500, times=5)
+        with pytest.raises(ValueError):
+            account.get_youtube_channels_stats(["UC_mock_channel_000000000"], "mock_key")
+
+
+def test_get_channel_subscriptions():
+    """Test paging through a channel's public subscriptions."""
+    with testing.MockServer() as server:
+        server.add_response("subscriptions", {
+            "items": [{"snippet": {"title": "Label", "publishedAt": "2021-01-01T00:00:00Z",
+                                   "resourceId": {"kind": "youtube#channel", "channelId": "UC_label"}}}],
+            "nextPageToken": "p2",
+        }, times=1)
+        server.add_response("subscriptions", {
+            "items": [{"snippet": {"title": "Producer", "resourceId": {"channelId": "UC_producer"}}}],
+        }, params={"pageToken": "p2"})
+
+        subscriptions = account.get_channel_subscriptions("UC_mock_channel_000000000", "mock_key")
+        assert [s["channel_id"] for s in subscriptions] == ["UC_label", "UC_producer"]
+
+        server.reset()
+        server.inject_error("subscriptions", 403, "subscriptionForbidden")
+        with pytest.raises(ValueError, match="private"):
+            account.get_channel_subscriptions("UC_mock_channel_000000000", "mock_key")
+
+        # Any other 403 keeps its own reason instead of reading as private subscriptions
+        server.inject_error("subscriptions", 403, "forbidden")
+        with pytest.raises(ValueError, match=r"403 Forbidden \(forbidden\)") as excinfo:
+            account.get_channel_subscriptions("UC_mock_channel_000000000", "mock_key")
+        assert "private" not in str(excinfo.value)
+
+
+def test_record_and_replay_fixtures(tmp_path):
+    """Test that recorded fixtures are redacted and replay page by page."""
+    with testing.MockServer() as server:
+        server.add_response("subscriptions", {
+            "items": [{"snippet": {"title": "Label", "resourceId": {"channelId": "UC_label"}}}],
+            "nextPageToken": "p2",
+        }, times=1)
+        server.add_response("subscriptions", {
+            "items": [{"snippet": {"title": "Producer", "resourceId": {"channelId": "UC_producer"}}}],
+        }, params={"pageToken": "p2"})
+        paths = testing.record_fixture(account.get_channel_subscriptions,
+                                       ("UC_mock_channel_000000000", "secret_mock_key"), str(tmp_path))
+
+    assert len(paths) == 2
+    for path in paths:
+        assert "secret_mock_key" not in open(path).read()
+
+    with testing.MockServer() as server:
+        assert server.load_fixtures(str(tmp_path)) == 2
+        subscriptions = account.get_channel_subscriptions("UC_mock_channel_000000000", "other_key")
+        assert [s["channel_id"] for s in subscriptions] == ["UC_label", "UC_producer"]
+
+
+def test_shared_test_helpers_reject_reentrant_use(tmp_path):
+    """Test that a second MockServer or a nested recording raises instead of hijacking the first."""
+    subscriptions = {"items": [{"snippet": {"title": "Label", "resourceId": {"channelId": "UC_label"}}}]}
+    with testing.MockServer() as server:
+        server.add_response("subscriptions", subscriptions)
+        with pytest.raises(RuntimeError, match="already installed"):
+            with testing.MockServer():
+                pass
+        # The rejected server must not have uninstalled the active one
+        result = account.get_channel_subscriptions("UC_mock_channel_000000000", "mock_key")
+        assert [s["channel_id"] for s in result] == ["UC_label"]
+
+        def nested(out_dir):
+            return testing.record_fixture(account.get_channel_subscriptions,
+                                          ("UC_mock_channel_000000000", "mock_key"), out_dir)
+
+        with pytest.raises(RuntimeError, match="already running"):
+            testing.record_fixture(nested, (str(tmp_path),), str(tmp_path))
+        paths = testing.record_fixture(account.get_channel_subscriptions,
+                                       ("UC_mock_channel_000000000", "mock_key"), str(tmp_path))
+        assert len(paths) == 1
+
+
+def test_get_channel_activities():
+    """Test typed activity events and the since filter."""
+    with testing.MockServer() as server:
+        server.add_response("activities", {"items": [
+            {"id": "a1", "snippet": {"type": "upload", "publishedAt": "2024-05-02T10:00:00Z", "title": "New single"},
+             "contentDetails": {"upload": {"videoId": "vid_upload1"}}},
+            {"id": "a2", "snippet": {"type": "playlistItem", "publishedAt": "2024-05-01T10:00:00Z", "title": "Added"},
+             "contentDetails": {"playlistItem": {"playlistId": "PL_mock",
+                                                 "resourceId": {"kind": "youtube#video", "videoId": "vid_item01"}}}},
+            {"id": "a3", "snippet": {"type": "bulletin", "publishedAt": "2024-04-30T10:00:00Z", "title": "Tour dates"},
+             "contentDetails": {"bulletin": {"resourceId": {"kind": "youtube#channel", "channelId": "UC_friend"}}}},
+        ]}, params={"publishedAfter": "2024-04-01T00:00:00Z"})
+
+        activities = account.get_channel_activities("UC_mock_channel_000000000", since="2024-04-01", api_key="mock_key")
+        assert [a["type"] for a in activities] == ["upload", "playlist_item", "post"]
+        assert activities[0]["video_id"] == "vid_upload1"
+        assert activities[1]["playlist_id"] == "PL_mock"
+        assert activities[2]["channel_id"] == "UC_friend"
+
+    with pytest.raises(ValueError, match="since"):
+        account.get_channel_activities("UC_mock_channel_000000000", since="last week", api_key="mock_key")
+
+
+def test_recent_video_availability():
+    """Test that unlisted, private and deleted uploads are labelled explicitly."""
+    snippet = {"title": "Song", "publishedAt": "2024-01-01T00:00:00Z", "thumbnails": {}}
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [
+            {"snippet": {"title": "Song"}, "contentDetails": {"videoId": "public00001"}},
+            {"snippet": {"title": "Demo"}, "contentDetails": {"videoId": "unlisted001"}},
+            {"snippet": {"title": "Private video"}, "contentDetails": {"videoId": "private0001"}},
+            {"snippet": {"title": "Deleted video"}, "contentDetails": {"videoId": "deleted0001"}},
+        ]})
+        server.add_response("videos", {"items": [
+            {"id": "public00001", "snippet": snippet, "status": {"privacyStatus": "public"}},
+            {"id": "unlisted001", "snippet": snippet, "status": {"privacyStatus": "unlisted"}},
+        ]})
+
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=4)
+
+    availability = {video["video_id"]: video["availability"] for video in stats["recent_videos"]}
+    assert availability == {
+        "public00001": "public",
+        "unlisted001": "unlisted",
+        "private0001": "private",
+        "deleted0001": "deleted",
+    }
+
+
+def test_export_run_partitioned(tmp_path):
+    """Test that exports land in a dated partition next to a manifest."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        manifest = export.run_partitioned(["UC_mock_channel_000000000", "UC_missing_channel_00000"],
+                                          str(tmp_path), format="csv", api_key="mock_key")
+
+    partition_dir = tmp_path / manifest["partition"]
+    assert json.loads((partition_dir / "manifest.json").read_text()) == manifest
+    assert manifest["record_count"] == 1
+    assert manifest["missing_channel_ids"] == ["UC_missing_channel_00000"]
+    lines = (partition_dir / "channels.csv").read_text().splitlines()
+    assert lines[0].startswith("channel_id,")
+    assert lines[1].startswith("UC_mock_channel_000000000,")
+
+    with pytest.raises(ValueError, match="partition_by"):
+        export.run_partitioned(["UC_mock_channel_000000000"], str(tmp_path), partition_by="channel")
+
+
+def test_export_csv_options(tmp_path):
+    """Test semicolon-delimited CSV with a BOM, full quoting and text columns."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        manifest = export.run_partitioned(["UC_mock_channel_000000000"], str(tmp_path), format="csv",
+                                          api_key="mock_key", csv_delimiter=";", csv_bom=True,
+                                          csv_quoting="nonnumeric", csv_text_columns=["total_view_count"])
+
+    raw = (tmp_path / manifest["partition"] / "channels.csv").read_bytes()
+    assert raw.startswith(b"\xef\xbb\xbf")
+    header, row = raw.decode("utf-8-sig").splitlines()
+    columns = header.split(";")
+    assert columns[0] == '"channel_id"'
+    values = dict(zip(columns, row.split(";")))
+    assert values['"total_view_count"'].startswith('"=""')
+    assert not values['"video_count"'].startswith('"')
+
+    with pytest.raises(ValueError, match="delimiter"):
+        export.run_partitioned(["UC_mock_channel_000000000"], str(tmp_path), format="csv", csv_delimiter=";;")
+
+
+def test_compare_channels():
+    """Test per-metric rankings and deltas to the leader."""
+    rival = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    rival.update(id="UC_rival_channel_00000000", contentDetails={"relatedPlaylists": {"uploads": "UU_rival"}})
+    rival["statistics"] = {"viewCount": "5000", "subscriberCount": "4", "hiddenSubscriberCount": False, "videoCount": "7"}
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [CHANNEL_FIXTURE["items"][0], rival]})
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": "mock0000001"}}]},
+                            params={"playlistId": "UU_mock_channel_000000000"})
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": "rival000001"}}]},
+                            params={"playlistId": "UU_rival"})
+        server.add_response("videos", {"items": [{"id": "mock0000001", "statistics": {
+            "viewCount": "100", "likeCount": "10", "commentCount": "0"}}]}, params={"id": "mock0000001"})
+        server.add_response("videos", {"items": [{"id": "rival000001", "statistics": {
+            "viewCount": "100", "likeCount": "1", "commentCount": "0"}}]}, params={"id": "rival000001"})
+
+        result = account.compare_channels(["UC_mock_channel_000000000", "UC_rival_channel_00000000"], "mock_key")
+
+    subscribers = result["rankings"]["subscriber_count"]
+    assert subscribers["ranking"] == ["UC_mock_channel_000000000", "UC_rival_channel_00000000"]
+    assert subscribers["deltas"]["UC_rival_channel_00000000"] == -6
+    assert result["rankings"]["total_view_count"]["leader"] == "UC_rival_channel_00000000"
+    assert result["rankings"]["engagement_score"]["leader"] == "UC_mock_channel_000000000"
+    assert result["not_found"] == []
+
+
+def test_compare_channels_resolution_errors():
+    """Test that only missing channels land in not_found and other lookup errors propagate."""
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": []}, params={"forHandle": "@nobody"})
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": []})
+
+        result = account.compare_channels(["UC_mock_channel_000000000", "@nobody"], "mock_key")
+        assert result["not_found"] == ["@nobody"]
+
+        server.reset()
+        server.inject_error("channels", 403, "quotaExceeded")
+        with pytest.raises(ValueError, match="403 Forbidden") as excinfo:
+            account.compare_channels(["UC_mock_channel_000000000", "@nobody"], "mock_key")
+        assert not isinstance(excinfo.value, youtube_stats.ChannelNotFoundError)
+
+
+def test_compare_channels_hidden_likes():
+    """Test that uploads with hidden likes are left out of the engagement score, not counted as zero."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": f"mock000000{n}"}} for n in range(2)]})
+        server.add_response("videos", {"items": [
+            {"id": "mock0000000", "statistics": {"viewCount": "100", "likeCount": "10", "commentCount": "0"}},
+            {"id": "mock0000001", "statistics": {"viewCount": "900", "commentCount": "0"}},
+        ]})
+
+        result = account.compare_channels(["UC_mock_channel_000000000"], "mock_key")
+
+    channel = result["channels"][0]
+    assert channel["engagement_score"] == pytest.approx(0.1)
+    assert channel["engagement_excluded_videos"] == 1
+
+
+def test_handle_field():
+    """Test that @handles are reported separately from legacy custom URLs."""
+    legacy = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    legacy.update(id="UC_legacy_channel_0000000")
+    legacy["snippet"]["customUrl"] = "legacyname"
+    handled = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    handled["snippet"]["customUrl"] = "@mockartist"
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [{"id": "UC_mock_channel_000000000"}]},
+                            params={"forHandle": "@mockartist"})
+        server.add_response("channels", {"items": [handled, legacy]})
+        results = account.get_youtube_channels_stats(["@mockartist", "UC_legacy_channel_0000000"], "mock_key")
+
+    assert results["@mockartist"]["handle"] == "@mockartist"
+    assert results["UC_legacy_channel_0000000"]["handle"] is None
+    assert results["UC_legacy_channel_0000000"]["custom_url"] == "legacyname"
+
+
+def test_resolve_channel_id():
+    """Test resolving every channel URL form with the cheapest lookup."""
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [{"id": "UC_from_handle_000000000"}]},
+                            params={"forHandle": "@mockartist"})
+        server.add_response("channels", {"items": [{"id": "UC_from_user_00000000000"}]},
+                            params={"forUsername": "mockuser"})
+        server.add_response("channels", {"items": []})
+        server.add_response("videos", {"items": [{"id": "dQw4w9WgXcQ", "snippet": {"channelId": "UC_uploader_000000000000"}}]})
+
+        assert account.resolve_channel_id("https://www.youtube.com/channel/UCBJycsmduvYEL83R_U4JriQ", "mock_key") \
+            == "UCBJycsmduvYEL83R_U4JriQ"
+        assert server.requests() == []
+        assert account.resolve_channel_id("youtube.com/@mockartist/videos", "mock_key") == "UC_from_handle_000000000"
+        assert account.resolve_channel_id("https://m.youtube.com/user/mockuser", "mock_key") == "UC_from_user_00000000000"
+        assert account.resolve_channel_id("mockartist", "mock_key") == "UC_from_handle_000000000"
+        assert account.resolve_channel_id("https://youtu.be/dQw4w9WgXcQ?t=1", "mock_key") == "UC_uploader_000000000000"
+
+        with pytest.raises(youtube_stats.ChannelNotFoundError) as excinfo:
+            account.resolve_channel_id("@nobody", "mock_key")
+        assert excinfo.value.identifier == "@nobody"
+
+        with pytest.raises(ValueError, match="Unrecognized"):
+            account.resolve_channel_id("https://example.com/@mockartist", "mock_key")
+
+
+def test_raw_statistics():
+    """Test that raw API strings are kept next to the parsed counts."""
+    channel = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    channel["statistics"] = {"viewCount": "12e3", "hiddenSubscriberCount": True, "videoCount": "0"}
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [channel]})
+        stats = account.get_youtube_channels_stats(["UC_mock_channel_000000000"], "mock_key")["UC_mock_channel_000000000"]
+
+    assert stats["raw_statistics"] == {
+        "view_count": "12e3",
+        "subscriber_count": None,
+        "video_count": "0",
+        "hidden_subscriber_count": True,
+    }
+    assert stats["subscriber_count_hidden"] is True
+    assert stats["video_count"] == 0
+
+
+def test_batch_status_isolates_bad_ids():
+    """Test that one rejected ID does not hide the rest of its batch."""
+    bad_request = {"error": {"code": 400, "message": "Invalid channel id", "errors": []}}
+    ids = ["UC_mock_channel_000000000", "UC_bad_id", "UC_missing_channel_00000"]
+    with testing.MockServer() as server:
+        server.add_response("channels", bad_request, status=400, params={"id": ",".join(ids)})
+        server.add_response("channels", bad_request, status=400, params={"id": "UC_bad_id"})
+        server.add_response("channels", CHANNEL_FIXTURE)
+
+        report = account.get_youtube_channels_stats(ids, "mock_key", with_status=True)
+        with pytest.raises(ValueError, match="UC_bad_id"):
+            account.get_youtube_channels_stats(ids, "mock_key")
+
+    assert {channel_id: status["status"] for channel_id, status in report["status"].items()} == {
+        "UC_mock_channel_000000000": "ok",
+        "UC_bad_id": "error",
+        "UC_missing_channel_00000": "not_found",
+    }
+    assert "Invalid channel id" in report["status"]["UC_bad_id"]["error"]
+    assert report["results"]["UC_mock_channel_000000000"]["channel_id"] == "UC_mock_channel_000000000"
+    assert report["results"]["UC_bad_id"] is None
+
+
+def test_compare_channels_hidden_subscribers():
+    """Test that hidden subscriber counts are excluded from the ranking, not ranked as zero."""
+    hidden = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    hidden["id"] = "UC_hidden_channel_0000000"
+    del hidden["contentDetails"]
+    hidden["statistics"] = {"viewCount": "9000", "subscriberCount": "0", "hiddenSubscriberCount": True, "videoCount": "2"}
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [CHANNEL_FIXTURE["items"][0], hidden]})
+        server.add_response("playlistItems", {"items": []})
+
+        result = account.compare_channels(["UC_mock_channel_000000000", "UC_hidden_channel_0000000"], "mock_key")
+
+    subscribers = result["rankings"]["subscriber_count"]
+    assert subscribers["ranking"] == ["UC_mock_channel_000000000"]
+    assert subscribers["excluded"] == [{"channel_id": "UC_hidden_channel_0000000", "reason": "hidden_subscriber_count"}]
+    assert "UC_hidden_channel_0000000" not in subscribers["deltas"]
+    assert result["rankings"]["total_view_count"]["leader"] == "UC_hidden_channel_0000000"
+
+
+def test_thumbnail_details():
+    """Test that every thumbnail variant, including standard and maxres, keeps its size."""
+    channel = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    channel["snippet"]["thumbnails"] = {
+        "default": {"url": "https://i.ytimg.com/default.jpg", "width": 88, "height": 88},
+        "standard": {"url": "https://i.ytimg.com/sddefault.jpg", "width": 640, "height": 480},
+        "maxres": {"url": "https://i.ytimg.com/maxresdefault.jpg", "width": 1280, "height": 720},
+    }
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [channel]})
+        stats = account.get_youtube_channels_stats(["UC_mock_channel_000000000"], "mock_key")["UC_mock_channel_000000000"]
+
+    assert stats["thumbnails"]["maxres"] == "https://i.ytimg.com/maxresdefault.jpg"
+    assert stats["thumbnail_details"]["standard"] == {"url": "https://i.ytimg.com/sddefault.jpg", "width": 640, "height": 480}
+    assert utils.best_thumbnail_url(stats) == "https://i.ytimg.com/maxresdefault.jpg"
+
+
+def test_stream_playlist_items():
+    """Test that playlist pages are fetched lazily and yielded in batches."""
+    with testing.MockServer() as server:
+        server.add_response("playlistItems", {
+            "items": [{"contentDetails": {"videoId": f"video{n:06d}"}} for n in range(3)],
+            "nextPageToken": "p2",
+        }, times=1)
+        server.add_response("playlistItems", {
+            "items": [{"contentDetails": {"videoId": f"video{n:06d}"}} for n in range(3, 5)],
+        }, params={"pageToken": "p2"})
+
+        stream = playlist.stream_playlist_items("PL_mock", "mock_key", batch_size=2)
+        first = next(stream)
+        assert [item["video_id"] for item in first] == ["video000000", "video000001"]
+        assert stream.pages_fetched == 1
+
+        rest = [item["video_id"] for batch in stream for item in batch]
+        assert rest == ["video000002", "video000003", "video000004"]
+        assert stream.pages_fetched == 2
+
+
+def test_iter_channel_uploads():
+    """Test that uploads are yielded one by one, fetching pages on demand."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {
+            "items": [{"contentDetails": {"videoId": "upload00001"}}, {"contentDetails": {"videoId": "upload00002"}}],
+            "nextPageToken": "p2",
+        }, params={"playlistId": "UU_mock_channel_000000000"}, times=1)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": "upload00003"}}]},
+                            params={"pageToken": "p2"})
+
+        uploads = account.iter_channel_uploads("UC_mock_channel_000000000", "mock_key")
+        assert next(uploads)["video_id"] == "upload00001"
+        assert [request["endpoint"] for request in server.requests()] == ["channels", "playlistItems"]
+        assert [video["video_id"] for video in uploads] == ["upload00002", "upload00003"]
+
+
+def test_get_channel_playlists():
+    """Test paging through a channel's playlists."""
+    with testing.MockServer() as server:
+        server.add_response("playlists", {
+            "items": [{"id": "PL_first", "snippet": {"title": "Singles", "thumbnails": {"default": {"url": "https://i.ytimg.com/pl.jpg"}}},
+                       "contentDetails": {"itemCount": 12}, "status": {"privacyStatus": "public"}}],
+            "nextPageToken": "p2",
+        }, times=1)
+        server.add_response("playlists", {"items": [{"id": "PL_second", "snippet": {"title": "Live"}}]},
+                            params={"pageToken": "p2"})
+
+        playlists = playlist.get_channel_playlists("UC_mock_channel_000000000", "mock_key")
+
+    assert [p["playlist_id"] for p in playlists] == ["PL_first", "PL_second"]
+    assert playlists[0]["item_count"] == 12
+    assert playlists[0]["privacy_status"] == "public"
+    assert playlists[0]["thumbnails"]["default"] == "https://i.ytimg.com/pl.jpg"
+    assert playlists[1]["item_count"] is None
+
+
+def _trending(*video_ids):
+    return {"items": [{"id": video_id, "snippet": {"title": video_id, "channelId": "UC_" + video_id},
+                       "statistics": {"viewCount": "10"}} for video_id in video_ids]}
+
+
+def test_compare_trending_regions():
+    """Test overlaps and region-exclusive videos across trending charts."""
+    with testing.MockServer() as server:
+        server.add_response("videos", _trending("everywhere1", "usgb0000001", "usonly00001"), params={"regionCode": "US"})
+        server.add_response("videos", _trending("usgb0000001", "everywhere1"), params={"regionCode": "GB"})
+        server.add_response("videos", _trending("everywhere1", "deonly00001"), params={"regionCode": "DE"})
+        server.add_response("videos", {"error": {"code": 400, "message": "Unsupported region", "errors": []}},
+                            status=400, params={"regionCode": "ZZ"})
+
+        result = trending.compare_trending_regions(["us", "GB", "DE", "ZZ"], "mock_key", category_id="10")
+
+    assert result["overlap"] == ["everywhere1"]
+    assert result["shared"]["usgb0000001"] == ["US", "GB"]
+    assert result["exclusive"] == {"US": ["usonly00001"], "GB": [], "DE": ["deonly00001"]}
+    assert result["regions"]["GB"][0]["rank"] == 1
+    assert "ZZ" in result["errors"]
+    assert all(request["params"]["videoCategoryId"] == "10" for request in server.requests())
+
+
+def test_derived_namespace():
+    """Test that computed fields are grouped under `derived` with their provenance."""
+    snippet = {"title": "Song", "publishedAt": "2024-01-01T00:00:00Z", "thumbnails": {}}
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [
+            {"snippet": {"title": "Song"}, "contentDetails": {"videoId": "public00001"}},
+            {"snippet": {"title": "Private video"}, "contentDetails": {"videoId": "private0001"}},
+        ]})
+        server.add_response("videos", {"items": [
+            {"id": "public00001", "snippet": snippet, "status": {"privacyStatus": "public"},
+             "statistics": {"viewCount": "1000", "likeCount": "50", "commentCount": "10"}},
+        ]})
+
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=2)
+
+    derived = stats["derived"]
+    assert derived["engagement_score"] == {"value": stats["engagement_score"], "source": "computed"}
+    assert derived["total_recent_views"] == {"value": 1000, "source": "computed"}
+    public, private = stats["recent_videos"]
+    assert public["derived"]["availability"] == {"value": "public", "source": "api"}
+    assert private["derived"]["availability"] == {"value": "private", "source": "computed"}
+
+
+def test_channel_exists():
+    """Test the id-only existence check for IDs, handles and junk input."""
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [{"id": "UC_mock_channel_000000000"}]},
+                            params={"id": "UC_mock_channel_000000000"})
+        server.add_response("channels", {"items": [{"id": "UC_mock_channel_000000000"}]},
+                            params={"forHandle": "@mock"})
+        server.add_response("channels", {"items": []})
+
+        assert account.channel_exists("UC_mock_channel_000000000", "mock_key") == (True, "UC_mock_channel_000000000")
+        assert account.channel_exists("https://www.youtube.com/@mock", "mock_key") == (True, "UC_mock_channel_000000000")
+        assert account.channel_exists("UC_gone_channel_000000000", "mock_key") == (False, None)
+        assert account.channel_exists("not a channel", "mock_key") == (False, None)
+        assert all(request["params"]["fields"] == "items(id)" for request in server.requests())
+
+
+def test_channel_keywords_list():
+    """Test that the quoted keywords string is split into a list."""
+    channel = json.loads(json.dumps(CHANNEL_FIXTURE["items"][0]))
+    raw = 'music "hip hop"  rap "lo fi beats" music'
+    channel["brandingSettings"] = {"channel": {"keywords": raw}}
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": [channel]})
+        stats = account.get_youtube_channels_stats(["UC_mock_channel_000000000"], "mock_key")
+
+    result = stats["UC_mock_channel_000000000"]
+    assert result["channel_keywords"] == ["music", "hip hop", "rap", "lo fi beats"]
+    assert result["channel_keywords_raw"] == raw
+
+
+def test_get_last_activity():
+    """Test that playlist updates and posts count as activity, not only uploads."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [
+            {"contentDetails": {"videoId": "short000001", "videoPublishedAt": "2024-03-01T12:00:00Z"}},
+            {"contentDetails": {"videoId": "older000001", "videoPublishedAt": "2024-02-01T12:00:00Z"}},
+        ]})
+        server.add_response("activities", {"items": [
+            {"id": "a1", "snippet": {"type": "bulletin", "publishedAt": "2024-04-02T08:00:00.000Z"}},
+            {"id": "a2", "snippet": {"type": "playlistItem", "publishedAt": "2024-03-15T08:00:00Z"},
+             "contentDetails": {"playlistItem": {"playlistId": "PL_mock", "resourceId": {"videoId": "vid_item01"}}}},
+        ]})
+
+        activity = account.get_last_activity("UC_mock_channel_000000000", "mock_key")
+
+    assert activity["last_activity_at"] == "2024-04-02T08:00:00.000Z"
+    assert activity["last_activity_type"] == "post"
+    assert activity["signals"] == {
+        "latest_upload": "2024-03-01T12:00:00Z",
+        "latest_playlist_update": "2024-03-15T08:00:00Z",
+        "latest_post": "2024-04-02T08:00:00.000Z",
+    }
+    assert activity["derived"]["last_activity_at"]["source"] == "computed"
+
+
+def test_estimate_hidden_subscribers():
+    """Test the subscriber range for channels hiding their count, signal by signal."""
+    hidden = copy.deepcopy(CHANNEL_FIXTURE)
+    statistics = hidden["items"][0]["statistics"]
+    statistics.update({"hiddenSubscriberCount": True, "viewCount": "400000"})
+    del statistics["subscriberCount"]
+    snippet = {"title": "Clip", "publishedAt": "2024-01-01T00:00:00Z", "thumbnails": {}}
+
+    def estimate(recent, video_count=2, **kwargs):
+        with testing.MockServer() as server:
+            server.add_response("channels", hidden)
+            server.add_response("playlistItems", {"items": [
+                {"contentDetails": {"videoId": "recent%07d" % index}} for index in range(len(recent))
+            ]})
+            server.add_response("videos", {"items": [
+                {"id": "recent%07d" % index, "snippet": snippet, "statistics": stats} for index, stats in enumerate(recent)
+            ]})
+            stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=video_count, **kwargs)
+        assert stats["subscriber_count"] is None
+        return stats.get("subscriber_count_estimate")
+
+    # Lifetime views alone: 400,000 views at 100-400 views per subscriber
+    assert estimate([], video_count=0, estimate_hidden_subscribers=True) == {
+        "low": 1000, "high": 4000, "point": 2000, "confidence": "low", "signals": ["total_views"], "source": "estimated",
+    }
+    assert estimate([], video_count=0) is None
+
+    # Recent uploads disagreeing with lifetime views widen the range to the union
+    recent = [{"viewCount": "3000"}, {"viewCount": "3000"}]
+    wide = estimate(recent, estimate_hidden_subscribers=True)
+    assert (wide["low"], wide["high"], wide["confidence"]) == (1000, 60000, "low")
+    assert wide["signals"] == ["total_views", "recent_views"]
+
+    # Views and engagement that agree narrow it to the overlap
+    statistics["viewCount"] = "4000000"
+    recent = [{"viewCount": "3000", "likeCount": "90", "commentCount": "10"}] * 2
+    narrow = estimate(recent, estimate_hidden_subscribers=True)
+    assert (narrow["low"], narrow["high"], narrow["point"]) == (10000, 40000, 20000)
+    assert narrow["confidence"] == "high"
+    assert narrow["signals"] == ["total_views", "recent_views", "recent_engagement"]
+
+    # Nothing to go on: no estimate rather than a zero range
+    statistics["viewCount"] = "0"
+    assert estimate([], video_count=0, estimate_hidden_subscribers=True) is None
+
+
+def test_channel_without_statistics():
+    """Test that a channel response without a statistics part degrades to None counts."""
+    bare = copy.deepcopy(CHANNEL_FIXTURE)
+    del bare["items"][0]["statistics"]
+    with testing.MockServer() as server:
+        server.add_response("channels", bare)
+        server.add_response("playlistItems", {"items": []})
+
+        single = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=1)
+        batch = account.get_youtube_channels_stats(["UC_mock_channel_000000000"], "mock_key")
+
+    for stats in (single, batch["UC_mock_channel_000000000"]):
+        assert stats["statistics_unavailable"] is True
+        assert (stats["subscriber_count"], stats["total_view_count"], stats["video_count"]) == (None, None, None)
+        assert stats["raw_statistics"]["view_count"] is None
+    assert single["channel_title"] == "Mock Artist"
+
+
+def test_channel_topic_categories():
+    """Test that channel topicDetails come back as readable names plus the raw URLs."""
+    channel = copy.deepcopy(CHANNEL_FIXTURE)
+    urls = ["https://en.wikipedia.org/wiki/Hip_hop_music", "https://en.wikipedia.org/wiki/Rhythm_and_blues"]
+    channel["items"][0]["topicDetails"] = {"topicCategories": urls}
+    with testing.MockServer() as server:
+        server.add_response("channels", channel)
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=0)
+        assert "topicDetails" in server.requests()[0]["params"]["part"]
+
+    assert stats["topic_categories"] == ["Hip hop music", "Rhythm and blues"]
+    assert stats["topic_category_urls"] == urls
+
+
+def test_channel_status():
+    """Test that the channel status part is exposed, with None when it is missing."""
+    channel = copy.deepcopy(CHANNEL_FIXTURE)
+    channel["items"][0]["status"] = {
+        "privacyStatus": "public", "isLinked": True, "madeForKids": False, "selfDeclaredMadeForKids": False,
+    }
+    with testing.MockServer() as server:
+        server.add_response("channels", channel)
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=0)
+        assert "status" in server.requests()[0]["params"]["part"].split(",")
+
+        server.reset()
+        server.add_response("channels", CHANNEL_FIXTURE)
+        bare = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=0)
+
+    assert (stats["privacy_status"], stats["is_linked"]) == ("public", True)
+    assert (stats["made_for_kids"], stats["self_declared_made_for_kids"]) == (False, False)
+    assert (bare["privacy_status"], bare["is_linked"], bare["made_for_kids"]) == (None, None, None)
+
+
+def test_channel_branding():
+    """Test that brandingSettings are exposed under the branding sub-dict."""
+    channel = copy.deepcopy(CHANNEL_FIXTURE)
+    channel["items"][0]["brandingSettings"] = {
+        "channel": {"unsubscribedTrailer": "trailer0001", "country": "US", "defaultLanguage": "en", "keywords": "rap \"new music\""},
+        "image": {"bannerExternalUrl": "https://yt3.googleusercontent.com/banner"},
+    }
+    with testing.MockServer() as server:
+        server.add_response("channels", channel)
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=0)
+
+    assert stats["branding"] == {
+        "banner_url": "https://yt3.googleusercontent.com/banner",
+        "unsubscribed_trailer": "trailer0001",
+        "country": "US",
+        "default_language": "en",
+        "keywords": 'rap "new music"',
+    }
+
+
+def test_classify_shorts():
+    """Test Short vs regular classification from duration and the shorts URL probe."""
+    snippet = {"title": "Clip", "publishedAt": "2024-01-01T00:00:00Z", "thumbnails": {}}
+    videos = [("short000001", "PT45S", "500"), ("long0000001", "PT10M2S", "2000"),
+              ("redirect001", "PT2M", "300"), ("nodur000001", None, "7")]
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [
+            {"contentDetails": {"videoId": video_id}} for video_id, _, _ in videos
+        ]})
+        server.add_response("videos", {"items": [
+            dict({"id": video_id, "snippet": snippet, "statistics": {"viewCount": views}},
+                 **({"contentDetails": {"duration": duration}} if duration else {}))
+            for video_id, duration, views in videos
+        ]})
+        server.add_response("shorts/short000001", {})
+        server.add_response("shorts/redirect001", {}, status=303)
+
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key",
+                                                  video_count=4, classify_shorts=True)
+        probed = [request["endpoint"] for request in server.requests() if request["endpoint"].startswith("shorts/")]
+
+    by_id = {video["video_id"]: video for video in stats["recent_videos"]}
+    assert by_id["short000001"]["is_short"] is True
+    assert by_id["short000001"]["short_detection"] == "shorts_url"
+    assert by_id["long0000001"]["is_short"] is False
+    assert by_id["long0000001"]["duration_seconds"] == 602
+    assert by_id["redirect001"]["is_short"] is False
+    assert by_id["nodur000001"]["is_short"] is None
+    assert "shorts/long0000001" not in probed
+    assert stats["video_classes"] == {
+        "shorts": {"count": 1, "total_views": 500},
+        "regular": {"count": 2, "total_views": 2300},
+        "unclassified": {"count": 1, "total_views": 7},
+    }
+
+
+def test_stream_restarts_after_invalid_page_token():
+    """Test that an expired page token restarts the listing without duplicates."""
+    expired = {"error": {"code": 400, "message": "The page token is invalid.",
+                         "errors": [{"message": "The page token is invalid.", "domain": "youtube.parameter",
+                                     "reason": "invalidPageToken"}]}}
+    first_page = {"items": [{"id": f"item{n}", "contentDetails": {"videoId": f"video{n:06d}"}} for n in range(2)],
+                  "nextPageToken": "p2"}
+    with testing.MockServer() as server:
+        server.add_response("playlistItems", expired, status=400, params={"pageToken": "p2"}, times=1)
+        server.add_response("playlistItems", {"items": [{"id": "item2", "contentDetails": {"videoId": "video000002"}}]},
+                            params={"pageToken": "p2"})
+        server.add_response("playlistItems", first_page)
+
+        stream = playlist.stream_playlist_items("PL_mock", "mock_key", batch_size=50)
+        video_ids = [item["video_id"] for batch in stream for item in batch]
+
+    assert video_ids == ["video000000", "video000001", "video000002"]
+    assert len(stream.warnings) == 1
+    assert "restarted" in stream.warnings[0]
+
+
+def test_get_channel_live_streams():
+    """Test joining live and upcoming search results with liveStreamingDetails."""
+    with testing.MockServer() as server:
+        server.add_response("search", {"items": [{"id": {"kind": "youtube#video", "videoId": "live0000001"}},
+                                                 {"id": {"kind": "youtube#video", "videoId": "ended000001"}}]},
+                            params={"eventType": "live"})
+        server.add_response("search", {"items": [{"id": {"kind": "youtube#video", "videoId": "soon0000001"}}]},
+                            params={"eventType": "upcoming"})
+        server.add_response("videos", {"items": [
+            {"id": "soon0000001", "snippet": {"title": "Premiere", "liveBroadcastContent": "upcoming"},
+             "liveStreamingDetails": {"scheduledStartTime": "2024-06-01T18:00:00Z"}},
+            {"id": "ended000001", "snippet": {"title": "Yesterday", "liveBroadcastContent": "none"},
+             "liveStreamingDetails": {"actualStartTime": "2024-05-30T18:00:00Z"}},
+            {"id": "live0000001", "snippet": {"title": "Live now", "liveBroadcastContent": "live"},
+             "liveStreamingDetails": {"concurrentViewers": "1234", "actualStartTime": "2024-05-31T18:00:00Z"}},
+        ]})
+
+        streams = account.get_channel_live_streams("UC_mock_channel_000000000", "mock_key")
+
+    assert [stream["video_id"] for stream in streams] == ["live0000001", "soon0000001"]
+    assert streams[0]["status"] == "live"
+    assert streams[0]["concurrent_viewers"] == 1234
+    assert streams[1]["scheduled_start_time"] == "2024-06-01T18:00:00Z"
+    assert streams[1]["concurrent_viewers"] is None
+
+
+def test_result_meta_provenance():
+    """Test that results record etag, endpoints, quota cost and cache hits."""
+    fixture = dict(CHANNEL_FIXTURE, etag="etag_channels_1")
+    config = auth.AuthConfig(api_key="mock_key", cache_ttl=60)
+    youtube_stats.clear_cache()
+    with testing.MockServer() as server:
+        server.add_response("channels", fixture)
+        first = account.get_youtube_channels_stats(["UC_mock_channel_000000000"], config)
+        second = account.get_youtube_channels_stats(["UC_mock_channel_000000000"], config)
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=0)
+    youtube_stats.clear_cache()
+
+    meta = first["UC_mock_channel_000000000"]["_meta"]
+    assert meta["etag"] == "etag_channels_1"
+    assert meta["endpoints"] == ["channels"]
+    assert meta["quota_cost"] == 1
+    assert meta["cache_hit"] is False
+    assert meta["fetched_at"].endswith("Z")
+    assert second["UC_mock_channel_000000000"]["_meta"]["cache_hit"] is True
+    assert second["UC_mock_channel_000000000"]["_meta"]["quota_cost"] == 0
+    assert stats["_meta"]["endpoints"][0] == "channels"
+
+
+def test_get_channel_total_duration():
+    """Test total and average upload duration, skipping unmeasurable videos."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [
+            {"contentDetails": {"videoId": video_id}} for video_id in ("video000001", "video000002", "private0001")
+        ]})
+        server.add_response("videos", {"items": [
+            {"id": "video000001", "contentDetails": {"duration": "PT1H2M3S"}},
+            {"id": "video000002", "contentDetails": {"duration": "PT57S"}},
+        ]})
+
+        result = account.get_channel_total_duration("UC_mock_channel_000000000", api_key="mock_key")
+
+    assert result["video_count"] == 3
+    assert result["measured_count"] == 2
+    assert result["unmeasured_count"] == 1
+    assert result["total_duration_seconds"] == 3780
+    assert result["average_duration_seconds"] == 1890.0
+
+
+def test_get_upload_frequency():
+    """Test upload rates, longest gap and last upload age over a window."""
+    from datetime import datetime, timedelta, timezone
+
+    now = datetime.now(timezone.utc)
+    ages = [2, 9, 30, 44, 120]
+    items = [{"contentDetails": {"videoId": f"video{n:06d}",
+                                 "videoPublishedAt": (now - timedelta(days=age)).strftime("%Y-%m-%dT%H:%M:%SZ")}}
+             for n, age in enumerate(ages)]
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": items})
+
+        result = account.get_upload_frequency("UC_mock_channel_000000000", window_days=70, api_key="mock_key")
+
+    assert result["upload_count"] == 4
+    assert result["uploads_per_week"] == pytest.approx(0.4)
+    assert result["longest_gap_days"] == pytest.approx(21, abs=0.01)
+    assert result["last_upload_age_days"] == pytest.approx(2, abs=0.01)
+    assert result["derived"]["uploads_per_week"]["source"] == "computed"
+
+
+def test_terminated_channel_is_typed():
+    """Test that taken-down channels raise ChannelUnavailableError with a reason."""
+    terminated = "UC_terminated_channel_000"
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": []})
+        server.add_response(f"channel/{terminated}",
+                            '<html><body>This account has been terminated for a violation.</body></html>')
+
+        with pytest.raises(youtube_stats.ChannelUnavailableError) as excinfo:
+            account.get_youtube_channel_stats(terminated, "mock_key")
+        with pytest.raises(youtube_stats.ChannelNotFoundError) as typo:
+            account.get_youtube_channel_stats("UC_mistyped_channel_00000", "mock_key")
+        report = account.get_youtube_channels_stats([terminated, "UC_mistyped_channel_00000"], "mock_key",
+                                                    with_status=True)
+
+    assert excinfo.value.reason == "terminated"
+    assert excinfo.value.identifier == terminated
+    assert not isinstance(typo.value, youtube_stats.ChannelUnavailableError)
+    assert report["status"][terminated] == {"status": "unavailable", "reason": "terminated", "error": None}
+    assert report["status"]["UC_mistyped_channel_00000"]["status"] == "not_found"
+
+
+def test_get_channel_top_videos():
+    """Test that top videos are ranked by the view counts of the videos endpoint."""
+    def video(video_id, views):
+        return {"id": video_id,
+                "snippet": {"title": video_id, "publishedAt": "2020-01-01T00:00:00Z", "thumbnails": {}},
+                "statistics": {"viewCount": str(views), "likeCount": "1", "commentCount": "0"}}
+
+    with testing.MockServer() as server:
+        server.add_response("search", {"items": [{"id": {"kind": "youtube#video", "videoId": v}}
+                                                 for v in ["video_b", "video_a", "video_c"]]})
+        server.add_response("videos", {"items": [video("video_b", 500), video("video_a", 900), video("video_c", 20)]})
+
+        top = account.get_channel_top_videos("UC_mock_channel_000000000", count=2, api_key="mock_key")
+        search = [request for request in server.requests() if request["endpoint"] == "search"]
+
+    assert [v["video_id"] for v in top] == ["video_a", "video_b"]
+    assert [v["rank"] for v in top] == [1, 2]
+    assert top[0]["view_count"] == 900
+    assert search[0]["params"]["order"] == "viewCount"
+
+
+def test_engagement_summary():
+    """Test averages, median and ratios over the recent videos."""
+    def video(video_id, views, likes, comments):
+        return {"id": video_id, "snippet": {"title": video_id, "publishedAt": "2024-01-01T00:00:00Z", "thumbnails": {}},
+                "statistics": {"viewCount": str(views), "likeCount": str(likes), "commentCount": str(comments)}}
+
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": f"video{n:06d}"}} for n in range(3)]})
+        server.add_response("videos", {"items": [video("video000000", 100, 10, 0), video("video000001", 300, 20, 10),
+                                                 video("video000002", 1600, 70, 10)]})
+
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=3)
+
+    summary = stats["engagement_summary"]
+    assert summary["average_views"] == pytest.approx(2000 / 3)
+    assert summary["median_views"] == 300
+    assert summary["like_view_ratio"] == pytest.approx(0.05)
+    assert summary["comment_view_ratio"] == pytest.approx(0.01)
+    assert summary["engagement_rate"] == pytest.approx(0.06)
+    assert stats["derived"]["engagement_summary"]["source"] == "computed"
+
+
+def test_engagement_summary_hidden_counts():
+    """Test that hidden likes and disabled comments are left out of the ratios rather than read as zero."""
+    def video(video_id, views, **counts):
+        statistics = {"viewCount": str(views), **{key: str(value) for key, value in counts.items()}}
+        return {"id": video_id, "snippet": {"title": video_id, "publishedAt": "2024-01-01T00:00:00Z", "thumbnails": {}},
+                "statistics": statistics}
+
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": f"video{n:06d}"}} for n in range(3)]})
+        server.add_response("videos", {"items": [video("video000000", 100, likeCount=10, commentCount=5),
+                                                 video("video000001", 900, commentCount=9),
+                                                 video("video000002", 400, likeCount=40)]})
+
+        stats = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=3)
+
+    summary = stats["engagement_summary"]
+    assert summary["video_count"] == 3
+    assert summary["like_view_ratio"] == pytest.approx(50 / 500)
+    assert summary["comment_view_ratio"] == pytest.approx(14 / 1000)
+    assert summary["engagement_rate"] == pytest.approx(0.15)
+    assert summary["likes_hidden_count"] == 1
+    assert summary["comments_disabled_count"] == 1
+    assert summary["engagement_excluded_count"] == 2
+    assert stats["engagement_score"] == pytest.approx(0.2)
+
+
+def test_get_channel_from_video():
+    """Test that a watch URL resolves to the uploader's channel stats."""
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [{"snippet": {"channelId": "UC_mock_channel_000000000"}}]},
+                            params={"id": "dQw4w9WgXcQ"})
+        server.add_response("videos", {"items": []})
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": []})
+
+        stats = account.get_channel_from_video("https://youtu.be/dQw4w9WgXcQ?t=42", "mock_key")
+        with pytest.raises(ValueError, match="Video not found"):
+            account.get_channel_from_video("aaaaaaaaaaa", "mock_key")
+
+    assert stats["channel_id"] == "UC_mock_channel_000000000"
+    assert stats["source_video_id"] == "dQw4w9WgXcQ"
+    assert stats["_meta"]["endpoints"][0] == "videos"
+    with pytest.raises(ValueError, match="Unrecognized video"):
+        account.get_channel_from_video("not a video", "mock_key")
+
+
+def test_memberships():
+    """Test membership levels and the lazily paged member roster."""
+    credentials = auth.OAuthCredentials(access_token="mock_token")
+    member = {"snippet": {"memberDetails": {"channelId": "UC_member_000000000000000", "displayName": "Fan"},
+                          "membershipsDetails": {"highestAccessibleLevel": "level_2",
+                                                 "highestAccessibleLevelDisplayName": "Gold",
+                                                 "accessibleLevels": ["level_1", "level_2"],
+                                                 "membershipsDuration": {"memberSince": "2023-05-01T00:00:00Z",
+                                                                         "memberTotalDurationMonths": 17}}}}
+    with testing.MockServer() as server:
+        server.add_response("membershipsLevels", {"items": [
+            {"id": "level_1", "snippet": {"creatorChannelId": "UC_mock_channel_000000000", "levelDetails": {"displayName": "Silver"}}},
+            {"id": "level_2", "snippet": {"creatorChannelId": "UC_mock_channel_000000000", "levelDetails": {"displayName": "Gold"}}},
+        ]})
+        server.add_response("members", {"items": [member], "nextPageToken": "p2"}, times=1)
+        server.add_response("members", {"items": [member]}, params={"pageToken": "p2"})
+
+        levels = memberships.get_membership_levels(credentials)
+        stream = memberships.iter_members(credentials)
+        members = list(stream)
+
+    assert [level["display_name"] for level in levels] == ["Silver", "Gold"]
+    assert len(members) == 2 and stream.pages_fetched == 2
+    assert members[0]["level_name"] == "Gold"
+    assert members[0]["total_duration_months"] == 17
+    with pytest.raises(ValueError, match="channel owner"):
+        memberships.iter_members("key_123")
+
+
+def test_resolve_channel_candidates():
+    """Test ranked candidates for a name without an exact match, and strict mode."""
+    def channel(channel_id, title, handle, subscribers):
+        return {"id": channel_id, "snippet": {"title": title, "customUrl": handle},
+                "statistics": {"subscriberCount": str(subscribers)}}
+
+    with testing.MockServer() as server:
+        server.add_response("channels", {"items": []}, params={"forUsername": "drake"})
+        server.add_response("channels", {"items": []}, params={"forHandle": "@drake"})
+        server.add_response("search", {"items": [{"id": {"kind": "youtube#channel", "channelId": f"UC_candidate_{n}"}}
+                                                 for n in range(3)]})
+        server.add_response("channels", {"items": [
+            channel("UC_candidate_0", "Drake Fan Club", "@drakefans", 900),
+            channel("UC_candidate_1", "Drake", "@drakemusic", 100),
+            channel("UC_candidate_2", "drake", None, 5000),
+        ]})
+
+        candidates = account.resolve_channel_candidates("drake", api_key="mock_key")
+        with pytest.raises(youtube_stats.AmbiguousChannelError) as excinfo:
+            account.resolve_channel_candidates("drake", strict=True, api_key="mock_key")
+
+    assert [c["channel_id"] for c in candidates] == ["UC_candidate_2", "UC_candidate_1", "UC_candidate_0"]
+    assert [c["match"] for c in candidates] == ["title", "title", "search"]
+    assert candidates[0]["subscriber_count"] == 5000
+    assert len(excinfo.value.candidates) == 3
+    assert isinstance(excinfo.value, ValueError)
+
+
+def test_search_channels_by_topic():
+    """Test that topic names map to search topicId values."""
+    with testing.MockServer() as server:
+        server.add_response("search", {"items": []})
+
+        account.search_youtube_channels("new artists", "mock_key", topic="Hip hop")
+        account.search_youtube_channels("new artists", "mock_key", topic="/m/064t9")
+        topic_ids = [request["params"]["topicId"] for request in server.requests()]
+
+    assert topic_ids == [account.TOPICS["hip_hop"], "/m/064t9"]
+    with pytest.raises(ValueError, match="Unknown topic"):
+        account.search_youtube_channels("new artists", "mock_key", topic="polka")
+
+
+def test_search_channels_uses_configured_region():
+    """Test that configure(region=, language=) applies to searches made with a plain key."""
+    try:
+        youtube_stats.configure(region="GB", language="fr")
+        with testing.MockServer() as server:
+            server.add_response("search", {"items": []})
+            account.search_youtube_channels("artistes", "mock_key")
+            [request] = server.requests()
+    finally:
+        auth.set_default_config(None)
+
+    assert request["params"]["regionCode"] == "GB"
+    assert request["params"]["relevanceLanguage"] == "fr"
+
+
+def test_search_channels_budgets():
+    """Test that channel search pagination stops at the page, quota and stop_when limits."""
+    def page(index, next_token):
+        body = {"items": [
+            {"id": {"kind": "youtube#channel", "channelId": "UC_page%d_%d" % (index, n)},
+             "snippet": {"title": "Artist %d.%d" % (index, n), "description": "", "channelTitle": "", "publishedAt": "2020-01-01T00:00:00Z"}}
+            for n in range(2)
+        ]}
+        if next_token:
+            body["nextPageToken"] = next_token
+        return body
+
+    def search(**kwargs):
+        with testing.MockServer() as server:
+            server.add_response("search", page(2, "p3"), params={"pageToken": "p2"})
+            server.add_response("search", page(3, None), params={"pageToken": "p3"})
+            server.add_response("search", page(1, "p2"))
+            results = account.search_youtube_channels("artists", "mock_key", max_results=100, **kwargs)
+            return [r["channel_id"] for r in results], len(server.requests())
+
+    assert search() == (["UC_page1_0", "UC_page1_1", "UC_page2_0", "UC_page2_1", "UC_page3_0", "UC_page3_1"], 3)
+    assert search(max_pages=2) == (["UC_page1_0", "UC_page1_1", "UC_page2_0", "UC_page2_1"], 2)
+    # Search costs 100 units a page, so 250 units allow two pages and 99 none
+    assert search(max_quota=250) == (["UC_page1_0", "UC_page1_1", "UC_page2_0", "UC_page2_1"], 2)
+    assert search(max_quota=99) == ([], 0)
+    assert search(max_pages=3, max_quota=100) == (["UC_page1_0", "UC_page1_1"], 1)
+
+    # The page holding the match is kept whole; no later page is fetched
+    seen = []
+
+    def found(channel):
+        seen.append(channel["channel_id"])
+        return channel["channel_id"] == "UC_page2_0"
+
+    assert search(stop_when=found) == (["UC_page1_0", "UC_page1_1", "UC_page2_0", "UC_page2_1"], 2)
+    assert seen == ["UC_page1_0", "UC_page1_1", "UC_page2_0", "UC_page2_1"]
+
+
+def test_download_channel_images(tmp_path):
+    """Test saving avatar and banner images and rejecting non-image responses."""
+    with testing.MockServer() as server:
+        channel = copy.deepcopy(CHANNEL_FIXTURE)
+        item = channel["items"][0]
+        item["snippet"]["thumbnails"]["high"] = {"url": f"{server.url}/images/avatar"}
+        item["brandingSettings"] = {"image": {"bannerExternalUrl": f"{server.url}/images/banner"}}
+        server.add_response("channels", channel)
+        server.add_response("images/avatar", "avatar-bytes", content_type="image/jpeg")
+        server.add_response("images/banner", "banner-bytes", content_type="image/png", times=1)
+        server.add_response("images/banner", "<html>error</html>", content_type="text/html")
+
+        saved = account.download_channel_images("UC_mock_channel_000000000", tmp_path,
+                                                sizes=["high", "medium"], api_key="mock_key")
+        with pytest.raises(ValueError, match="Expected an image"):
+            account.download_channel_images("UC_mock_channel_000000000", api_key="mock_key")
+
+    avatar, banner = saved["images"]
+    assert (avatar["kind"], avatar["size"], avatar["content_type"]) == ("avatar", "high", "image/jpeg")
+    assert open(avatar["path"], "rb").read() == b"avatar-bytes"
+    assert banner["path"].endswith("UC_mock_channel_000000000_banner_original.png")
+    assert saved["missing"] == ["avatar_medium"]
+
+
+VIDEO_FIXTURE = {
+    "id": "dQw4w9WgXcQ",
+    "snippet": {
+        "title": "Mock Song",
+        "description": "Official video",
+        "channelId": "UC_mock_channel_000000000",
+        "channelTitle": "Mock Artist",
+        "publishedAt": "2009-10-25T06:57:33Z",
+        "thumbnails": {"high": {"url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg", "width": 480, "height": 360}},
+    },
+    "statistics": {"viewCount": "1500000000", "likeCount": "17000000", "commentCount": "2300000"},
+    "contentDetails": {"duration": "PT3M33S", "dimension": "2d", "definition": "hd", "caption": "true",
+                       "licensedContent": True},
+}
+
+
+def test_get_video_stats():
+    """Test the single-video statistics primitive."""
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [VIDEO_FIXTURE]}, params={"id": "dQw4w9WgXcQ"})
+        server.add_response("videos", {"items": []})
+
+        stats = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        with pytest.raises(ValueError, match="Video not found"):
+            video.get_video_stats("aaaaaaaaaaa", "mock_key")
+
+    assert stats["title"] == "Mock Song"
+    assert stats["channel_id"] == "UC_mock_channel_000000000"
+    assert stats["view_count"] == 1500000000
+    assert stats["duration"] == "PT3M33S" and stats["duration_seconds"] == 213
+    assert stats["thumbnails"]["high"].endswith("hqdefault.jpg")
+    assert stats["_meta"]["endpoints"] == ["videos"]
+
+
+def test_get_videos_stats_batches():
+    """Test 50-ID batching, deduplication and the not-found list."""
+    ids = [f"video{n:06d}" for n in range(60)]
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [dict(VIDEO_FIXTURE, id=video_id) for video_id in ids[:49]]}, times=1)
+        server.add_response("videos", {"items": [dict(VIDEO_FIXTURE, id=video_id) for video_id in ids[50:]]})
+
+        result = video.get_videos_stats(ids + ids[:5], "mock_key")
+        batches = [request["params"]["id"].split(",") for request in server.requests()]
+
+    assert [len(batch) for batch in batches] == [50, 10]
+    assert list(result["videos"]) == ids[:49] + ids[50:]
+    assert result["not_found"] == [ids[49]]
+    assert result["videos"]["video000003"]["view_count"] == 1500000000
+
+
+def test_video_content_details():
+    """Test contentDetails fields in single-video and channel recent-video results."""
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": "dQw4w9WgXcQ"}}]})
+        server.add_response("videos", {"items": [VIDEO_FIXTURE]})
+
+        single = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        recent = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=1)["recent_videos"][0]
+        parts = [request["params"]["part"] for request in server.requests() if request["endpoint"] == "videos"]
+
+    for result in (single, recent):
+        assert result["definition"] == "hd"
+        assert result["dimension"] == "2d"
+        assert result["licensed_content"] is True
+        assert result["caption"] is True
+        assert result["duration_seconds"] == 213
+    assert all("contentDetails" in part for part in parts)
+
+
+def test_video_tags_and_category():
+    """Test tags and category names, looked up once per category."""
+    tagged = copy.deepcopy(VIDEO_FIXTURE)
+    tagged["snippet"].update(tags=["pop", "80s"], categoryId="10")
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [tagged]})
+        server.add_response("videoCategories", {"items": [{"id": "10", "snippet": {"title": "Music"}}]})
+
+        first = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        second = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        lookups = [request for request in server.requests() if request["endpoint"] == "videoCategories"]
+
+    assert first["tags"] == ["pop", "80s"]
+    assert (first["category_id"], first["category_name"]) == ("10", "Music")
+    assert second["category_name"] == "Music"
+    assert len(lookups) <= 1
+
+
+def test_video_region_restriction():
+    """Test that geo-blocking lists are exposed and unrestricted videos report None."""
+    blocked = copy.deepcopy(VIDEO_FIXTURE)
+    blocked["id"] = "blocked0001"
+    blocked["contentDetails"]["regionRestriction"] = {"blocked": ["DE", "RU"]}
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [blocked, VIDEO_FIXTURE]})
+
+        result = video.get_videos_stats(["blocked0001", "dQw4w9WgXcQ"], "mock_key")
+
+    assert result["videos"]["blocked0001"]["region_restriction"] == {"allowed": None, "blocked": ["DE", "RU"]}
+    assert result["videos"]["dQw4w9WgXcQ"]["region_restriction"] is None
+
+
+def test_video_status():
+    """Test that status fields identify unlisted and made-for-kids videos."""
+    unlisted = copy.deepcopy(VIDEO_FIXTURE)
+    unlisted["status"] = {
+        "privacyStatus": "unlisted",
+        "uploadStatus": "processed",
+        "license": "creativeCommon",
+        "embeddable": False,
+        "publicStatsViewable": False,
+        "madeForKids": True,
+    }
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [unlisted]})
+
+        result = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+
+    assert "status" in server.requests()[0]["params"]["part"]
+    assert result["privacy_status"] == "unlisted"
+    assert result["availability"] == "unlisted"
+    assert result["upload_status"] == "processed"
+    assert result["license"] == "creativeCommon"
+    assert result["embeddable"] is False
+    assert result["public_stats_viewable"] is False
+    assert result["made_for_kids"] is True
+
+
+def test_video_live_streaming_details():
+    """Test that live streaming details are exposed and absent for regular uploads."""
+    live = copy.deepcopy(VIDEO_FIXTURE)
+    live["id"] = "livestream1"
+    live["liveStreamingDetails"] = {
+        "scheduledStartTime": "2024-05-01T18:00:00Z",
+        "actualStartTime": "2024-05-01T18:02:11Z",
+        "concurrentViewers": "5120",
+        "activeLiveChatId": "Cg0KC2xpdmVzdHJlYW0x",
+    }
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [live, VIDEO_FIXTURE]})
+
+        result = video.get_videos_stats(["livestream1", "dQw4w9WgXcQ"], "mock_key")
+
+    assert "liveStreamingDetails" in server.requests()[0]["params"]["part"]
+    stream = result["videos"]["livestream1"]
+    assert stream["concurrent_viewers"] == 5120
+    assert stream["scheduled_start_time"] == "2024-05-01T18:00:00Z"
+    assert stream["actual_start_time"] == "2024-05-01T18:02:11Z"
+    assert stream["active_live_chat_id"] == "Cg0KC2xpdmVzdHJlYW0x"
+    upload = result["videos"]["dQw4w9WgXcQ"]
+    assert upload["concurrent_viewers"] is None
+    assert upload["active_live_chat_id"] is None
+
+
+def test_video_topic_details():
+    """Test that video topic categories are exposed as names and URLs."""
+    fixture = copy.deepcopy(VIDEO_FIXTURE)
+    fixture["topicDetails"] = {
+        "topicCategories": [
+            "https://en.wikipedia.org/wiki/Music",
+            "https://en.wikipedia.org/wiki/Pop_music",
+        ]
+    }
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [fixture]})
+
+        result = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+
+    assert "topicDetails" in server.requests()[0]["params"]["part"]
+    assert result["topic_categories"] == ["Music", "Pop music"]
+    assert result["topic_category_urls"] == fixture["topicDetails"]["topicCategories"]
+
+
+def test_paid_product_placement():
+    """Test the sponsorship flag in single-video and channel recent-video results."""
+    sponsored = copy.deepcopy(VIDEO_FIXTURE)
+    sponsored["paidProductPlacementDetails"] = {"hasPaidProductPlacement": True}
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": "dQw4w9WgXcQ"}}]})
+        server.add_response("videos", {"items": [sponsored]}, times=2)
+        server.add_response("videos", {"items": [VIDEO_FIXTURE]})
+
+        single = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        recent = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=1)["recent_videos"][0]
+        undeclared = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        parts = [request["params"]["part"] for request in server.requests() if request["endpoint"] == "videos"]
+
+    assert single["has_paid_product_placement"] is True
+    assert recent["has_paid_product_placement"] is True
+    assert undeclared["has_paid_product_placement"] is None
+    assert all("paidProductPlacementDetails" in part for part in parts)
+
+
+def test_video_engagement_rate():
+    """Test the per-video engagement rate and its configurable weights."""
+    hidden = copy.deepcopy(VIDEO_FIXTURE)
+    hidden["id"] = "hiddenlikes"
+    del hidden["statistics"]["likeCount"]
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [VIDEO_FIXTURE, hidden]})
+
+        result = video.get_videos_stats(["dQw4w9WgXcQ", "hiddenlikes"], "mock_key")
+        weighted = video.get_video_stats("dQw4w9WgXcQ", "mock_key", engagement_rate_weights=(1.0, 2.0))
+        # Weights apply to that call only
+        unweighted = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+
+    stats = VIDEO_FIXTURE["statistics"]
+    views, likes, comments = (int(stats[key]) for key in ("viewCount", "likeCount", "commentCount"))
+    assert result["videos"]["dQw4w9WgXcQ"]["engagement_rate"] == pytest.approx((likes + comments) / views)
+    assert result["videos"]["hiddenlikes"]["engagement_rate"] is None
+    assert weighted["engagement_rate"] == pytest.approx((likes + 2 * comments) / views)
+    assert unweighted["engagement_rate"] == pytest.approx((likes + comments) / views)
+
+    with pytest.raises(ValueError, match="finite and non-negative"):
+        video.get_video_stats("dQw4w9WgXcQ", "mock_key", engagement_rate_weights=(-1.0, 1.0))
+    with pytest.raises(ValueError, match="finite and non-negative"):
+        video.get_videos_stats(["dQw4w9WgXcQ"], "mock_key", engagement_rate_weights=(1.0, float("nan")))
+
+
+def test_download_video_thumbnail(tmp_path):
+    """Test thumbnail quality fallback and saving to a directory."""
+    with testing.MockServer() as server:
+        server.add_response("vi/dQw4w9WgXcQ/hqdefault.jpg", "hq-bytes", content_type="image/jpeg")
+
+        returned = video.download_video_thumbnail("dQw4w9WgXcQ")
+        saved = video.download_video_thumbnail("dQw4w9WgXcQ", quality="sddefault", dest=tmp_path)
+        with pytest.raises(ValueError, match="No thumbnail found"):
+            video.download_video_thumbnail("dQw4w9WgXcQ", quality="mqdefault")
+        endpoints = [request["endpoint"] for request in server.requests()]
+
+    assert returned["quality"] == "hqdefault"
+    assert returned["data"] == b"hq-bytes"
+    assert returned["url"] == "https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg"
+    assert endpoints[:3] == ["vi/dQw4w9WgXcQ/maxresdefault.jpg", "vi/dQw4w9WgXcQ/sddefault.jpg", "vi/dQw4w9WgXcQ/hqdefault.jpg"]
+    assert saved["path"].endswith("dQw4w9WgXcQ_hqdefault.jpg")
+    assert open(saved["path"], "rb").read() == b"hq-bytes"
+    assert saved["_meta"]["quota_cost"] == 0
+
+    with pytest.raises(ValueError, match="Unknown thumbnail quality"):
+        video.download_video_thumbnail("dQw4w9WgXcQ", quality="huge")
+
+
+def test_video_is_short():
+    """Test Shorts detection in video results and the standalone check."""
+    short = copy.deepcopy(VIDEO_FIXTURE)
+    short["id"] = "short000001"
+    short["contentDetails"]["duration"] = "PT45S"
+    regular = copy.deepcopy(VIDEO_FIXTURE)
+    regular["id"] = "redirect001"
+    regular["contentDetails"]["duration"] = "PT2M"
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [short, regular, VIDEO_FIXTURE]}, times=1)
+        server.add_response("videos", {"items": [short]})
+        server.add_response("shorts/short000001", {})
+        server.add_response("shorts/redirect001", {}, status=303)
+
+        result = video.get_videos_stats(["short000001", "redirect001", "dQw4w9WgXcQ"], "mock_key", detect_shorts=True)
+        standalone = video.is_short("short000001", "mock_key")
+        probed = [request["endpoint"] for request in server.requests() if request["endpoint"].startswith("shorts/")]
+
+        # Without detect_shorts nothing is probed and the keys are left out
+        server.reset()
+        server.add_response("videos", {"items": [short]})
+        unprobed = video.get_video_stats("short000001", "mock_key")
+        assert [request["endpoint"] for request in server.requests()] == ["videos"]
+    assert "is_short" not in unprobed and "is_short" not in unprobed["derived"]
+
+    videos = result["videos"]
+    assert (videos["short000001"]["is_short"], videos["short000001"]["short_detection"]) == (True, "shorts_url")
+    assert (videos["redirect001"]["is_short"], videos["redirect001"]["short_detection"]) == (False, "shorts_url")
+    assert (videos["dQw4w9WgXcQ"]["is_short"], videos["dQw4w9WgXcQ"]["short_detection"]) == (False, "duration")
+    assert "shorts/dQw4w9WgXcQ" not in probed
+    assert standalone is True
+
+
+def test_video_functions_accept_urls():
+    """Test that video functions take watch, youtu.be and shorts URLs."""
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [VIDEO_FIXTURE]})
+
+        single = video.get_video_stats("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=42s&list=PL123", "mock_key")
+        batch = video.get_videos_stats(["youtu.be/dQw4w9WgXcQ?si=abc", "https://youtube.com/shorts/dQw4w9WgXcQ"], "mock_key")
+        ids = [request["params"]["id"] for request in server.requests() if request["endpoint"] == "videos"]
+
+    assert single["video_id"] == "dQw4w9WgXcQ"
+    assert list(batch["videos"]) == ["dQw4w9WgXcQ"]
+    assert ids == ["dQw4w9WgXcQ", "dQw4w9WgXcQ"]
+
+    for malformed in ["not a video", "https://www.youtube.com/@SomeChannel", "https://example.com/watch?v=dQw4w9WgXcQ"]:
+        with pytest.raises(youtube_stats.InvalidVideoReferenceError) as excinfo:
+            video.get_video_stats(malformed, "mock_key")
+        assert excinfo.value.input == malformed
+    assert issubclass(youtube_stats.InvalidVideoReferenceError, ValueError)
+
+
+def test_hidden_likes_and_disabled_comments():
+    """Test explicit flags for counts the uploader hid or turned off."""
+    hidden = copy.deepcopy(VIDEO_FIXTURE)
+    del hidden["statistics"]["likeCount"]
+    del hidden["statistics"]["commentCount"]
+    with testing.MockServer() as server:
+        server.add_response("channels", CHANNEL_FIXTURE)
+        server.add_response("playlistItems", {"items": [{"contentDetails": {"videoId": "dQw4w9WgXcQ"}}]})
+        server.add_response("videos", {"items": [hidden]}, times=2)
+        server.add_response("videos", {"items": [VIDEO_FIXTURE]})
+
+        single = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        recent = account.get_youtube_channel_stats("UC_mock_channel_000000000", "mock_key", video_count=1)["recent_videos"][0]
+        visible = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+
+    for result in (single, recent):
+        assert result["likes_hidden"] is True
+        assert result["comments_disabled"] is True
+    assert single["like_count"] is None and single["comment_count"] is None
+    assert visible["likes_hidden"] is False
+    assert visible["comments_disabled"] is False
+
+
+def test_estimated_dislikes():
+    """Test the opt-in Return YouTube Dislike estimate and its provenance."""
+    with testing.MockServer() as server:
+        server.add_response("videos", {"items": [VIDEO_FIXTURE]})
+        server.add_response("votes", {"id": "dQw4w9WgXcQ", "likes": 17000000, "dislikes": 250000},
+                            params={"videoId": "dQw4w9WgXcQ"})
+
+        plain = video.get_video_stats("dQw4w9WgXcQ", "mock_key")
+        with_dislikes = video.get_video_stats("dQw4w9WgXcQ", "mock_key", include_dislikes=True)
+        batch = video.get_videos_stats(["dQw4w9WgXcQ"], "mock_key", include_dislikes=True)
+        vote_requests = [request for request in server.requests() if request["endpoint"] == "votes"]
+
+    assert "estimated_dislikes" not in plain
+    assert with_dislikes["estimated_dislikes"] == 250000
+    assert with_dislikes["derived"]["estimated_dislikes"] == {"value": 250000, "source": "third_party"}
+    assert batch["videos"]["dQw4w9WgXcQ"]["estimated_dislikes"] == 250000
+    assert len(vote_requests) == 2
+
+
+def test_get_video_captions():
+    """Test listing caption tracks with their kind and last update."""
+    captions = {"items": [
+        {"id": "AUieDaZ1", "snippet": {"videoId": "dQw4w9WgXcQ", "language": "en", "name": "", "trackKind": "asr",
+                                       "lastUpdated": "2024-01-02T03:04:05Z", "isCC": False, "isDraft": False,
+                                       "status": "serving"}},
+        {"id": "AUieDaZ2", "snippet": {"videoId": "dQw4w9WgXcQ", "language": "pt-BR", "name": "Português",
+                                       "trackKind": "standard", "lastUpdated": "2023-06-07T08:09:10Z",
+                                       "isCC": True, "isDraft": False, "status": "serving"}},
+    ]}
+    with testing.MockServer() as server:
+        server.add_response("captions", captions, params={"videoId": "dQw4w9WgXcQ"})
+
+        tracks = video.get_video_captions("https://youtu.be/dQw4w9WgXcQ", "mock_key")
+
+    assert [track["language"] for track in tracks] == ["en", "pt-BR"]
+    assert (tracks[0]["kind"], tracks[0]["is_auto_generated"]) == ("asr", True)
+    assert (tracks[1]["kind"], tracks[1]["is_auto_generated"], tracks[1]["is_cc"]) == ("standard", False, True)
+    assert tracks[1]["last_updated"] == "2023-06-07T08:09:10Z"
+
+
+def test_get_video_transcript():
+    """Test transcript segments, falling back to the automatic track."""
+    timed_text = {"events": [
+        {"tStartMs": 0, "dDurationMs": 1500, "id": 1},
+        {"tStartMs": 1500, "dDurationMs": 2000, "segs": [{"utf8": "We're no "}, {"utf8": "strangers"}]},
+        {"tStartMs": 3500, "dDurationMs": 2250, "segs": [{"utf8": "to love\n"}]},
+        {"tStartMs": 5750, "dDurationMs": 100, "segs": [{"utf8": "\n"}]},
+    ]}
+    with testing.MockServer() as server:
+        server.add_response("api/timedtext", timed_text, params={"v": "dQw4w9WgXcQ", "lang": "en", "kind": "asr"})
+
+        segments = video.get_video_transcript("https://www.youtube.com/watch?v=dQw4w9WgXcQ")
+        with pytest.raises(ValueError, match="No transcript"):
+            video.get_video_transcript("dQw4w9WgXcQ", lang="de")
+        kinds = [request["params"].get("kind") for request in server.requests()][:2]
+
+        # The language is one encoded value and cannot inject other parameters
+        server.reset()
+        server.add_response("api/timedtext", timed_text, params={"lang": "de"})
+        with pytest.raises(ValueError, match="No transcript"):
+            video.get_video_transcript("dQw4w9WgXcQ", lang="de&kind=asr")
+        assert server.requests()[0]["params"]["lang"] == "de&kind=asr"
+
+    assert segments == [
+        {"start": 1.5, "duration": 2.0, "text": "We're no strangers"},
+        {"start": 3.5, "duration": 2.25, "text": "to love"},
+    ]
+    assert kinds == [None, "asr"]
+
+
+def test_get_video_oembed():
+    """Test the keyless oEmbed lookup and its error mapping."""
+    oembed = {
+        "title": "Mock Song", "author_name": "Mock Artist", "author_url": "https://www.youtube.com/@mockartist",
+        "type": "video", "height": 113, "width": 200, "version": "1.0", "provider_name": "YouTube",
+        "thumbnail_url": "https://i.ytimg.com/vi/dQw4w9WgXcQ/hqdefault.jpg", "thumbnail_width": 480,
+        "thumbnail_height": 360, "html": "<iframe src=\"https://www.youtube.com/embed/dQw4w9WgXcQ\"></iframe>",
+    }
+    with testing.MockServer() as server:
+        server.add_response("oembed", oembed, params={"url": "https://www.youtube.com/watch?v=dQw4w9WgXcQ"})
+        server.add_response("oembed", "Unauthorized", status=401, params={"url": "https://www.youtube.com/watch?v=private0001"})
+        server.add_response("oembed", "Not Found", status=404)
+
+        result = video.get_video_oembed("https://youtu.be/dQw4w9WgXcQ?si=share")
+        with pytest.raises(ValueError, match="cannot be embedded"):
+            video.get_video_oembed("private0001")
+        with pytest.raises(ValueError, match="Video not found"):
+            video.get_video_oembed("missing0001")
+
+    assert result["video_id"] == "dQw4w9WgXcQ"
+    assert (result["title"], result["author_name"]) == ("Mock Song", "Mock Artist")
+    assert result["thumbnail_url"].endswith("hqdefault.jpg")
+    assert result["html"].startswith("<iframe")
+    assert (result["_meta"]["request_count"], result["_meta"]["quota_cost"]) == (0, 0)